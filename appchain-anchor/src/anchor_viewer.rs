@@ -15,6 +15,10 @@ impl AnchorViewer for AppchainAnchor {
         self.owner_pk.clone()
     }
     //
+    fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.clone()
+    }
+    //
     fn get_anchor_settings(&self) -> AnchorSettings {
         self.anchor_settings.get().unwrap()
     }
@@ -81,6 +85,8 @@ impl AnchorViewer for AppchainAnchor {
             permissionless_actions_status: self.permissionless_actions_status.get().unwrap(),
             asset_transfer_is_paused: self.asset_transfer_is_paused,
             rewards_withdrawal_is_paused: self.rewards_withdrawal_is_paused,
+            stake_withdrawal_is_paused: self.stake_withdrawal_is_paused,
+            paused_bridge_flows: self.paused_bridge_flows.to_vec(),
         }
     }
     //
@@ -180,7 +186,19 @@ impl AnchorViewer for AppchainAnchor {
                 Vec::new()
             }
         } else {
-            self.next_validator_set.get().unwrap().get_validator_list()
+            self.next_validator_set
+                .get()
+                .unwrap()
+                .get_validator_list()
+                .into_iter()
+                .map(|mut validator| {
+                    validator.commission_percent =
+                        Some(self.get_commission_percent_of_validator_in_next_era(
+                            &validator.validator_id,
+                        ));
+                    validator
+                })
+                .collect()
         }
     }
     //
@@ -237,12 +255,7 @@ impl AnchorViewer for AppchainAnchor {
         let mut results = Vec::<UnbondedStake>::new();
         if let Some(unbonded_stake_references) = self.unbonded_stakes.get(&account_id) {
             unbonded_stake_references.iter().for_each(|reference| {
-                let validator_set = self
-                    .validator_set_histories
-                    .get()
-                    .unwrap()
-                    .get(&reference.era_number)
-                    .unwrap();
+                let unlock_start_timestamp = self.get_unlock_start_timestamp_of(reference);
                 let staking_history = self
                     .staking_histories
                     .get()
@@ -266,7 +279,7 @@ impl AnchorViewer for AppchainAnchor {
                         account_id: validator_id,
                         amount,
                         unlock_time: U64::from(
-                            validator_set.start_timestamp()
+                            unlock_start_timestamp
                                 + protocol_settings.unlock_period_of_validator_deposit.0
                                     * SECONDS_OF_A_DAY
                                     * NANO_SECONDS_MULTIPLE,
@@ -291,7 +304,7 @@ impl AnchorViewer for AppchainAnchor {
                         account_id: delegator_id,
                         amount,
                         unlock_time: U64::from(
-                            validator_set.start_timestamp()
+                            unlock_start_timestamp
                                 + protocol_settings.unlock_period_of_delegator_deposit.0
                                     * SECONDS_OF_A_DAY
                                     * NANO_SECONDS_MULTIPLE,
@@ -606,4 +619,222 @@ impl AnchorViewer for AppchainAnchor {
             .unwrap()
             .get_slice_of(&start_index.0, quantity.map(|q| q.0))
     }
+    //
+    fn get_appchain_challenge_status(&self, index: U64) -> Option<AppchainChallengeStatus> {
+        self.appchain_challenge_statuses.get(&index.0)
+    }
+    //
+    fn get_reward_beneficiary_of(&self, account_id: AccountId) -> Option<AccountId> {
+        self.reward_beneficiaries.get(&account_id)
+    }
+    //
+    fn get_failed_withdrawals_of(&self, account_id: AccountId) -> Vec<FailedWithdrawal> {
+        self.failed_withdrawals.get(&account_id).unwrap_or_default()
+    }
+    //
+    fn get_pending_slashed_oct_token(&self) -> U128 {
+        U128::from(self.pending_slashed_oct_token)
+    }
+    //
+    fn get_validator_id_of_grandpa_key(&self, grandpa_key: String) -> Option<AccountId> {
+        self.validator_ids_of_grandpa_keys
+            .get(&grandpa_key.trim_start_matches("0x").to_lowercase())
+    }
+    //
+    fn get_era_number_of_grandpa_set_id(&self, set_id: u32) -> Option<U64> {
+        self.era_numbers_of_grandpa_set_ids
+            .get(&set_id)
+            .map(|era_number| U64::from(era_number))
+    }
+    //
+    fn get_unminted_bounty_of_appchain_challenge(&self, index: U64) -> U128 {
+        U128::from(
+            self.unminted_appchain_challenge_bounties
+                .get(&index.0)
+                .unwrap_or(0),
+        )
+    }
+    //
+    fn get_onboarding_grants_status(&self) -> OnboardingGrantsStatus {
+        self.onboarding_grants_status.get().unwrap()
+    }
+    //
+    fn get_onboarding_grants(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<OnboardingGrant> {
+        self.onboarding_grants
+            .get()
+            .unwrap()
+            .get_slice_of(&start_index.0, quantity.map(|q| q.0))
+    }
+    //
+    fn get_onboarding_grant_of(&self, account_id_in_appchain: String) -> Option<OnboardingGrant> {
+        let account_id_in_appchain =
+            AccountIdInAppchain::new(Some(account_id_in_appchain), &self.appchain_template_type);
+        self.onboarding_grant_indexes
+            .get(&account_id_in_appchain.to_string())
+            .map_or(None, |index| {
+                self.onboarding_grants.get().unwrap().get(&index)
+            })
+    }
+    //
+    fn get_bridge_fee_of(&self, asset: BridgingAsset) -> Option<BridgeFee> {
+        self.bridge_fees.get(&asset)
+    }
+    //
+    fn get_accrued_bridge_fees(&self) -> Vec<AccruedBridgeFee> {
+        self.accrued_bridge_fees
+            .iter()
+            .map(|(asset, accrued_amount)| AccruedBridgeFee {
+                fee: self.bridge_fees.get(&asset),
+                asset,
+                accrued_amount,
+            })
+            .collect()
+    }
+    //
+    fn get_bridge_flow_status_of(
+        &self,
+        asset: BridgingAsset,
+        direction: BridgingDirection,
+    ) -> BridgeFlowStatus {
+        self.internal_get_bridge_flow_status(&asset, &direction)
+    }
+    //
+    fn get_paused_bridge_flows(&self) -> Vec<BridgeFlowStatus> {
+        self.paused_bridge_flows
+            .iter()
+            .map(|(asset, direction)| self.internal_get_bridge_flow_status(&asset, &direction))
+            .collect()
+    }
+    //
+    fn get_escrowed_assets_of(&self, account_id: AccountId) -> Vec<EscrowedAsset> {
+        self.escrowed_assets.get(&account_id).unwrap_or_default()
+    }
+    //
+    fn get_storage_fund(&self) -> StorageFund {
+        self.storage_fund.get().unwrap()
+    }
+    //
+    fn get_storage_deposit_budget_of(&self, asset: BridgingAsset) -> Option<U128> {
+        self.storage_deposit_budgets.get(&asset)
+    }
+    //
+    fn get_price_reporters(&self) -> Vec<AccountId> {
+        let mut price_reporters = self
+            .role_members
+            .get(&AnchorRole::PriceMaintainer)
+            .unwrap_or_default();
+        if let Some(maintainer) = self
+            .anchor_settings
+            .get()
+            .unwrap()
+            .token_price_maintainer_account
+        {
+            if !price_reporters.contains(&maintainer) {
+                price_reporters.push(maintainer);
+            }
+        }
+        price_reporters
+    }
+    //
+    fn get_relayer_stats(&self) -> Vec<RelayerStats> {
+        self.relayer_stats.values_as_vector().to_vec()
+    }
+    //
+    fn get_relayer_stats_of(&self, relayer: AccountId) -> Option<RelayerStats> {
+        self.relayer_stats.get(&relayer)
+    }
+    //
+    fn get_role_members(&self, role: AnchorRole) -> Vec<AccountId> {
+        self.role_members.get(&role).unwrap_or_default()
+    }
+    //
+    fn get_roles_of(&self, account_id: AccountId) -> Vec<AnchorRole> {
+        AnchorRole::all()
+            .into_iter()
+            .filter(|role| self.has_role(role, &account_id))
+            .collect()
+    }
+    //
+    fn get_witness_attestations_of(&self, start_nonce: u32) -> Vec<WitnessAttestation> {
+        self.witness_attestations
+            .get(&start_nonce)
+            .unwrap_or_default()
+    }
+    //
+    fn get_witness_submission_stats(&self) -> Vec<WitnessSubmissionStats> {
+        self.role_members
+            .get(&AnchorRole::Witness)
+            .unwrap_or_default()
+            .iter()
+            .map(|witness| self.internal_get_witness_submission_stats_of(witness))
+            .collect()
+    }
+    //
+    fn get_governance_proposals(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<GovernanceProposal> {
+        self.governance_proposals
+            .get()
+            .unwrap()
+            .get_slice_of(&start_index.0, quantity.map(|q| q.0))
+    }
+    //
+    fn get_governance_proposal(&self, index: U64) -> Option<GovernanceProposal> {
+        self.governance_proposals.get().unwrap().get(&index.0)
+    }
+    //
+    fn get_role_audit_records(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<RoleAuditRecord> {
+        self.role_audit_records
+            .get()
+            .unwrap()
+            .get_slice_of(&start_index.0, quantity.map(|q| q.0))
+    }
+    //
+    fn get_price_status_of(&self, asset: PricedAsset) -> AssetPriceStatus {
+        self.internal_get_price_status_of(&asset)
+    }
+    //
+    fn get_collateralization_status(&self) -> CollateralizationStatus {
+        self.internal_get_collateralization_status()
+    }
+    //
+    fn get_retryable_appchain_messages(&self) -> Vec<RetryableAppchainMessage> {
+        self.retryable_appchain_messages.values().collect()
+    }
+    //
+    fn get_processing_result_history_of_appchain_message(
+        &self,
+        nonce: u32,
+    ) -> Vec<AppchainMessageProcessingResult> {
+        self.appchain_message_processing_result_histories
+            .get(&nonce)
+            .unwrap_or_default()
+    }
+    //
+    fn get_jailed_validators(&self) -> Vec<JailedValidator> {
+        let next_validator_set = self.next_validator_set.get().unwrap();
+        next_validator_set
+            .validator_set()
+            .get_validator_ids()
+            .into_iter()
+            .filter_map(|validator_id| {
+                self.jailed_validators
+                    .get(&validator_id)
+                    .map(|era_number| JailedValidator {
+                        validator_id,
+                        jailed_era_number: U64::from(era_number),
+                    })
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,97 @@
+use crate::*;
+use beefy_light_client::mmr::{self, MmrLeafProof};
+use parity_scale_codec::{Compact, Decode};
+
+pub type BlockNumber = u32;
+
+/// The data for proving a header (and its MMR leaf) of appchain
+/// against the MMR root accepted by the beefy light client.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Debug, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct HeaderWithMmrProof {
+    pub encoded_messages: Vec<u8>,
+    pub header: Vec<u8>,
+    pub mmr_leaf: Vec<u8>,
+    pub mmr_proof: Vec<u8>,
+}
+
+/// The proof of a conspiracy of MMR, which consists of two conflicting
+/// MMR leaves or headers of the same block number.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Clone, Debug, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConspiracyMmrProof {
+    pub first: HeaderWithMmrProof,
+    pub second: HeaderWithMmrProof,
+}
+
+impl HeaderWithMmrProof {
+    ///
+    pub fn block_number(&self) -> Result<BlockNumber, String> {
+        // The encoded header starts with `parent_hash` and the compact encoded `number`.
+        match <([u8; 32], Compact<BlockNumber>)>::decode(&mut &self.header[..]) {
+            Ok((_, number)) => Ok(number.0),
+            Err(err) => Err(format!("Failed to decode header: {}", err)),
+        }
+    }
+    /// Verify the MMR leaf against the MMR root with the MMR proof.
+    pub fn verify_mmr_leaf(&self, mmr_root: &[u8; 32]) -> Result<(), String> {
+        // The MMR leaf is the SCALE encoded bytes of the opaque leaf.
+        let leaf: Vec<u8> = match Decode::decode(&mut &self.mmr_leaf[..]) {
+            Ok(leaf) => leaf,
+            Err(err) => return Err(format!("Failed to decode MMR leaf: {}", err)),
+        };
+        let proof = match MmrLeafProof::decode(&mut &self.mmr_proof[..]) {
+            Ok(proof) => proof,
+            Err(err) => return Err(format!("Failed to decode MMR proof: {}", err)),
+        };
+        let mut leaf_hash = [0u8; 32];
+        leaf_hash.copy_from_slice(&env::keccak256(&leaf));
+        match mmr::verify_leaf_proof(*mmr_root, leaf_hash, proof) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("The MMR leaf is not included in the MMR root.".to_string()),
+            Err(err) => Err(format!("Failed in verifying MMR leaf proof: {:?}", err)),
+        }
+    }
+}
+
+impl ConspiracyMmrProof {
+    ///
+    pub fn verify(
+        &self,
+        light_client: &LightClient,
+        block_number: BlockNumber,
+    ) -> Result<(), String> {
+        if self.first.header == self.second.header && self.first.mmr_leaf == self.second.mmr_leaf {
+            return Err("The two headers and MMR leaves in proof are identical.".to_string());
+        }
+        let mmr_root = match light_client.get_latest_commitment() {
+            Some(commitment) => commitment.payload,
+            None => return Err("There is no MMR root accepted by light client.".to_string()),
+        };
+        for header_with_proof in [&self.first, &self.second] {
+            let number = header_with_proof.block_number()?;
+            if number != block_number {
+                return Err(format!(
+                    "Block number of header in proof is '{}', but '{}' is expected.",
+                    number, block_number
+                ));
+            }
+            if let Err(err) = light_client.verify_solochain_messages(
+                &header_with_proof.encoded_messages,
+                &header_with_proof.header,
+                &header_with_proof.mmr_leaf,
+                &header_with_proof.mmr_proof,
+            ) {
+                return Err(format!("Failed in verifying header in proof: {:?}", err));
+            }
+            header_with_proof.verify_mmr_leaf(&mmr_root)?;
+        }
+        Ok(())
+    }
+    /// The hash of the proof, which is used to identify a committed proof.
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&env::sha256(&self.try_to_vec().unwrap()));
+        hash
+    }
+}
@@ -119,6 +119,20 @@ impl EquivocationProof {
             }
         }
     }
+    ///
+    pub fn round_number(&self) -> RoundNumber {
+        match &self.equivocation {
+            Equivocation::Prevote(equivocation) => equivocation.round_number,
+            Equivocation::Precommit(equivocation) => equivocation.round_number,
+        }
+    }
+    ///
+    pub fn identity(&self) -> &PublicKey {
+        match &self.equivocation {
+            Equivocation::Prevote(equivocation) => &equivocation.identity,
+            Equivocation::Precommit(equivocation) => &equivocation.identity,
+        }
+    }
     //
     fn check_signature(
         &self,
@@ -132,6 +146,13 @@ impl EquivocationProof {
         // Need to convert `round` and `set_id` to u64 to match original
         // signing data in appchain side
         (message, u64::from(*round), u64::from(self.set_id)).encode_to(&mut buffer);
+        pubkey.verify(&buffer, signature)
+    }
+}
+
+impl PublicKey {
+    /// Verify the ed25519 signature of this key on a message.
+    pub fn verify(&self, message: &[u8], signature: &SignatureData) -> bool {
         if signature.0.len() != 64 {
             log!("Invalid signature data length.");
             return false;
@@ -141,8 +162,8 @@ impl EquivocationProof {
             sig_data[i] = signature.0.get(i).unwrap_or(&0).clone();
         }
         if let Ok(signature) = ed25519_dalek::Signature::try_from(sig_data) {
-            match ed25519_dalek::PublicKey::from_bytes(&pubkey.0) {
-                Ok(pubkey) => match pubkey.verify(&buffer, &signature) {
+            match ed25519_dalek::PublicKey::from_bytes(&self.0) {
+                Ok(pubkey) => match pubkey.verify(message, &signature) {
                     Ok(()) => true,
                     Err(err) => {
                         log!("Signature verification failed: {}", err);
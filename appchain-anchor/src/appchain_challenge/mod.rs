@@ -1,8 +1,12 @@
+mod conspiracy_mmr;
 mod equivocation_challenge;
 
 use crate::*;
 
-use self::equivocation_challenge::EquivocationProof;
+pub use self::conspiracy_mmr::ConspiracyMmrProof;
+pub use self::equivocation_challenge::{
+    EquivocationProof, PublicKey as GrandpaPublicKey, SignatureData,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -14,5 +18,20 @@ pub enum AppchainChallenge {
     ConspiracyMmr {
         submitter_account: AccountId,
         block_number: u32,
+        proof: ConspiracyMmrProof,
     },
 }
+
+impl AppchainChallenge {
+    ///
+    pub fn submitter_account(&self) -> &AccountId {
+        match self {
+            AppchainChallenge::EquivocationChallenge {
+                submitter_account, ..
+            } => submitter_account,
+            AppchainChallenge::ConspiracyMmr {
+                submitter_account, ..
+            } => submitter_account,
+        }
+    }
+}
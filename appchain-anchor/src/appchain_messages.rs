@@ -260,6 +260,7 @@ impl AppchainAnchor {
     pub fn internal_stage_appchain_messages(&mut self, messages: &Vec<RawMessage>) {
         let mut processing_status = self.permissionless_actions_status.get().unwrap();
         let mut appchain_messages = self.appchain_messages.get().unwrap();
+        let max_nonce_before_staging = processing_status.max_nonce_of_staged_appchain_messages;
         messages
             .iter()
             .filter(|message| {
@@ -275,6 +276,16 @@ impl AppchainAnchor {
         self.appchain_messages.set(&appchain_messages);
         processing_status.max_nonce_of_staged_appchain_messages = appchain_messages.max_nonce();
         self.permissionless_actions_status.set(&processing_status);
+        if processing_status.max_nonce_of_staged_appchain_messages > max_nonce_before_staging {
+            self.internal_record_relayer_workload(
+                u64::from(
+                    processing_status.max_nonce_of_staged_appchain_messages
+                        - max_nonce_before_staging,
+                ),
+                0,
+                0,
+            );
+        }
     }
     //
     fn internal_stage_raw_message(
@@ -0,0 +1,229 @@
+use core::convert::TryFrom;
+use near_contract_standards::fungible_token::core::ext_ft_core;
+use near_sdk::json_types::I128;
+use std::str::FromStr;
+
+use super::native_near_token::PREFIX_OF_RECEIVER_SUB_ACCOUNT;
+use crate::{interfaces::BridgeFeeManager, *};
+
+/// The denominator of bridge fee in basis points.
+const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
+
+pub trait BridgeFeeResolver {
+    /// Resolver for claiming accrued bridge fees
+    fn resolve_bridge_fee_claim(
+        &mut self,
+        asset: BridgingAsset,
+        receiver_id: AccountId,
+        amount: U128,
+    );
+}
+
+impl BridgeFee {
+    ///
+    pub fn calculate(&self, amount: u128) -> u128 {
+        match self {
+            BridgeFee::Flat(fee) => fee.0,
+            BridgeFee::BasisPoints(basis_points) => {
+                amount / BASIS_POINTS_DENOMINATOR * u128::from(*basis_points)
+                    + amount % BASIS_POINTS_DENOMINATOR * u128::from(*basis_points)
+                        / BASIS_POINTS_DENOMINATOR
+            }
+        }
+    }
+}
+
+impl AppchainAnchor {
+    /// Calculate the bridge fee of the given asset and amount.
+    ///
+    /// The amount must be greater than the fee.
+    pub fn internal_calculate_bridge_fee(&self, asset: &BridgingAsset, amount: &U128) -> U128 {
+        let fee = self
+            .bridge_fees
+            .get(asset)
+            .map_or(0, |bridge_fee| bridge_fee.calculate(amount.0));
+        assert!(
+            amount.0 > fee,
+            "The amount '{}' is not enough to pay the bridge fee '{}'.",
+            amount.0,
+            fee
+        );
+        U128::from(fee)
+    }
+    ///
+    pub fn internal_accrue_bridge_fee(&mut self, asset: &BridgingAsset, fee: &U128) {
+        if fee.0 == 0 {
+            return;
+        }
+        let accrued_amount = self.accrued_bridge_fees.get(asset).unwrap_or(U128::from(0));
+        self.accrued_bridge_fees
+            .insert(asset, &U128::from(accrued_amount.0 + fee.0));
+    }
+    ///
+    pub fn get_near_vault_account(&self) -> AccountId {
+        AccountId::from_str(
+            format!(
+                "{}.{}",
+                PREFIX_OF_RECEIVER_SUB_ACCOUNT,
+                env::current_account_id()
+            )
+            .as_str(),
+        )
+        .unwrap()
+    }
+}
+
+#[near_bindgen]
+impl BridgeFeeManager for AppchainAnchor {
+    //
+    fn set_bridge_fee(&mut self, asset: BridgingAsset, fee: Option<BridgeFee>) {
+        self.assert_owner();
+        match fee {
+            Some(fee) => {
+                match asset {
+                    BridgingAsset::WrappedAppchainNFT { .. } => assert!(
+                        match fee {
+                            BridgeFee::Flat(_) => true,
+                            BridgeFee::BasisPoints(_) => false,
+                        },
+                        "The bridge fee of wrapped appchain NFT must be flat."
+                    ),
+                    _ => (),
+                }
+                if let BridgeFee::BasisPoints(basis_points) = fee {
+                    assert!(
+                        u128::from(basis_points) < BASIS_POINTS_DENOMINATOR,
+                        "The basis points of bridge fee must be less than {}.",
+                        BASIS_POINTS_DENOMINATOR
+                    );
+                }
+                self.bridge_fees.insert(&asset, &fee);
+            }
+            None => {
+                self.bridge_fees.remove(&asset);
+            }
+        }
+    }
+    //
+    fn claim_bridge_fees(&mut self, asset: BridgingAsset) {
+        self.assert_relayer();
+        // The fees are paid to the caller, which may be any member of role `Relayer`.
+        let relayer_account = env::predecessor_account_id();
+        let amount = self
+            .accrued_bridge_fees
+            .get(&asset)
+            .unwrap_or(U128::from(0));
+        assert!(amount.0 > 0, "No accrued bridge fee of the asset.");
+        self.accrued_bridge_fees.remove(&asset);
+        let resolver = ext_self::ext(env::current_account_id())
+            .with_attached_deposit(0)
+            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+            .with_unused_gas_weight(0)
+            .resolve_bridge_fee_claim(asset.clone(), relayer_account.clone(), amount);
+        match asset {
+            BridgingAsset::NearFungibleToken { contract_account } => {
+                ext_ft_core::ext(contract_account)
+                    .with_attached_deposit(1)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+                    .with_unused_gas_weight(0)
+                    .ft_transfer(relayer_account, amount, None)
+                    .then(resolver);
+            }
+            BridgingAsset::NativeNearToken => {
+                #[derive(near_sdk::serde::Serialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    receiver_id: AccountId,
+                    amount: U128,
+                }
+                let args = Input {
+                    receiver_id: relayer_account,
+                    amount,
+                };
+                let args = near_sdk::serde_json::to_vec(&args)
+                    .expect("Failed to serialize the cross contract args using JSON.");
+                Promise::new(self.get_near_vault_account())
+                    .function_call(
+                        "unlock_near".to_string(),
+                        args,
+                        0,
+                        Gas::ONE_TERA.mul(T_GAS_FOR_UNLOCK_NATIVE_NEAR),
+                    )
+                    .then(resolver);
+            }
+            BridgingAsset::WrappedAppchainToken => {
+                self.assert_contract_account_of_wrapped_appchain_token_is_set();
+                #[derive(near_sdk::serde::Serialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Args {
+                    account_id: AccountId,
+                    amount: U128,
+                }
+                let args = Args {
+                    account_id: relayer_account,
+                    amount,
+                };
+                let args = near_sdk::serde_json::to_vec(&args)
+                    .expect("Failed to serialize the cross contract args using JSON.");
+                Promise::new(
+                    self.wrapped_appchain_token
+                        .get()
+                        .unwrap()
+                        .contract_account
+                        .unwrap(),
+                )
+                .function_call(
+                    "mint".to_string(),
+                    args,
+                    STORAGE_DEPOSIT_FOR_NEP141_TOEKN,
+                    Gas::ONE_TERA.mul(T_GAS_FOR_MINT_FUNGIBLE_TOKEN),
+                )
+                .then(resolver);
+            }
+            BridgingAsset::WrappedAppchainNFT { .. } => {
+                panic!("The bridge fee of wrapped appchain NFT is charged in appchain.")
+            }
+        }
+    }
+}
+
+#[near_bindgen]
+impl BridgeFeeResolver for AppchainAnchor {
+    //
+    fn resolve_bridge_fee_claim(
+        &mut self,
+        asset: BridgingAsset,
+        receiver_id: AccountId,
+        amount: U128,
+    ) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                if let BridgingAsset::WrappedAppchainToken = asset {
+                    let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
+                    wrapped_appchain_token.changed_balance = I128::from(
+                        wrapped_appchain_token.changed_balance.0
+                            + i128::try_from(amount.0).unwrap(),
+                    );
+                    self.wrapped_appchain_token.set(&wrapped_appchain_token);
+                }
+                log!(
+                    "Accrued bridge fee '{}' of '{}' is claimed by '{}'.",
+                    amount.0,
+                    serde_json::to_string(&asset).unwrap(),
+                    receiver_id
+                );
+            }
+            PromiseResult::Failed => {
+                self.internal_accrue_bridge_fee(&asset, &amount);
+                log!(
+                    "Failed to claim accrued bridge fee '{}' of '{}' for '{}'. It can be claimed again later.",
+                    amount.0,
+                    serde_json::to_string(&asset).unwrap(),
+                    receiver_id
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{interfaces::BridgeRateLimitManager, *};
+
+/// The length of a bucket for recording bridging amounts, in seconds.
+const SECONDS_OF_A_BUCKET: u64 = 600;
+///
+const SECONDS_OF_AN_HOUR: u64 = 3600;
+
+type BridgeFlowKey = (BridgingAsset, BridgingDirection);
+
+fn current_bucket() -> u64 {
+    env::block_timestamp() / NANO_SECONDS_MULTIPLE / SECONDS_OF_A_BUCKET
+}
+
+fn sum_of_buckets_in_window(buckets: &Vec<(u64, u128)>, window_seconds: u64) -> u128 {
+    let current_bucket = current_bucket();
+    buckets
+        .iter()
+        .filter(|(bucket, _)| bucket + window_seconds / SECONDS_OF_A_BUCKET > current_bucket)
+        .map(|(_, amount)| amount)
+        .sum()
+}
+
+impl AppchainAnchor {
+    // Get the bridging amounts of the last day, grouped by buckets.
+    fn get_buckets_of_bridge_flow(&self, key: &BridgeFlowKey) -> Vec<(u64, u128)> {
+        let current_bucket = current_bucket();
+        self.bridge_flow_buckets
+            .get(key)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(bucket, _)| bucket + SECONDS_OF_A_DAY / SECONDS_OF_A_BUCKET > current_bucket)
+            .collect()
+    }
+    /// Check the rate limit of the given asset and direction for the given amount.
+    ///
+    /// If the rate limit is exceeded, the bridging of the asset in the direction
+    /// will be paused automatically. The caller should reject the transfer without panicking,
+    /// otherwise the pausing will be reverted.
+    pub fn internal_check_bridge_flow(
+        &mut self,
+        asset: &BridgingAsset,
+        direction: &BridgingDirection,
+        amount: &U128,
+    ) -> Result<(), String> {
+        let key = (asset.clone(), direction.clone());
+        if self.paused_bridge_flows.contains(&key) {
+            return Err(format!(
+                "Bridging of '{}' in direction '{}' is paused.",
+                serde_json::to_string(asset).unwrap(),
+                serde_json::to_string(direction).unwrap()
+            ));
+        }
+        let buckets = self.get_buckets_of_bridge_flow(&key);
+        if let Some(rate_limit) = self.bridge_rate_limits.get(&key) {
+            let mut exceeded_window = None;
+            if let Some(maximum_amount) = rate_limit.maximum_amount_per_hour {
+                if sum_of_buckets_in_window(&buckets, SECONDS_OF_AN_HOUR) + amount.0
+                    > maximum_amount.0
+                {
+                    exceeded_window = Some("hour");
+                }
+            }
+            if let Some(maximum_amount) = rate_limit.maximum_amount_per_day {
+                if sum_of_buckets_in_window(&buckets, SECONDS_OF_A_DAY) + amount.0
+                    > maximum_amount.0
+                {
+                    exceeded_window = Some("day");
+                }
+            }
+            if let Some(window) = exceeded_window {
+                self.paused_bridge_flows.insert(&key);
+                log!(
+                    "Bridging of '{}' in direction '{}' is paused automatically.",
+                    serde_json::to_string(asset).unwrap(),
+                    serde_json::to_string(direction).unwrap()
+                );
+                return Err(format!(
+                    "Rate limit per {} of '{}' in direction '{}' is exceeded. Amount: '{}'.",
+                    window,
+                    serde_json::to_string(asset).unwrap(),
+                    serde_json::to_string(direction).unwrap(),
+                    amount.0
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// Record the amount of a bridging which has passed the checking of rate limit
+    /// and is processed successfully.
+    pub fn internal_record_bridge_flow(
+        &mut self,
+        asset: &BridgingAsset,
+        direction: &BridgingDirection,
+        amount: &U128,
+    ) {
+        let key = (asset.clone(), direction.clone());
+        let mut buckets = self.get_buckets_of_bridge_flow(&key);
+        let current_bucket = current_bucket();
+        match buckets.last_mut() {
+            Some((bucket, bucket_amount)) if *bucket == current_bucket => {
+                *bucket_amount += amount.0;
+            }
+            _ => buckets.push((current_bucket, amount.0)),
+        }
+        self.bridge_flow_buckets.insert(&key, &buckets);
+    }
+    ///
+    pub fn internal_get_bridge_flow_status(
+        &self,
+        asset: &BridgingAsset,
+        direction: &BridgingDirection,
+    ) -> BridgeFlowStatus {
+        let key = (asset.clone(), direction.clone());
+        let buckets = self.get_buckets_of_bridge_flow(&key);
+        BridgeFlowStatus {
+            asset: asset.clone(),
+            direction: direction.clone(),
+            rate_limit: self.bridge_rate_limits.get(&key),
+            amount_in_last_hour: U128::from(sum_of_buckets_in_window(&buckets, SECONDS_OF_AN_HOUR)),
+            amount_in_last_day: U128::from(sum_of_buckets_in_window(&buckets, SECONDS_OF_A_DAY)),
+            is_paused: self.paused_bridge_flows.contains(&key),
+        }
+    }
+}
+
+#[near_bindgen]
+impl BridgeRateLimitManager for AppchainAnchor {
+    //
+    fn set_bridge_rate_limit(
+        &mut self,
+        asset: BridgingAsset,
+        direction: BridgingDirection,
+        rate_limit: Option<BridgeRateLimit>,
+    ) {
+        self.assert_owner();
+        match rate_limit {
+            Some(rate_limit) => {
+                self.bridge_rate_limits
+                    .insert(&(asset, direction), &rate_limit);
+            }
+            None => {
+                self.bridge_rate_limits.remove(&(asset, direction));
+            }
+        }
+    }
+    //
+    fn pause_bridge_flow(&mut self, asset: BridgingAsset, direction: BridgingDirection) {
+        self.assert_pauser();
+        assert!(
+            self.paused_bridge_flows.insert(&(asset, direction)),
+            "The bridging of the asset in the direction is already paused."
+        );
+    }
+    //
+    fn resume_bridge_flow(&mut self, asset: BridgingAsset, direction: BridgingDirection) {
+        self.assert_operator();
+        assert!(
+            self.paused_bridge_flows.remove(&(asset, direction)),
+            "The bridging of the asset in the direction is not paused."
+        );
+    }
+}
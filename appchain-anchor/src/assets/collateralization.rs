@@ -0,0 +1,189 @@
+use crate::*;
+
+impl AppchainAnchor {
+    // Get the market value of the wrapped appchain NFTs locked in this contract,
+    // valued by the floor price of each class.
+    fn get_market_value_of_locked_wrapped_appchain_nfts(&self) -> Balance {
+        self.wrapped_appchain_nfts
+            .get()
+            .unwrap()
+            .to_vec()
+            .iter()
+            .map(|wrapped_appchain_nft| {
+                u128::from(wrapped_appchain_nft.count_of_locked_tokens.0)
+                    * self
+                        .floor_prices_of_wrapped_appchain_nfts
+                        .get(&wrapped_appchain_nft.class_id)
+                        .map_or(0, |price| price.0)
+            })
+            .sum()
+    }
+    ///
+    pub fn internal_get_collateralization_status(&self) -> CollateralizationStatus {
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        let native_near_token = self.native_near_token.get().unwrap();
+        let market_value_of_staked_oct_token = self.get_market_value_of_staked_oct_token().0;
+        let market_value_of_near_fungible_tokens = self
+            .near_fungible_tokens
+            .get()
+            .unwrap()
+            .total_market_value();
+        let market_value_of_native_near_token =
+            native_near_token.locked_balance.0 * native_near_token.price_in_usd.0;
+        let market_value_of_locked_wrapped_appchain_nfts =
+            self.get_market_value_of_locked_wrapped_appchain_nfts();
+        let market_value_of_wrapped_appchain_token = self
+            .wrapped_appchain_token
+            .get()
+            .unwrap()
+            .bridged_market_value();
+        let market_value_of_bridged_assets = market_value_of_near_fungible_tokens
+            + market_value_of_native_near_token
+            + market_value_of_locked_wrapped_appchain_nfts
+            + market_value_of_wrapped_appchain_token;
+        CollateralizationStatus {
+            market_value_of_staked_oct_token: U128::from(market_value_of_staked_oct_token),
+            market_value_of_near_fungible_tokens: U128::from(market_value_of_near_fungible_tokens),
+            market_value_of_native_near_token: U128::from(market_value_of_native_near_token),
+            market_value_of_locked_wrapped_appchain_nfts: U128::from(
+                market_value_of_locked_wrapped_appchain_nfts,
+            ),
+            market_value_of_wrapped_appchain_token: U128::from(
+                market_value_of_wrapped_appchain_token,
+            ),
+            maximum_market_value_of_locked_assets: U128::from(
+                market_value_of_staked_oct_token
+                    * u128::from(
+                        protocol_settings.maximum_market_value_percent_of_near_fungible_tokens,
+                    )
+                    / 100,
+            ),
+            maximum_market_value_of_wrapped_appchain_token: U128::from(
+                market_value_of_staked_oct_token
+                    * u128::from(
+                        protocol_settings.maximum_market_value_percent_of_wrapped_appchain_token,
+                    )
+                    / 100,
+            ),
+            collateralization_ratio_percent: match market_value_of_bridged_assets {
+                0 => None,
+                _ => Some(U128::from(
+                    market_value_of_staked_oct_token * 100 / market_value_of_bridged_assets,
+                )),
+            },
+        }
+    }
+    /// Check whether the staked OCT token can still cover the market value of
+    /// bridged assets, after the given amount of an asset is locked in or minted by
+    /// this contract.
+    ///
+    /// It should only be called for the transfers of assets between NEAR protocol and appchain.
+    /// The premined balance of wrapped appchain token is not counted as bridged asset.
+    ///
+    /// The assets locked in this contract (NEAR fungible tokens, native NEAR token and
+    /// wrapped appchain NFTs) are limited by `maximum_market_value_percent_of_near_fungible_tokens`,
+    /// and the wrapped appchain token is limited by `maximum_market_value_percent_of_wrapped_appchain_token`.
+    pub fn internal_check_collateralization(
+        &self,
+        asset: &BridgingAsset,
+        amount: &U128,
+    ) -> Result<(), String> {
+        let status = self.internal_get_collateralization_status();
+        match asset {
+            BridgingAsset::WrappedAppchainToken => {
+                let market_value = self
+                    .wrapped_appchain_token
+                    .get()
+                    .unwrap()
+                    .get_market_value_of(amount.0);
+                if status.market_value_of_wrapped_appchain_token.0 + market_value
+                    > status.maximum_market_value_of_wrapped_appchain_token.0
+                {
+                    return Err(format!("Too much wrapped appchain token to mint."));
+                }
+            }
+            _ => {
+                let market_value = match asset {
+                    BridgingAsset::NearFungibleToken { contract_account } => {
+                        let near_fungible_tokens = self.near_fungible_tokens.get().unwrap();
+                        near_fungible_tokens
+                            .get_by_contract_account(contract_account)
+                            .map_or(0, |near_fungible_token| {
+                                near_fungible_tokens.get_market_value_of(
+                                    &near_fungible_token.metadata.symbol,
+                                    amount.0,
+                                )
+                            })
+                    }
+                    BridgingAsset::NativeNearToken => {
+                        amount.0 * self.native_near_token.get().unwrap().price_in_usd.0
+                    }
+                    BridgingAsset::WrappedAppchainNFT { class_id } => {
+                        amount.0
+                            * self
+                                .floor_prices_of_wrapped_appchain_nfts
+                                .get(class_id)
+                                .map_or(0, |price| price.0)
+                    }
+                    BridgingAsset::WrappedAppchainToken => unreachable!(),
+                };
+                if status.market_value_of_near_fungible_tokens.0
+                    + status.market_value_of_native_near_token.0
+                    + status.market_value_of_locked_wrapped_appchain_nfts.0
+                    + market_value
+                    > status.maximum_market_value_of_locked_assets.0
+                {
+                    return Err(format!(
+                        "Too much '{}' to lock.",
+                        serde_json::to_string(asset).unwrap()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Assert the collateralization and the freshness of prices for locking an asset
+    /// in this contract.
+    pub fn assert_locked_asset_on_near_side(&self, asset: &BridgingAsset, amount: &U128) {
+        self.assert_price_is_not_stale(&PricedAsset::OctToken);
+        match asset {
+            BridgingAsset::NearFungibleToken { contract_account } => {
+                if let Some(near_fungible_token) = self
+                    .near_fungible_tokens
+                    .get()
+                    .unwrap()
+                    .get_by_contract_account(contract_account)
+                {
+                    self.assert_price_is_not_stale(&PricedAsset::NearFungibleToken {
+                        symbol: near_fungible_token.metadata.symbol,
+                    });
+                }
+            }
+            BridgingAsset::NativeNearToken => {
+                self.assert_price_is_not_stale(&PricedAsset::NativeNearToken)
+            }
+            BridgingAsset::WrappedAppchainNFT { class_id } => {
+                // The NFTs without floor price can not be valued, so they are
+                // not counted in the collateralization.
+                if self
+                    .floor_prices_of_wrapped_appchain_nfts
+                    .get(class_id)
+                    .map_or(true, |price| price.0 == 0)
+                {
+                    log!(
+                        "The floor price of wrapped appchain NFT of class '{}' is not set, skip valuation of it.",
+                        class_id
+                    );
+                    return;
+                }
+                self.assert_price_is_not_stale(&PricedAsset::WrappedAppchainNFT {
+                    class_id: class_id.clone(),
+                })
+            }
+            BridgingAsset::WrappedAppchainToken => (),
+        }
+        if let Err(message) = self.internal_check_collateralization(asset, amount) {
+            panic!("{}", message);
+        }
+    }
+}
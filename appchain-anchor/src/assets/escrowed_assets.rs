@@ -0,0 +1,222 @@
+use near_contract_standards::fungible_token::core::ext_ft_core;
+
+use crate::{interfaces::EscrowedAssetManager, *};
+
+pub trait EscrowedAssetResolver {
+    /// Resolver for claiming an escrowed asset
+    fn resolve_escrowed_asset_claim(&mut self, receiver_id: AccountId, asset: EscrowedAsset);
+}
+
+impl AppchainAnchor {
+    /// Credit an asset whose delivery failed to the escrow ledger of the receiver.
+    pub fn internal_escrow_asset(&mut self, receiver_id: &AccountId, asset: EscrowedAsset) {
+        let mut escrowed_assets = self.escrowed_assets.get(receiver_id).unwrap_or_default();
+        match &asset {
+            EscrowedAsset::NearFungibleToken {
+                contract_account,
+                amount,
+            } => {
+                if let Some(EscrowedAsset::NearFungibleToken {
+                    amount: escrowed_amount,
+                    ..
+                }) = escrowed_assets
+                    .iter_mut()
+                    .find(|escrowed_asset| match escrowed_asset {
+                        EscrowedAsset::NearFungibleToken {
+                            contract_account: escrowed_contract_account,
+                            ..
+                        } => escrowed_contract_account.eq(contract_account),
+                        _ => false,
+                    })
+                {
+                    *escrowed_amount = U128::from(escrowed_amount.0 + amount.0);
+                } else {
+                    escrowed_assets.push(asset.clone());
+                }
+            }
+            EscrowedAsset::LockedWrappedAppchainNFT { .. }
+            | EscrowedAsset::UnmintedWrappedAppchainNFT { .. } => {
+                escrowed_assets.push(asset.clone())
+            }
+        }
+        self.escrowed_assets.insert(receiver_id, &escrowed_assets);
+        log!(
+            "Asset '{}' is escrowed for '{}'. It can be claimed by the receiver later.",
+            serde_json::to_string(&asset).unwrap(),
+            receiver_id
+        );
+    }
+    // Get the bridging asset of an escrowed asset, which is used to check
+    // the pause status of the bridge flow of it.
+    fn get_bridging_asset_of(asset: &EscrowedAsset) -> BridgingAsset {
+        match asset {
+            EscrowedAsset::NearFungibleToken {
+                contract_account, ..
+            } => BridgingAsset::NearFungibleToken {
+                contract_account: contract_account.clone(),
+            },
+            EscrowedAsset::LockedWrappedAppchainNFT { class_id, .. }
+            | EscrowedAsset::UnmintedWrappedAppchainNFT { class_id, .. } => {
+                BridgingAsset::WrappedAppchainNFT {
+                    class_id: class_id.clone(),
+                }
+            }
+        }
+    }
+    //
+    fn deliver_escrowed_asset(&self, receiver_id: &AccountId, asset: &EscrowedAsset) {
+        let resolver = ext_self::ext(env::current_account_id())
+            .with_attached_deposit(0)
+            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+            .with_unused_gas_weight(0)
+            .resolve_escrowed_asset_claim(receiver_id.clone(), asset.clone());
+        match asset {
+            EscrowedAsset::NearFungibleToken {
+                contract_account,
+                amount,
+            } => {
+                ext_ft_core::ext(contract_account.clone())
+                    .with_attached_deposit(1)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+                    .with_unused_gas_weight(0)
+                    .ft_transfer(receiver_id.clone(), amount.clone(), None)
+                    .then(resolver);
+            }
+            EscrowedAsset::LockedWrappedAppchainNFT {
+                class_id,
+                instance_id,
+            } => {
+                #[derive(near_sdk::serde::Serialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Args {
+                    receiver_id: AccountId,
+                    token_id: TokenId,
+                    approval_id: Option<u64>,
+                    memo: Option<String>,
+                }
+                let args = Args {
+                    receiver_id: receiver_id.clone(),
+                    token_id: instance_id.clone(),
+                    approval_id: None,
+                    memo: None,
+                };
+                let args = near_sdk::serde_json::to_vec(&args)
+                    .expect("Failed to serialize the cross contract args using JSON.");
+                Promise::new(self.get_contract_account_of_wrapped_appchain_nft(class_id))
+                    .function_call(
+                        "nft_transfer".to_string(),
+                        args,
+                        1,
+                        Gas::ONE_TERA.mul(T_GAS_FOR_NFT_TRANSFER),
+                    )
+                    .then(resolver);
+            }
+            EscrowedAsset::UnmintedWrappedAppchainNFT {
+                class_id,
+                instance_id,
+                token_metadata,
+            } => {
+                #[derive(near_sdk::serde::Serialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Args {
+                    token_id: TokenId,
+                    token_owner_id: AccountId,
+                    token_metadata: TokenMetadata,
+                }
+                let args = Args {
+                    token_id: instance_id.clone(),
+                    token_owner_id: receiver_id.clone(),
+                    token_metadata: token_metadata.clone(),
+                };
+                let args = near_sdk::serde_json::to_vec(&args)
+                    .expect("Failed to serialize the cross contract args using JSON.");
+                Promise::new(self.get_contract_account_of_wrapped_appchain_nft(class_id))
+                    .function_call(
+                        "nft_mint".to_string(),
+                        args,
+                        STORAGE_DEPOSIT_FOR_MINT_NFT,
+                        Gas::ONE_TERA.mul(T_GAS_FOR_MINT_NFT),
+                    )
+                    .then(resolver);
+            }
+        }
+    }
+    //
+    fn get_contract_account_of_wrapped_appchain_nft(&self, class_id: &String) -> AccountId {
+        match self.wrapped_appchain_nfts.get().unwrap().get(class_id) {
+            Some(wrapped_appchain_nft) => wrapped_appchain_nft.contract_account,
+            None => panic!(
+                "Unregistered class id of wrapped appchain NFT: '{}'",
+                class_id
+            ),
+        }
+    }
+}
+
+#[near_bindgen]
+impl EscrowedAssetManager for AppchainAnchor {
+    //
+    fn claim_escrowed_assets(&mut self) {
+        self.assert_asset_transfer_is_not_paused();
+        let receiver_id = env::predecessor_account_id();
+        let escrowed_assets = self.escrowed_assets.get(&receiver_id).unwrap_or_default();
+        assert!(
+            escrowed_assets.len() > 0,
+            "There is no escrowed asset for '{}'.",
+            receiver_id
+        );
+        // The assets whose bridge flow to NEAR is paused are kept in escrow.
+        let (paused_assets, assets_to_deliver): (Vec<EscrowedAsset>, Vec<EscrowedAsset>) =
+            escrowed_assets.into_iter().partition(|asset| {
+                self.paused_bridge_flows.contains(&(
+                    AppchainAnchor::get_bridging_asset_of(asset),
+                    BridgingDirection::AppchainToNear,
+                ))
+            });
+        assert!(
+            assets_to_deliver.len() > 0,
+            "Bridging of all escrowed assets of '{}' to NEAR is paused.",
+            receiver_id
+        );
+        if paused_assets.len() > 0 {
+            self.escrowed_assets.insert(&receiver_id, &paused_assets);
+        } else {
+            self.escrowed_assets.remove(&receiver_id);
+        }
+        for asset in assets_to_deliver.iter() {
+            self.deliver_escrowed_asset(&receiver_id, asset);
+        }
+    }
+}
+
+#[near_bindgen]
+impl EscrowedAssetResolver for AppchainAnchor {
+    //
+    fn resolve_escrowed_asset_claim(&mut self, receiver_id: AccountId, asset: EscrowedAsset) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                if let EscrowedAsset::LockedWrappedAppchainNFT {
+                    class_id,
+                    instance_id,
+                } = &asset
+                {
+                    let mut wrapped_appchain_nfts = self.wrapped_appchain_nfts.get().unwrap();
+                    if let Some(mut wrapped_appchain_nft) = wrapped_appchain_nfts.get(class_id) {
+                        wrapped_appchain_nft.remove_locked_nft(instance_id);
+                        wrapped_appchain_nfts.insert(class_id, &wrapped_appchain_nft);
+                    };
+                }
+                log!(
+                    "Escrowed asset '{}' is claimed by '{}'.",
+                    serde_json::to_string(&asset).unwrap(),
+                    receiver_id
+                );
+            }
+            PromiseResult::Failed => {
+                self.internal_escrow_asset(&receiver_id, asset);
+            }
+        }
+    }
+}
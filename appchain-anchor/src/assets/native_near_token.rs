@@ -52,14 +52,7 @@ impl NativeNearTokenManager for AppchainAnchor {
     }
     ///
     fn set_price_of_native_near_token(&mut self, price: U128) {
-        self.assert_owner();
-        let mut native_near_token = self.native_near_token.get().unwrap();
-        assert!(
-            native_near_token.price_in_usd.0 != price.0,
-            "The price is not changed."
-        );
-        native_near_token.price_in_usd = price;
-        self.native_near_token.set(&native_near_token);
+        self.internal_submit_price(PricedAsset::NativeNearToken, price);
     }
     ///
     fn open_bridging_of_native_near_token(&mut self) {
@@ -89,7 +82,7 @@ impl NativeNearTokenManager for AppchainAnchor {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
-    ) {
+    ) -> bool {
         assert!(
             env::predecessor_account_id().eq(&AccountId::from_str(
                 format!(
@@ -108,24 +101,45 @@ impl NativeNearTokenManager for AppchainAnchor {
             native_near_token.bridging_state.eq(&BridgingState::Active),
             "Bridging for native NEAR token is closed."
         );
-        self.assert_locked_asset_on_near_side(None, &amount);
+        let fee = self.internal_calculate_bridge_fee(&BridgingAsset::NativeNearToken, &amount);
+        let amount = U128::from(amount.0 - fee.0);
+        if let Err(message) = self.internal_check_bridge_flow(
+            &BridgingAsset::NativeNearToken,
+            &BridgingDirection::NearToAppchain,
+            &amount,
+        ) {
+            // Reject the deposit without panicking, to keep the automatic pausing.
+            // The deposit will be refunded by the near vault contract.
+            log!("{}", message);
+            return false;
+        }
+        self.assert_locked_asset_on_near_side(&BridgingAsset::NativeNearToken, &amount);
         native_near_token.locked_balance =
             U128::from(native_near_token.locked_balance.0 + amount.0);
         self.native_near_token.set(&native_near_token);
+        self.internal_record_bridge_flow(
+            &BridgingAsset::NativeNearToken,
+            &BridgingDirection::NearToAppchain,
+            &amount,
+        );
+        self.internal_accrue_bridge_fee(&BridgingAsset::NativeNearToken, &fee);
         let appchain_notification_history = self.internal_append_appchain_notification(
             AppchainNotification::NearFungibleTokenLocked {
                 contract_account: String::from_str(CONTRACT_ACCOUNT_FOR_NATIVE_NEAR_TOKEN).unwrap(),
                 sender_id_in_near,
                 receiver_id_in_appchain: receiver_id_in_appchain.clone(),
                 amount,
+                fee,
             },
         );
         log!(
-            "Received native NEAR token. Start transfer to '{}' of appchain. Amount: '{}', Crosschain notification index: '{}'.",
+            "Received native NEAR token. Start transfer to '{}' of appchain. Amount: '{}', Fee: '{}', Crosschain notification index: '{}'.",
             &receiver_id_in_appchain,
             &amount.0,
+            &fee.0,
             &appchain_notification_history.index.0
         );
+        true
     }
 }
 
@@ -166,6 +180,7 @@ impl NativeNearToken {
             Gas::ONE_TERA.mul(T_GAS_FOR_UNLOCK_NATIVE_NEAR),
         );
         processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_UNLOCK_NATIVE_NEAR));
+        processing_context.record_asset_transfer();
         self.locked_balance = U128::from(self.locked_balance.0 - amount.0);
         MultiTxsOperationProcessingResult::Ok
     }
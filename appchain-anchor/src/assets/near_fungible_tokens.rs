@@ -162,16 +162,13 @@ impl NearFungibleTokenManager for AppchainAnchor {
     }
     //
     fn set_price_of_near_fungible_token(&mut self, symbol: String, price: U128) {
-        self.assert_token_price_maintainer();
-        let mut near_fungible_tokens = self.near_fungible_tokens.get().unwrap();
+        let near_fungible_tokens = self.near_fungible_tokens.get().unwrap();
         assert!(
             near_fungible_tokens.contains(&symbol),
             "Token '{}' is not registered.",
             &symbol
         );
-        let mut near_fungible_token = near_fungible_tokens.get(&symbol).unwrap();
-        near_fungible_token.price_in_usd = price;
-        near_fungible_tokens.insert(&near_fungible_token);
+        self.internal_submit_price(PricedAsset::NearFungibleToken { symbol }, price);
     }
     //
     fn open_bridging_of_near_fungible_token(&mut self, symbol: String) {
@@ -233,27 +230,42 @@ impl AppchainAnchor {
                     receiver_id_in_appchain,
                 } => {
                     AccountIdInAppchain::new(Some(receiver_id_in_appchain.clone()), &self.appchain_template_type).assert_valid();
-                    self.assert_locked_asset_on_near_side(Some((&near_fungible_token, &amount)), &U128::from(0));
+                    let asset = BridgingAsset::NearFungibleToken {
+                        contract_account: near_fungible_token.contract_account.clone(),
+                    };
+                    let deposit_amount = amount;
+                    let fee = self.internal_calculate_bridge_fee(&asset, &amount);
+                    let amount = U128::from(amount.0 - fee.0);
+                    if let Err(message) = self.internal_check_bridge_flow(&asset, &BridgingDirection::NearToAppchain, &amount) {
+                        // Return the deposit without panicking, to keep the automatic pausing.
+                        log!("{} Return deposit.", message);
+                        return PromiseOrValue::Value(deposit_amount);
+                    }
+                    self.assert_locked_asset_on_near_side(&asset, &amount);
                     near_fungible_token.locked_balance =
                         match near_fungible_token.locked_balance.0.checked_add(amount.0) {
                             Some(value) => U128::from(value),
                             None => panic!("Locked balance overflow. Return deposit."),
                         };
                     near_fungible_tokens.insert(&near_fungible_token);
+                    self.internal_record_bridge_flow(&asset, &BridgingDirection::NearToAppchain, &amount);
+                    self.internal_accrue_bridge_fee(&asset, &fee);
                     let appchain_notification_history = self.internal_append_appchain_notification(
                         AppchainNotification::NearFungibleTokenLocked {
                             contract_account: near_fungible_token.contract_account.to_string(),
                             sender_id_in_near: sender_id.clone(),
                             receiver_id_in_appchain: receiver_id_in_appchain.clone(),
                             amount,
+                            fee,
                         },
                     );
                     log!(
-                        "Received fungible token in contract '{}' from '{}'. Start transfer to '{}' of appchain. Amount: '{}', Crosschain notification index: '{}'.",
+                        "Received fungible token in contract '{}' from '{}'. Start transfer to '{}' of appchain. Amount: '{}', Fee: '{}', Crosschain notification index: '{}'.",
                         &near_fungible_token.contract_account,
                         &sender_id.clone(),
                         &receiver_id_in_appchain,
                         &amount.0,
+                        &fee.0,
                         &appchain_notification_history.index.0
                     );
                     return PromiseOrValue::Value(0.into());
@@ -282,9 +294,11 @@ impl AppchainAnchor {
         if let Some(mut near_fungible_token) =
             near_fungible_tokens.get_by_contract_account(&contract_account)
         {
+            // The locked token can still be unlocked while the appchain is closing.
             if near_fungible_token
                 .bridging_state
                 .eq(&BridgingState::Closed)
+                && !self.appchain_state.eq(&AppchainState::Closing)
             {
                 let message = format!(
                     "Bridging for NEAR fungible token in contract '{}' is now closed.",
@@ -303,26 +317,41 @@ impl AppchainAnchor {
                     None => U128::from(0),
                 };
             near_fungible_tokens.insert(&near_fungible_token);
-            ext_ft_core::ext(near_fungible_token.contract_account)
+            let ft_transfer = ext_ft_core::ext(near_fungible_token.contract_account.clone())
                 .with_attached_deposit(1)
                 .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
                 .with_unused_gas_weight(0)
-                .ft_transfer(receiver_id_in_near.clone(), amount.clone(), None)
-                .then(
-                    ext_self::ext(env::current_account_id())
-                        .with_attached_deposit(0)
-                        .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
-                        .with_unused_gas_weight(0)
-                        .resolve_fungible_token_transfer(
-                            near_fungible_token.metadata.symbol,
-                            sender_id_in_appchain.clone(),
-                            receiver_id_in_near.clone(),
-                            amount.clone(),
-                            appchain_message_nonce,
-                        ),
-                );
+                .ft_transfer(receiver_id_in_near.clone(), amount.clone(), None);
+            let ft_transfer = match self.internal_register_storage_for_receiver(
+                &BridgingAsset::NearFungibleToken {
+                    contract_account: near_fungible_token.contract_account.clone(),
+                },
+                &near_fungible_token.contract_account,
+                receiver_id_in_near,
+            ) {
+                Some(storage_registration) => {
+                    processing_context
+                        .add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_STORAGE_REGISTRATION));
+                    storage_registration.then(ft_transfer)
+                }
+                None => ft_transfer,
+            };
+            ft_transfer.then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_fungible_token_transfer(
+                        near_fungible_token.metadata.symbol,
+                        sender_id_in_appchain.clone(),
+                        receiver_id_in_near.clone(),
+                        amount.clone(),
+                        appchain_message_nonce,
+                    ),
+            );
             processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER));
             processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION));
+            processing_context.record_asset_transfer();
             MultiTxsOperationProcessingResult::Ok
         } else {
             let message = format!(
@@ -380,6 +409,17 @@ impl FungibleTokenContractResolver for AppchainAnchor {
                         message,
                     },
                 );
+                if let Some(near_fungible_token) =
+                    self.near_fungible_tokens.get().unwrap().get(&symbol)
+                {
+                    self.internal_escrow_asset(
+                        &receiver_id_in_near,
+                        EscrowedAsset::NearFungibleToken {
+                            contract_account: near_fungible_token.contract_account,
+                            amount,
+                        },
+                    );
+                }
             }
         }
     }
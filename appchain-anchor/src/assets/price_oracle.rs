@@ -0,0 +1,209 @@
+use crate::{interfaces::PriceOracleManager, *};
+
+impl AppchainAnchor {
+    //
+    fn get_current_price_of(&self, asset: &PricedAsset) -> U128 {
+        match asset {
+            PricedAsset::OctToken => self.oct_token.get().unwrap().price_in_usd,
+            PricedAsset::WrappedAppchainToken => {
+                self.wrapped_appchain_token.get().unwrap().price_in_usd
+            }
+            PricedAsset::NearFungibleToken { symbol } => {
+                match self.near_fungible_tokens.get().unwrap().get(symbol) {
+                    Some(near_fungible_token) => near_fungible_token.price_in_usd,
+                    None => panic!("Token '{}' is not registered.", symbol),
+                }
+            }
+            PricedAsset::NativeNearToken => self.native_near_token.get().unwrap().price_in_usd,
+            PricedAsset::WrappedAppchainNFT { class_id } => self
+                .floor_prices_of_wrapped_appchain_nfts
+                .get(class_id)
+                .unwrap_or(U128::from(0)),
+        }
+    }
+    //
+    fn set_current_price_of(&mut self, asset: &PricedAsset, price: U128) {
+        match asset {
+            PricedAsset::OctToken => {
+                let mut oct_token = self.oct_token.get().unwrap();
+                oct_token.price_in_usd = price;
+                self.oct_token.set(&oct_token);
+            }
+            PricedAsset::WrappedAppchainToken => {
+                let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
+                wrapped_appchain_token.price_in_usd = price;
+                self.wrapped_appchain_token.set(&wrapped_appchain_token);
+            }
+            PricedAsset::NearFungibleToken { symbol } => {
+                let mut near_fungible_tokens = self.near_fungible_tokens.get().unwrap();
+                let mut near_fungible_token = near_fungible_tokens.get(symbol).unwrap();
+                near_fungible_token.price_in_usd = price;
+                near_fungible_tokens.insert(&near_fungible_token);
+            }
+            PricedAsset::NativeNearToken => {
+                let mut native_near_token = self.native_near_token.get().unwrap();
+                native_near_token.price_in_usd = price;
+                self.native_near_token.set(&native_near_token);
+            }
+            PricedAsset::WrappedAppchainNFT { class_id } => {
+                self.floor_prices_of_wrapped_appchain_nfts
+                    .insert(class_id, &price);
+            }
+        }
+    }
+    //
+    fn is_price_reporter(&self, account_id: &AccountId) -> bool {
+        self.has_role(&AnchorRole::PriceMaintainer, account_id)
+    }
+    // Whether a price reported at the given timestamp is stale.
+    // A zero staleness timeout means the price never becomes stale.
+    fn is_stale_price_timestamp(&self, timestamp: &U64) -> bool {
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        protocol_settings.staleness_timeout_of_token_price.0 > 0
+            && env::block_timestamp()
+                > timestamp.0
+                    + protocol_settings.staleness_timeout_of_token_price.0 * NANO_SECONDS_MULTIPLE
+    }
+    ///
+    pub fn assert_price_reporter(&self) {
+        assert!(
+            self.is_price_reporter(&env::predecessor_account_id()),
+            "This function can only be called by account with role 'PriceMaintainer'."
+        );
+    }
+    //
+    fn is_stale_price_of(&self, asset: &PricedAsset) -> bool {
+        if self
+            .protocol_settings
+            .get()
+            .unwrap()
+            .staleness_timeout_of_token_price
+            .0
+            == 0
+        {
+            return false;
+        }
+        match self.asset_price_records.get(asset) {
+            Some(price_record) => self.is_stale_price_timestamp(&price_record.updated_at),
+            None => true,
+        }
+    }
+    /// Assert that the price of an asset is updated within the staleness timeout.
+    pub fn assert_price_is_not_stale(&self, asset: &PricedAsset) {
+        assert!(
+            !self.is_stale_price_of(asset),
+            "The price of '{}' is stale. Bridging into appchain is blocked.",
+            serde_json::to_string(asset).unwrap()
+        );
+    }
+    /// Record the price submitted by the caller, and update the price of the asset
+    /// to the median of all valid submissions, if there are enough submissions.
+    ///
+    /// The price submitted by the owner overrides the current price directly.
+    pub fn internal_submit_price(&mut self, asset: PricedAsset, price: U128) {
+        assert!(price.0 > 0, "The price should not be zero.");
+        if env::predecessor_account_id().eq(&self.owner) {
+            self.asset_price_records.insert(
+                &asset,
+                &AssetPriceRecord {
+                    updated_at: U64::from(env::block_timestamp()),
+                    submissions: Vec::new(),
+                },
+            );
+            self.set_current_price_of(&asset, price);
+            log!(
+                "The price of '{}' is overridden by owner. Price: '{}'.",
+                serde_json::to_string(&asset).unwrap(),
+                price.0
+            );
+            return;
+        }
+        self.assert_price_reporter();
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        // The current price is the last accepted price of the asset.
+        let current_price = self.get_current_price_of(&asset);
+        if current_price.0 > 0 {
+            let deviation = match price.0 > current_price.0 {
+                true => price.0 - current_price.0,
+                false => current_price.0 - price.0,
+            };
+            assert!(
+                deviation
+                    <= current_price.0
+                        * u128::from(protocol_settings.maximum_price_deviation_percent_per_update)
+                        / 100,
+                "The price '{}' deviates too much from the current price '{}'.",
+                price.0,
+                current_price.0
+            );
+        }
+        let reporter = env::predecessor_account_id();
+        let mut price_record = self.asset_price_records.get(&asset).unwrap_or_default();
+        price_record.submissions = price_record
+            .submissions
+            .into_iter()
+            .filter(|submission| {
+                !submission.reporter.eq(&reporter)
+                    && self.is_price_reporter(&submission.reporter)
+                    && !self.is_stale_price_timestamp(&submission.submitted_at)
+            })
+            .collect();
+        price_record.submissions.push(PriceSubmission {
+            reporter,
+            price,
+            submitted_at: U64::from(env::block_timestamp()),
+        });
+        if price_record.submissions.len()
+            < usize::from(protocol_settings.minimum_price_submission_count)
+        {
+            log!(
+                "The price of '{}' is submitted by {} of {} required reporter(s).",
+                serde_json::to_string(&asset).unwrap(),
+                price_record.submissions.len(),
+                protocol_settings.minimum_price_submission_count
+            );
+            self.asset_price_records.insert(&asset, &price_record);
+            return;
+        }
+        price_record.updated_at = U64::from(env::block_timestamp());
+        let mut prices: Vec<u128> = price_record
+            .submissions
+            .iter()
+            .map(|submission| submission.price.0)
+            .collect();
+        prices.sort();
+        let median_price = match prices.len() % 2 {
+            0 => (prices[prices.len() / 2 - 1] + prices[prices.len() / 2]) / 2,
+            _ => prices[prices.len() / 2],
+        };
+        self.asset_price_records.insert(&asset, &price_record);
+        self.set_current_price_of(&asset, U128::from(median_price));
+    }
+    ///
+    pub fn internal_get_price_status_of(&self, asset: &PricedAsset) -> AssetPriceStatus {
+        let price_record = self.asset_price_records.get(asset);
+        AssetPriceStatus {
+            asset: asset.clone(),
+            price: self.get_current_price_of(asset),
+            updated_at: price_record
+                .as_ref()
+                .map(|price_record| price_record.updated_at.clone()),
+            is_stale: self.is_stale_price_of(asset),
+            submissions: price_record.map_or(Vec::new(), |price_record| price_record.submissions),
+        }
+    }
+}
+
+#[near_bindgen]
+impl PriceOracleManager for AppchainAnchor {
+    //
+    fn add_price_reporter(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.internal_grant_role(AnchorRole::PriceMaintainer, account_id);
+    }
+    //
+    fn remove_price_reporter(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.internal_revoke_role(AnchorRole::PriceMaintainer, account_id);
+    }
+}
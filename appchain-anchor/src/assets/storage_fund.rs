@@ -0,0 +1,295 @@
+use crate::{interfaces::StorageFundManager, *};
+use near_contract_standards::storage_management::StorageBalance;
+
+pub trait StorageFundResolver {
+    /// Resolver for querying storage balance of a receiver in a token contract
+    fn resolve_storage_balance_query_for_receiver(
+        &mut self,
+        asset: BridgingAsset,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+    ) -> PromiseOrValue<()>;
+    /// Resolver for registering storage of a receiver in a token contract
+    fn resolve_storage_deposit_for_receiver(
+        &mut self,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    );
+    /// Resolver for topping up storage fund with accrued bridge fees of native NEAR token
+    fn resolve_storage_fund_topping_up(&mut self, amount: U128);
+}
+
+impl AppchainAnchor {
+    /// Take the storage deposit budget of an asset from storage fund.
+    ///
+    /// Returns `None` if the auto storage registration is not enabled for the asset,
+    /// or the balance of storage fund is not enough.
+    pub fn internal_take_storage_deposit_budget(&mut self, asset: &BridgingAsset) -> Option<u128> {
+        let budget = self.storage_deposit_budgets.get(asset)?;
+        let mut storage_fund = self.storage_fund.get().unwrap();
+        if storage_fund.balance.0 < budget.0 {
+            log!(
+                "The balance of storage fund is not enough. Balance: '{}', Budget: '{}'.",
+                storage_fund.balance.0,
+                budget.0
+            );
+            return None;
+        }
+        storage_fund.balance = U128::from(storage_fund.balance.0 - budget.0);
+        storage_fund.spent_amount = U128::from(storage_fund.spent_amount.0 + budget.0);
+        self.storage_fund.set(&storage_fund);
+        Some(budget.0)
+    }
+    /// Generate the promise for registering storage of a receiver in a token contract,
+    /// if the auto storage registration is enabled for the asset.
+    ///
+    /// The storage balance of the receiver is queried first, the storage deposit budget
+    /// is only taken from storage fund if the receiver is not registered yet.
+    pub fn internal_register_storage_for_receiver(
+        &mut self,
+        asset: &BridgingAsset,
+        contract_account: &AccountId,
+        receiver_id: &AccountId,
+    ) -> Option<Promise> {
+        if !self.storage_deposit_budgets.contains_key(asset) {
+            return None;
+        }
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Args {
+            account_id: AccountId,
+        }
+        let args = Args {
+            account_id: receiver_id.clone(),
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Some(
+            Promise::new(contract_account.clone())
+                .function_call(
+                    "storage_balance_of".to_string(),
+                    args,
+                    0,
+                    Gas::ONE_TERA.mul(T_GAS_FOR_STORAGE_BALANCE_OF),
+                )
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_attached_deposit(0)
+                        .with_static_gas(
+                            Gas::ONE_TERA
+                                .mul(T_GAS_FOR_STORAGE_DEPOSIT + T_GAS_FOR_RESOLVER_FUNCTION * 2),
+                        )
+                        .with_unused_gas_weight(0)
+                        .resolve_storage_balance_query_for_receiver(
+                            asset.clone(),
+                            contract_account.clone(),
+                            receiver_id.clone(),
+                        ),
+                ),
+        )
+    }
+    // Register storage of a receiver in a token contract with the budget taken from storage fund.
+    fn internal_deposit_storage_for_receiver(
+        &mut self,
+        asset: &BridgingAsset,
+        contract_account: &AccountId,
+        receiver_id: &AccountId,
+    ) -> Option<Promise> {
+        let budget = self.internal_take_storage_deposit_budget(asset)?;
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Args {
+            account_id: AccountId,
+            registration_only: bool,
+        }
+        let args = Args {
+            account_id: receiver_id.clone(),
+            registration_only: true,
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Some(
+            Promise::new(contract_account.clone())
+                .function_call(
+                    "storage_deposit".to_string(),
+                    args,
+                    budget,
+                    Gas::ONE_TERA.mul(T_GAS_FOR_STORAGE_DEPOSIT),
+                )
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_attached_deposit(0)
+                        .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                        .with_unused_gas_weight(0)
+                        .resolve_storage_deposit_for_receiver(
+                            contract_account.clone(),
+                            receiver_id.clone(),
+                            U128::from(budget),
+                        ),
+                ),
+        )
+    }
+}
+
+#[near_bindgen]
+impl AppchainAnchor {
+    /// Top up the storage fund with the attached deposit.
+    /// This function can only be called by the owner.
+    #[payable]
+    pub fn deposit_to_storage_fund(&mut self) {
+        self.assert_owner();
+        let amount = env::attached_deposit();
+        assert!(amount > 0, "Attached deposit should not be zero.");
+        let mut storage_fund = self.storage_fund.get().unwrap();
+        storage_fund.balance = U128::from(storage_fund.balance.0 + amount);
+        self.storage_fund.set(&storage_fund);
+    }
+}
+
+#[near_bindgen]
+impl StorageFundManager for AppchainAnchor {
+    //
+    fn set_storage_deposit_budget(&mut self, asset: BridgingAsset, budget: Option<U128>) {
+        self.assert_owner();
+        assert!(
+            match asset {
+                BridgingAsset::NearFungibleToken { .. } => true,
+                _ => false,
+            },
+            "Auto storage registration is only applicable to NEAR fungible tokens."
+        );
+        match budget {
+            Some(budget) => {
+                assert!(budget.0 > 0, "The budget should not be zero.");
+                self.storage_deposit_budgets.insert(&asset, &budget);
+            }
+            None => {
+                self.storage_deposit_budgets.remove(&asset);
+            }
+        }
+    }
+    //
+    fn top_up_storage_fund_with_bridge_fees(&mut self) {
+        self.assert_owner();
+        let amount = self
+            .accrued_bridge_fees
+            .get(&BridgingAsset::NativeNearToken)
+            .unwrap_or(U128::from(0));
+        assert!(amount.0 > 0, "No accrued bridge fee of native NEAR token.");
+        self.accrued_bridge_fees
+            .remove(&BridgingAsset::NativeNearToken);
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Input {
+            receiver_id: AccountId,
+            amount: U128,
+        }
+        let args = Input {
+            receiver_id: env::current_account_id(),
+            amount,
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Promise::new(self.get_near_vault_account())
+            .function_call(
+                "unlock_near".to_string(),
+                args,
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_UNLOCK_NATIVE_NEAR),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_storage_fund_topping_up(amount),
+            );
+    }
+}
+
+#[near_bindgen]
+impl StorageFundResolver for AppchainAnchor {
+    //
+    fn resolve_storage_balance_query_for_receiver(
+        &mut self,
+        asset: BridgingAsset,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+    ) -> PromiseOrValue<()> {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(value) => {
+                match near_sdk::serde_json::from_slice::<Option<StorageBalance>>(&value) {
+                    Ok(None) => {
+                        match self.internal_deposit_storage_for_receiver(
+                            &asset,
+                            &contract_account,
+                            &receiver_id,
+                        ) {
+                            Some(storage_deposit) => storage_deposit.into(),
+                            None => PromiseOrValue::Value(()),
+                        }
+                    }
+                    _ => PromiseOrValue::Value(()),
+                }
+            }
+            PromiseResult::Failed => {
+                log!(
+                    "Failed to query storage balance of '{}' in contract '{}'.",
+                    receiver_id,
+                    contract_account
+                );
+                PromiseOrValue::Value(())
+            }
+        }
+    }
+    //
+    fn resolve_storage_deposit_for_receiver(
+        &mut self,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Storage of '{}' is registered in contract '{}'. Deposit: '{}'.",
+                    receiver_id,
+                    contract_account,
+                    amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                // The attached deposit is refunded to this contract.
+                let mut storage_fund = self.storage_fund.get().unwrap();
+                storage_fund.balance = U128::from(storage_fund.balance.0 + amount.0);
+                storage_fund.spent_amount = U128::from(storage_fund.spent_amount.0 - amount.0);
+                self.storage_fund.set(&storage_fund);
+                log!(
+                    "Failed to register storage of '{}' in contract '{}'.",
+                    receiver_id,
+                    contract_account
+                );
+            }
+        }
+    }
+    //
+    fn resolve_storage_fund_topping_up(&mut self, amount: U128) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                let mut storage_fund = self.storage_fund.get().unwrap();
+                storage_fund.balance = U128::from(storage_fund.balance.0 + amount.0);
+                self.storage_fund.set(&storage_fund);
+            }
+            PromiseResult::Failed => {
+                self.internal_accrue_bridge_fee(&BridgingAsset::NativeNearToken, &amount);
+            }
+        }
+    }
+}
@@ -245,6 +245,18 @@ impl WrappedAppchainNFTManager for AppchainAnchor {
             panic!("Unregistered class id.");
         }
     }
+    //
+    fn set_floor_price_of_wrapped_appchain_nft(&mut self, class_id: String, price: U128) {
+        assert!(
+            self.wrapped_appchain_nfts
+                .get()
+                .unwrap()
+                .get(&class_id)
+                .is_some(),
+            "Unregistered class id."
+        );
+        self.internal_submit_price(PricedAsset::WrappedAppchainNFT { class_id }, price);
+    }
 }
 
 impl AppchainAnchor {
@@ -261,9 +273,12 @@ impl AppchainAnchor {
     ) -> MultiTxsOperationProcessingResult {
         let wrapped_appchain_nfts = self.wrapped_appchain_nfts.get().unwrap();
         if let Some(wrapped_appchain_nft) = wrapped_appchain_nfts.get(&class_id) {
+            // The locked NFT can still be unlocked while the appchain is closing.
             if wrapped_appchain_nft
                 .bridging_state
                 .eq(&BridgingState::Closed)
+                && !(self.appchain_state.eq(&AppchainState::Closing)
+                    && wrapped_appchain_nft.is_nft_locked(&instance_id))
             {
                 let message = format!(
                     "Bridging for wrapped appchain NFT in contract '{}' is now closed.",
@@ -316,6 +331,7 @@ impl AppchainAnchor {
                     );
                 processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_NFT_TRANSFER));
                 processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION));
+                processing_context.record_asset_transfer();
                 MultiTxsOperationProcessingResult::Ok
             } else {
                 #[derive(near_sdk::serde::Serialize)]
@@ -355,6 +371,7 @@ impl AppchainAnchor {
                     );
                 processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_MINT_NFT));
                 processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION));
+                processing_context.record_asset_transfer();
                 MultiTxsOperationProcessingResult::Ok
             }
         } else {
@@ -400,11 +417,34 @@ impl AppchainAnchor {
                         &self.appchain_template_type,
                     )
                     .assert_valid();
-                    wrapped_appchain_nft.add_locked_nft(&token_id);
                     let class_id = wrapped_appchain_nfts
                         .get_class_id_by_contract_account(&predecessor_account_id)
                         .unwrap();
+                    self.assert_bridge_flow_is_not_paused(
+                        &BridgingAsset::WrappedAppchainNFT {
+                            class_id: class_id.clone(),
+                        },
+                        &BridgingDirection::NearToAppchain,
+                    );
+                    self.assert_locked_asset_on_near_side(
+                        &BridgingAsset::WrappedAppchainNFT {
+                            class_id: class_id.clone(),
+                        },
+                        &U128::from(1),
+                    );
+                    wrapped_appchain_nft.add_locked_nft(&token_id);
                     wrapped_appchain_nfts.insert(&class_id, &wrapped_appchain_nft);
+                    // The fee of NFT can not be deducted from the NFT itself,
+                    // it is recorded in the notification and charged in appchain.
+                    let fee = self
+                        .bridge_fees
+                        .get(&BridgingAsset::WrappedAppchainNFT {
+                            class_id: class_id.clone(),
+                        })
+                        .map_or(U128::from(0), |bridge_fee| match bridge_fee {
+                            BridgeFee::Flat(fee) => fee,
+                            BridgeFee::BasisPoints(_) => U128::from(0),
+                        });
                     let appchain_notification_history = self.internal_append_appchain_notification(
                         AppchainNotification::WrappedAppchainNFTLocked {
                             class_id,
@@ -412,6 +452,7 @@ impl AppchainAnchor {
                             sender_id_in_near: sender_id.clone(),
                             owner_id_in_near: nft_owner_id.clone(),
                             receiver_id_in_appchain: receiver_id_in_appchain.clone(),
+                            fee,
                         },
                     );
                     log!(
@@ -482,6 +523,13 @@ impl WrappedAppchainNFTContractResolver for AppchainAnchor {
                         message,
                     },
                 );
+                self.internal_escrow_asset(
+                    &receiver_id_in_near,
+                    EscrowedAsset::LockedWrappedAppchainNFT {
+                        class_id,
+                        instance_id,
+                    },
+                );
             }
         }
     }
@@ -527,6 +575,14 @@ impl WrappedAppchainNFTContractResolver for AppchainAnchor {
                         message,
                     },
                 );
+                self.internal_escrow_asset(
+                    &receiver_id_in_near,
+                    EscrowedAsset::UnmintedWrappedAppchainNFT {
+                        class_id,
+                        instance_id,
+                        token_metadata,
+                    },
+                );
             }
         }
     }
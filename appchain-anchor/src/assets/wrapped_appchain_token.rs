@@ -14,7 +14,8 @@ pub trait WrappedAppchainTokenContractResolver {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
-    );
+        fee: U128,
+    ) -> bool;
     /// Resolver for minting wrapped appchain token
     fn resolve_wrapped_appchain_token_minting(
         &mut self,
@@ -22,6 +23,7 @@ pub trait WrappedAppchainTokenContractResolver {
         receiver_id_in_near: AccountId,
         amount: U128,
         appchain_message_nonce: u32,
+        retrying_message: Option<(U64, u32)>,
     );
 }
 
@@ -55,6 +57,13 @@ impl WrappedAppchainToken {
         u128::try_from(total_balance).unwrap() / u128::pow(10, u32::from(self.metadata.decimals))
             * self.price_in_usd.0
     }
+    /// The market value of the wrapped appchain token minted by bridging,
+    /// the premined balance is not included.
+    pub fn bridged_market_value(&self) -> Balance {
+        u128::try_from(self.changed_balance.0.max(0)).unwrap()
+            / u128::pow(10, u32::from(self.metadata.decimals))
+            * self.price_in_usd.0
+    }
     ///
     pub fn get_market_value_of(&self, amount: u128) -> Balance {
         amount / u128::pow(10, u32::from(self.metadata.decimals)) * self.price_in_usd.0
@@ -101,20 +110,43 @@ impl WrappedAppchainTokenManager for AppchainAnchor {
     }
     //
     fn set_price_of_wrapped_appchain_token(&mut self, price: U128) {
-        self.assert_token_price_maintainer();
-        let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
-        wrapped_appchain_token.price_in_usd = price;
-        self.wrapped_appchain_token.set(&wrapped_appchain_token);
+        self.internal_submit_price(PricedAsset::WrappedAppchainToken, price);
     }
     //
-    fn burn_wrapped_appchain_token(&self, receiver_id: String, amount: U128) {
+    fn burn_wrapped_appchain_token(
+        &mut self,
+        receiver_id: String,
+        amount: U128,
+    ) -> PromiseOrValue<bool> {
         self.assert_asset_transfer_is_not_paused();
+        assert!(
+            !self.appchain_state.eq(&AppchainState::Closing)
+                && !self.appchain_state.eq(&AppchainState::Closed),
+            "Bridging of wrapped appchain token is closed."
+        );
         self.assert_contract_account_of_wrapped_appchain_token_is_set();
         let wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
         let sender_id = env::predecessor_account_id();
         let account_id_in_appchain =
             AccountIdInAppchain::new(Some(receiver_id.clone()), &self.appchain_template_type);
         account_id_in_appchain.assert_valid();
+        // The bridge fee is only charged on the burnings initiated by users,
+        // the onboarding grants sent through the wrapped appchain token faucet are free.
+        let fee = match sender_id.eq(&self.get_wat_faucet_account()) {
+            true => U128::from(0),
+            false => {
+                self.internal_calculate_bridge_fee(&BridgingAsset::WrappedAppchainToken, &amount)
+            }
+        };
+        if let Err(message) = self.internal_check_bridge_flow(
+            &BridgingAsset::WrappedAppchainToken,
+            &BridgingDirection::NearToAppchain,
+            &U128::from(amount.0 - fee.0),
+        ) {
+            // Reject the burning without panicking, to keep the automatic pausing.
+            log!("{}", message);
+            return PromiseOrValue::Value(false);
+        }
         // burn token in wrapped appchain token contract
         #[derive(near_sdk::serde::Serialize)]
         #[serde(crate = "near_sdk::serde")]
@@ -144,8 +176,10 @@ impl WrappedAppchainTokenManager for AppchainAnchor {
                         sender_id.clone(),
                         receiver_id.clone(),
                         amount,
+                        fee,
                     ),
-            );
+            )
+            .into()
     }
 }
 
@@ -212,10 +246,12 @@ impl AppchainAnchor {
                         receiver_id.clone(),
                         amount.clone(),
                         appchain_message_nonce,
+                        processing_context.retrying_message(),
                     ),
             );
         processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_MINT_FUNGIBLE_TOKEN));
         processing_context.add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION));
+        processing_context.record_asset_transfer();
         MultiTxsOperationProcessingResult::Ok
     }
 }
@@ -228,7 +264,8 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
-    ) {
+        fee: U128,
+    ) -> bool {
         assert_self();
         let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
         match env::promise_result(0) {
@@ -238,21 +275,32 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
                     wrapped_appchain_token.changed_balance.0 - i128::try_from(amount.0).unwrap(),
                 );
                 self.wrapped_appchain_token.set(&wrapped_appchain_token);
+                self.internal_record_bridge_flow(
+                    &BridgingAsset::WrappedAppchainToken,
+                    &BridgingDirection::NearToAppchain,
+                    &U128::from(amount.0 - fee.0),
+                );
+                // The fee is burnt together with the bridging amount,
+                // and will be minted to relayer when it is claimed.
+                self.internal_accrue_bridge_fee(&BridgingAsset::WrappedAppchainToken, &fee);
                 let appchain_notification_history = self.internal_append_appchain_notification(
                     AppchainNotification::WrappedAppchainTokenBurnt {
                         sender_id_in_near: sender_id_in_near.clone(),
                         receiver_id_in_appchain: receiver_id_in_appchain.clone(),
-                        amount: U128::from(amount),
+                        amount: U128::from(amount.0 - fee.0),
+                        fee,
                     },
                 );
                 log!(
-                    "Wrapped appchain token burnt in contract '{}' by '{}' for '{}' of appchain. Amount: '{}', Crosschain notification index: '{}'.",
+                    "Wrapped appchain token burnt in contract '{}' by '{}' for '{}' of appchain. Amount: '{}', Fee: '{}', Crosschain notification index: '{}'.",
                     &wrapped_appchain_token.contract_account.unwrap(),
                     &sender_id_in_near,
                     &receiver_id_in_appchain,
-                    &amount.0,
+                    &(amount.0 - fee.0),
+                    &fee.0,
                     &appchain_notification_history.index.0
                 );
+                true
             }
             PromiseResult::Failed => {
                 log!(
@@ -262,6 +310,7 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
                     &receiver_id_in_appchain,
                     &amount.0
                 );
+                false
             }
         }
     }
@@ -272,6 +321,7 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
         receiver_id_in_near: AccountId,
         amount: U128,
         appchain_message_nonce: u32,
+        retrying_message: Option<(U64, u32)>,
     ) {
         assert_self();
         match env::promise_result(0) {
@@ -294,6 +344,8 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
                             message: Some(message),
                         },
                     );
+                } else if receiver_id_in_near.eq(&env::current_account_id()) {
+                    self.internal_credit_pending_relayer_rewards(appchain_message_nonce);
                 };
             }
             PromiseResult::Failed => {
@@ -308,6 +360,19 @@ impl WrappedAppchainTokenContractResolver for AppchainAnchor {
                         message,
                     },
                 );
+                if let Some(sender_id_in_appchain) = sender_id_in_appchain {
+                    // Keep the original failing time if the minting is retried,
+                    // to ensure the retry period will expire.
+                    self.internal_add_retryable_operation(
+                        appchain_message_nonce,
+                        RetryableOperation::MintWrappedAppchainToken {
+                            sender_id_in_appchain,
+                            receiver_id_in_near,
+                            amount,
+                        },
+                        retrying_message,
+                    );
+                }
             }
         }
     }
@@ -5,6 +5,7 @@ use near_contract_standards::{
 };
 
 use crate::*;
+use near_sdk::json_types::Base58CryptoHash;
 
 pub trait AnchorViewer {
     /// Get version of this contract.
@@ -14,6 +15,8 @@ pub trait AnchorViewer {
     /// Get the public key of owner account.
     /// This key will be used in creation of wrapped appchain NFT contract.
     fn get_owner_pk(&self) -> PublicKey;
+    /// Get the account proposed as the new owner, which has not accepted the ownership yet.
+    fn get_pending_owner(&self) -> Option<AccountId>;
     /// Get anchor settings detail.
     fn get_anchor_settings(&self) -> AnchorSettings;
     /// Get appchain settings detail.
@@ -156,6 +159,93 @@ pub trait AnchorViewer {
         start_index: U64,
         quantity: Option<U64>,
     ) -> Vec<AppchainChallenge>;
+    /// Get processing status of appchain challenge by index.
+    fn get_appchain_challenge_status(&self, index: U64) -> Option<AppchainChallengeStatus>;
+    /// Get the beneficiary account of the rewards of a validator or delegator.
+    fn get_reward_beneficiary_of(&self, account_id: AccountId) -> Option<AccountId>;
+    /// Get the failed withdrawals (of stake or rewards) of an account, which can be claimed.
+    fn get_failed_withdrawals_of(&self, account_id: AccountId) -> Vec<FailedWithdrawal>;
+    /// Get the amount of slashed OCT token which is not transferred to treasury account yet.
+    fn get_pending_slashed_oct_token(&self) -> U128;
+    /// Get the validator id which the GRANDPA key (in hex) is bound to.
+    fn get_validator_id_of_grandpa_key(&self, grandpa_key: String) -> Option<AccountId>;
+    /// Get the era number of a GRANDPA authority set of appchain.
+    fn get_era_number_of_grandpa_set_id(&self, set_id: u32) -> Option<U64>;
+    /// Get the bounty of an appchain challenge which failed to be minted.
+    fn get_unminted_bounty_of_appchain_challenge(&self, index: U64) -> U128;
+    /// Get the validators which are jailed currently.
+    fn get_jailed_validators(&self) -> Vec<JailedValidator>;
+    /// Get the status of onboarding grants, including the remaining budget
+    /// and the last synced balance of wrapped appchain token faucet.
+    fn get_onboarding_grants_status(&self) -> OnboardingGrantsStatus;
+    /// Get the history of onboarding grants.
+    fn get_onboarding_grants(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<OnboardingGrant>;
+    /// Get the onboarding grant of an account in appchain.
+    fn get_onboarding_grant_of(&self, account_id_in_appchain: String) -> Option<OnboardingGrant>;
+    /// Get the fee for bridging an asset from NEAR protocol to appchain.
+    fn get_bridge_fee_of(&self, asset: BridgingAsset) -> Option<BridgeFee>;
+    /// Get the accrued bridge fees of all assets.
+    fn get_accrued_bridge_fees(&self) -> Vec<AccruedBridgeFee>;
+    /// Get the rate limit and recent bridged amounts of an asset in a certain direction.
+    fn get_bridge_flow_status_of(
+        &self,
+        asset: BridgingAsset,
+        direction: BridgingDirection,
+    ) -> BridgeFlowStatus;
+    /// Get the status of all paused bridge flows.
+    fn get_paused_bridge_flows(&self) -> Vec<BridgeFlowStatus>;
+    /// Get the escrowed assets of an account, whose delivery failed
+    /// in the transfers from appchain.
+    fn get_escrowed_assets_of(&self, account_id: AccountId) -> Vec<EscrowedAsset>;
+    /// Get the status of storage fund.
+    fn get_storage_fund(&self) -> StorageFund;
+    /// Get the storage deposit budget per transfer of an asset.
+    fn get_storage_deposit_budget_of(&self, asset: BridgingAsset) -> Option<U128>;
+    /// Get the accounts of price reporters.
+    fn get_price_reporters(&self) -> Vec<AccountId>;
+    /// Get the accounting of all relayers.
+    fn get_relayer_stats(&self) -> Vec<RelayerStats>;
+    /// Get the accounting of a relayer.
+    fn get_relayer_stats_of(&self, relayer: AccountId) -> Option<RelayerStats>;
+    /// Get the accounts explicitly granted with a role.
+    fn get_role_members(&self, role: AnchorRole) -> Vec<AccountId>;
+    /// Get the roles held by an account, including the implicit ones.
+    fn get_roles_of(&self, account_id: AccountId) -> Vec<AnchorRole>;
+    /// Get the attestations of witnesses on the appchain messages starting from a nonce,
+    /// which are not staged yet.
+    fn get_witness_attestations_of(&self, start_nonce: u32) -> Vec<WitnessAttestation>;
+    /// Get the submission stats of all accounts with role `Witness`.
+    fn get_witness_submission_stats(&self) -> Vec<WitnessSubmissionStats>;
+    /// Get the governance proposals.
+    fn get_governance_proposals(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<GovernanceProposal>;
+    /// Get a governance proposal by its index.
+    fn get_governance_proposal(&self, index: U64) -> Option<GovernanceProposal>;
+    /// Get the audit records of granting and revoking roles.
+    fn get_role_audit_records(
+        &self,
+        start_index: U64,
+        quantity: Option<U64>,
+    ) -> Vec<RoleAuditRecord>;
+    /// Get the current price, the last update time and the submissions of reporters
+    /// of an asset.
+    fn get_price_status_of(&self, asset: PricedAsset) -> AssetPriceStatus;
+    /// Get the market values of bridged assets and the collateralization ratio.
+    fn get_collateralization_status(&self) -> CollateralizationStatus;
+    /// Get the failed appchain messages in retry queue.
+    fn get_retryable_appchain_messages(&self) -> Vec<RetryableAppchainMessage>;
+    /// Get the processing results of all attempts of a retryable appchain message.
+    fn get_processing_result_history_of_appchain_message(
+        &self,
+        nonce: u32,
+    ) -> Vec<AppchainMessageProcessingResult>;
 }
 
 pub trait AppchainLifecycleManager {
@@ -165,6 +255,20 @@ pub trait AppchainLifecycleManager {
     fn go_live(&mut self);
     /// Initialize the beefy light client
     fn initialize_beefy_light_client(&mut self, initial_public_keys: Vec<String>);
+    /// Change the state of corresponding appchain to `closing`,
+    /// and close the bridging of all assets.
+    fn start_closing(&mut self);
+    /// Unbond all validators and delegators, and change the state of corresponding
+    /// appchain to `closed` after the reward of the last era is distributed
+    /// and all locked assets are unlocked.
+    ///
+    /// If the appchain is halted and the reward of the last era will never be concluded,
+    /// the owner or the registry can set `skip_reward_of_last_era` to `true` to
+    /// complete closing without the reward of the last era.
+    fn complete_closing(
+        &mut self,
+        skip_reward_of_last_era: Option<bool>,
+    ) -> MultiTxsOperationProcessingResult;
 }
 
 pub trait NearFungibleTokenManager {
@@ -193,6 +297,125 @@ pub trait NearFungibleTokenManager {
     fn close_bridging_of_near_fungible_token(&mut self, symbol: String);
 }
 
+pub trait BridgeFeeManager {
+    /// Set the fee for bridging an asset from NEAR protocol to appchain.
+    /// Passing `None` will remove the fee of the asset.
+    /// This function can only be called by the owner.
+    fn set_bridge_fee(&mut self, asset: BridgingAsset, fee: Option<BridgeFee>);
+    /// Claim the accrued bridge fees of an asset, which will be transferred to the caller.
+    /// This function can only be called by the account with role `Relayer`.
+    fn claim_bridge_fees(&mut self, asset: BridgingAsset);
+}
+
+pub trait BridgeRateLimitManager {
+    /// Set the rate limit for bridging an asset in a certain direction.
+    /// Passing `None` will remove the rate limit.
+    /// This function can only be called by the owner.
+    fn set_bridge_rate_limit(
+        &mut self,
+        asset: BridgingAsset,
+        direction: BridgingDirection,
+        rate_limit: Option<BridgeRateLimit>,
+    );
+    /// Pause the bridging of an asset in a certain direction.
+    /// This function can only be called by account with role `Owner`, `Operator` or `Pauser`.
+    fn pause_bridge_flow(&mut self, asset: BridgingAsset, direction: BridgingDirection);
+    /// Resume the bridging of an asset in a certain direction.
+    /// This function can only be called by account with role `Owner` or `Operator`.
+    fn resume_bridge_flow(&mut self, asset: BridgingAsset, direction: BridgingDirection);
+}
+
+pub trait EscrowedAssetManager {
+    /// Claim all escrowed assets of the caller, whose delivery failed
+    /// in the transfers from appchain.
+    /// The assets whose bridging from appchain to NEAR is paused are kept in escrow.
+    fn claim_escrowed_assets(&mut self);
+}
+
+pub trait GovernanceProposalManager {
+    /// Schedule a call of a timelocked function with the args in JSON.
+    /// This function can only be called by account with the role(s) required by the function.
+    fn propose_governance_action(&mut self, method_name: String, args: String) -> U64;
+    /// Cancel a pending governance proposal.
+    /// This function can only be called by account with role `Owner` or `Guardian`.
+    fn cancel_governance_proposal(&mut self, index: U64);
+    /// Execute a governance proposal whose timelock delay has passed.
+    fn execute_governance_proposal(&mut self, index: U64);
+}
+
+pub trait OwnershipManager {
+    /// Propose an account as the new owner. The ownership is transferred only after
+    /// the proposed account accepts it.
+    /// This function can only be called by the owner.
+    fn propose_owner(&mut self, owner: AccountId);
+    /// Cancel the pending ownership transfer.
+    /// This function can only be called by the owner.
+    fn cancel_owner_proposal(&mut self);
+    /// Accept the ownership of this contract. The explicit members of role `Owner` are
+    /// revoked when the ownership is transferred.
+    /// If `owner_pk` is provided, it will replace the owner public key, and the full access
+    /// key of the native NEAR token vault will be rotated to it. If the governance timelock
+    /// is enabled, a governance proposal of `set_owner_pk` is created instead, which can be
+    /// executed after the timelock delay.
+    /// This function can only be called by the pending owner.
+    fn accept_ownership(&mut self, owner_pk: Option<PublicKey>);
+}
+
+pub trait WitnessActions {
+    /// Attest the sha256 hash of the encoded appchain messages starting from a nonce.
+    /// In witness mode of beefy light client, the appchain messages can only be staged
+    /// after they are attested by enough witnesses.
+    /// This function can only be called by account with role `Witness`.
+    fn attest_appchain_messages(&mut self, start_nonce: u32, messages_hash: Base58CryptoHash);
+}
+
+pub trait RelayerRewardsManager {
+    /// Withdraw the relayer rewards of the caller in wrapped appchain token.
+    fn withdraw_relayer_rewards(&mut self);
+}
+
+pub trait RoleManager {
+    /// Grant a role to an account.
+    /// This function can only be called by account with role `Owner`.
+    fn grant_role(&mut self, role: AnchorRole, account_id: AccountId);
+    /// Revoke a role from an account.
+    /// This function can only be called by account with role `Owner`.
+    fn revoke_role(&mut self, role: AnchorRole, account_id: AccountId);
+}
+
+pub trait PriceOracleManager {
+    /// Add an account as price reporter, by granting role `PriceMaintainer` to it.
+    /// This function can only be called by the owner.
+    fn add_price_reporter(&mut self, account_id: AccountId);
+    /// Remove an account from price reporters, by revoking role `PriceMaintainer` from it.
+    /// This function can only be called by the owner.
+    fn remove_price_reporter(&mut self, account_id: AccountId);
+}
+
+pub trait StorageFundManager {
+    /// Set the storage deposit budget per transfer of a NEAR fungible token.
+    /// If it is set, the receivers of the token which are not registered yet will be
+    /// registered in the token contract automatically, before the tokens are transferred to them.
+    /// This function can only be called by the owner.
+    fn set_storage_deposit_budget(&mut self, asset: BridgingAsset, budget: Option<U128>);
+    /// Move the accrued bridge fees of native NEAR token to storage fund.
+    /// This function can only be called by the owner.
+    fn top_up_storage_fund_with_bridge_fees(&mut self);
+}
+
+pub trait OnboardingGrantManager {
+    /// Set the remaining budget of onboarding grants for new validators.
+    /// This function can only be called by account with role `Owner` or `Operator`.
+    fn set_onboarding_grant_budget(&mut self, budget: U128);
+    /// Retry a failed onboarding grant, or send an onboarding grant which was recorded
+    /// when the budget was not enough.
+    /// This function can be called by any account.
+    fn claim_onboarding_grant(&mut self, index: U64);
+    /// Query the balance of wrapped appchain token faucet and record it in anchor.
+    /// This function can be called by any account.
+    fn sync_wat_faucet_balance(&mut self);
+}
+
 pub trait OwnerActions {
     ///
     fn remove_validator_set_before(&mut self, era_number: U64);
@@ -200,6 +423,16 @@ pub trait OwnerActions {
     fn remove_staking_history_before(&mut self, index: U64);
     ///
     fn remove_appchain_notification_history_before(&mut self, index: U64);
+    /// Transfer the slashed OCT token which failed to be transferred before,
+    /// or was slashed while the treasury account is not set, to treasury account.
+    fn transfer_pending_slashed_oct_token_to_treasury(&mut self);
+    /// Record the era in which a GRANDPA authority set of appchain is active.
+    /// The era number is used to judge the equivocation challenges of the set.
+    ///
+    /// The set ids of following eras are derived from the latest recorded one at era switch,
+    /// so this function is only needed for the eras before upgrade, or for correcting
+    /// the derivation when the authority set of appchain changed more than once in an era.
+    fn set_era_number_of_grandpa_set_id(&mut self, set_id: u32, era_number: U64);
 }
 
 pub trait PermissionlessActions {
@@ -227,6 +460,16 @@ pub trait PermissionlessActions {
     fn process_appchain_messages(&mut self) -> MultiTxsOperationProcessingResult;
     ///
     fn commit_appchain_challenge(&mut self, appchain_challenge: AppchainChallenge);
+    /// Process a committed appchain challenge.
+    /// The verdict of the challenge will be made at first, and then the offender
+    /// of an accepted challenge will be penalized.
+    fn process_appchain_challenge(&mut self, index: U64) -> MultiTxsOperationProcessingResult;
+    /// Retry a failed appchain message in retry queue.
+    /// Any account can retry the message in the retry period, and after the period
+    /// only the account with role `Owner` or `Operator` can retry it.
+    fn retry_appchain_message(&mut self, nonce: u32) -> MultiTxsOperationProcessingResult;
+    /// Mint the bounty of an appchain challenge which failed to be minted before.
+    fn retry_minting_bounty_of_appchain_challenge(&mut self, index: U64);
     //
     fn process_appchain_messages_with_all_proofs(
         &mut self,
@@ -275,9 +518,33 @@ pub trait ProtocolSettingsManager {
     ///
     fn change_validator_commission_percent(&mut self, value: u16);
     ///
+    fn change_maximum_validator_commission_percent(&mut self, value: u16);
+    ///
+    fn change_maximum_validator_commission_percent_change_per_era(&mut self, value: u16);
+    ///
     fn change_maximum_allowed_unprofitable_era_count(&mut self, value: u16);
     ///
+    fn change_minimum_jailed_era_count_before_unjail(&mut self, value: u16);
+    ///
+    fn change_maximum_jailed_era_count(&mut self, value: u16);
+    ///
+    fn change_retry_period_of_failed_appchain_message(&mut self, value: U64);
+    ///
+    fn change_maximum_price_deviation_percent_per_update(&mut self, value: u16);
+    ///
+    fn change_staleness_timeout_of_token_price(&mut self, value: U64);
+    ///
+    fn change_minimum_price_submission_count(&mut self, value: u16);
+    ///
+    fn change_governance_timelock_delay(&mut self, value: U64);
+    ///
+    fn change_witness_attestation_threshold(&mut self, value: u16);
+    ///
     fn change_subaccount_for_council_keeper_contract(&mut self, subaccount_name: String);
+    ///
+    fn change_slash_percent_per_offence_of_kind(&mut self, kind: String, value: u16);
+    ///
+    fn remove_slash_percent_per_offence_of_kind(&mut self, kind: String);
 }
 
 pub trait AppchainSettingsManager {
@@ -289,6 +556,10 @@ pub trait AppchainSettingsManager {
     fn set_era_reward(&mut self, era_reward: U128);
     ///
     fn set_bonus_for_new_validator(&mut self, bonus_amount: U128);
+    ///
+    fn set_bounty_for_appchain_challenge(&mut self, bounty_amount: U128);
+    ///
+    fn set_relayer_reward_per_era(&mut self, reward_amount: U128);
 }
 
 pub trait AnchorSettingsManager {
@@ -300,6 +571,8 @@ pub trait AnchorSettingsManager {
     fn turn_on_beefy_light_client_witness_mode(&mut self);
     ///
     fn turn_off_beefy_light_client_witness_mode(&mut self);
+    ///
+    fn set_treasury_account(&mut self, account_id: AccountId);
 }
 
 pub trait StakingManager {
@@ -330,6 +603,14 @@ pub trait StakingManager {
     /// Withdraw rewards of a certain delegator to a validator.
     /// This function can be called by any account.
     fn withdraw_delegator_rewards(&mut self, delegator_id: AccountId, validator_id: AccountId);
+    /// Withdraw all rewards of a certain account, including the rewards as a validator
+    /// and the rewards of all delegations of it, in a single transfer.
+    /// This function can be called by any account.
+    /// This function needs to be called repeatedly until the result is `Ok`.
+    fn withdraw_all_rewards_of(
+        &mut self,
+        account_id: AccountId,
+    ) -> MultiTxsOperationProcessingResult;
     /// Change the validator which the caller is delegated to.
     /// This function can only be called by a delegator.
     fn change_delegated_validator(
@@ -337,6 +618,13 @@ pub trait StakingManager {
         old_validator_id: AccountId,
         new_validator_id: AccountId,
     );
+    /// Set the beneficiary account of the rewards of the caller (validator or delegator).
+    /// If `beneficiary_id` is `None`, the rewards will be paid to the caller itself.
+    fn set_reward_beneficiary(&mut self, beneficiary_id: Option<AccountId>);
+    /// Transfer the failed withdrawals (of stake or rewards) of a certain account
+    /// to their receivers again.
+    /// This function can be called by any account.
+    fn claim_failed_withdrawals(&mut self, account_id: AccountId);
 }
 
 pub trait SudoActions {
@@ -363,6 +651,10 @@ pub trait SudoActions {
     ///
     fn resume_rewards_withdrawal(&mut self);
     ///
+    fn pause_stake_withdrawal(&mut self);
+    ///
+    fn resume_stake_withdrawal(&mut self);
+    ///
     fn change_account_id_in_appchain_of_validator(
         &mut self,
         validator_id: AccountId,
@@ -384,6 +676,19 @@ pub trait ValidatorActions {
     fn set_validator_id_in_appchain(&mut self, account_id_in_appchain: String);
     ///
     fn set_validator_profile(&mut self, profile: HashMap<String, String>);
+    /// Set the commission percent of the validator (the caller).
+    /// The new value will take effect in next era.
+    fn set_validator_commission_percent(&mut self, commission_percent: u16);
+    /// Unjail the caller (a jailed validator).
+    /// The validator will be included in the validator set again from next era.
+    fn unjail(&mut self);
+    /// Bind the GRANDPA key (in hex) of the validator (the caller) in appchain.
+    /// A bound key can not be bound to another validator.
+    ///
+    /// The `signature` (in hex) is the ed25519 signature of the GRANDPA key on the message
+    /// `<account id of this contract>:<account id of the validator>`, which proves
+    /// that the validator owns the key.
+    fn set_grandpa_key_in_appchain(&mut self, grandpa_key: String, signature: String);
 }
 
 pub trait WrappedAppchainTokenManager {
@@ -401,7 +706,11 @@ pub trait WrappedAppchainTokenManager {
     ///
     fn set_price_of_wrapped_appchain_token(&mut self, price: U128);
     ///
-    fn burn_wrapped_appchain_token(&self, receiver_id: String, amount: U128);
+    fn burn_wrapped_appchain_token(
+        &mut self,
+        receiver_id: String,
+        amount: U128,
+    ) -> PromiseOrValue<bool>;
 }
 
 pub trait WrappedAppchainNFTManager {
@@ -417,6 +726,10 @@ pub trait WrappedAppchainNFTManager {
     fn open_bridging_of_wrapped_appchain_nft(&mut self, class_id: String);
     ///
     fn close_bridging_of_wrapped_appchain_nft(&mut self, class_id: String);
+    /// Submit the floor price (in USD) of a single NFT of a class.
+    /// This function can only be called by price reporters or the owner.
+    /// The price submitted by the owner overrides the current floor price directly.
+    fn set_floor_price_of_wrapped_appchain_nft(&mut self, class_id: String, price: U128);
 }
 
 pub trait NativeNearTokenManager {
@@ -428,11 +741,12 @@ pub trait NativeNearTokenManager {
     fn open_bridging_of_native_near_token(&mut self);
     ///
     fn close_bridging_of_native_near_token(&mut self);
-    ///
+    /// Generate appchain notification for the native NEAR token deposited in near vault.
+    /// Returns `false` if the deposit is rejected, and it should be refunded by near vault.
     fn generate_appchain_notification_for_near_deposit(
         &mut self,
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
-    );
+    ) -> bool;
 }
@@ -19,7 +19,7 @@ use core::convert::TryInto;
 use near_contract_standards::non_fungible_token::metadata::TokenMetadata;
 use near_contract_standards::non_fungible_token::TokenId;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedSet};
+use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
 use near_sdk::json_types::{U128, U64};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{
@@ -65,6 +65,14 @@ const T_GAS_FOR_BURN_WRAPPED_APPCHAIN_TOKEN: u64 = 50;
 const T_GAS_FOR_NATIVE_NEAR_RECEIVER_CONTRACT_INITIALIZATION: u64 = 50;
 const T_GAS_FOR_UNLOCK_NATIVE_NEAR: u64 = 10;
 const T_GAS_FOR_SYNC_STAKING_AMOUNT_TO_COUNCIL: u64 = 150;
+const T_GAS_FOR_FT_BALANCE_OF: u64 = 5;
+const T_GAS_FOR_STORAGE_DEPOSIT: u64 = 10;
+const T_GAS_FOR_STORAGE_BALANCE_OF: u64 = 5;
+/// The gas for checking and registering storage of a receiver in a token contract.
+const T_GAS_FOR_STORAGE_REGISTRATION: u64 =
+    T_GAS_FOR_STORAGE_BALANCE_OF + T_GAS_FOR_STORAGE_DEPOSIT + T_GAS_FOR_RESOLVER_FUNCTION * 2;
+const T_GAS_FOR_GOVERNANCE_ACTION: u64 = 50;
+const T_GAS_FOR_ROTATE_FULL_ACCESS_KEY: u64 = 10;
 /// The value of decimals value of USD.
 const USD_DECIMALS_VALUE: Balance = 1_000_000;
 /// The value of decimals value of OCT token.
@@ -81,6 +89,8 @@ const STORAGE_DEPOSIT_FOR_MINT_NFT: Balance = 100_000_000_000_000_000_000_000;
 const WRAPPED_APPCHAIN_NFT_CONTRACT_INIT_BALANCE: Balance = 3_200_000_000_000_000_000_000_000;
 /// Storage deposit for native NEAR token receiver contract (in yocto)
 const NATIVE_NEAR_TOKEN_RECEIVER_CONTRACT_INIT_BALANCE: Balance = 3_200_000_000_000_000_000_000_000;
+/// The maximum count of relayers whose workload can be rewarded in an era.
+const MAXIMUM_RELAYER_COUNT_PER_ERA: usize = 20;
 
 #[ext_contract(ext_self)]
 trait ResolverForSelfCallback {
@@ -90,7 +100,8 @@ trait ResolverForSelfCallback {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
-    );
+        fee: U128,
+    ) -> bool;
     /// Resolver for minting wrapped appchain token
     fn resolve_wrapped_appchain_token_minting(
         &mut self,
@@ -98,6 +109,7 @@ trait ResolverForSelfCallback {
         receiver_id_in_near: AccountId,
         amount: U128,
         appchain_message_nonce: u32,
+        retrying_message: Option<(U64, u32)>,
     );
     /// Resolver for transfer NEAR fungible token
     fn resolve_fungible_token_transfer(
@@ -128,6 +140,65 @@ trait ResolverForSelfCallback {
         token_metadata: TokenMetadata,
         appchain_message_nonce: u32,
     );
+    /// Resolver for transferring slashed OCT token to treasury account
+    fn resolve_slashed_oct_token_transfer(&mut self, treasury_account: AccountId, amount: U128);
+    /// Resolver for minting bounty of appchain challenge
+    fn resolve_appchain_challenge_bounty_minting(
+        &mut self,
+        challenge_index: U64,
+        submitter_account: AccountId,
+        amount: U128,
+    );
+    /// Resolver for withdrawing unbonded stake
+    fn resolve_stake_withdrawal(&mut self, account_id: AccountId, stake_amount: U128);
+    /// Resolver for withdrawing rewards
+    fn resolve_rewards_withdrawal(
+        &mut self,
+        account_id: AccountId,
+        receiver_id: AccountId,
+        withdrawn_rewards: WithdrawnRewards,
+    );
+    /// Resolver for claiming a failed withdrawal
+    fn resolve_failed_withdrawal_claim(
+        &mut self,
+        account_id: AccountId,
+        failed_withdrawal: FailedWithdrawal,
+    );
+    /// Resolver for granting wrapped appchain token to a new validator
+    fn resolve_onboarding_grant(&mut self, index: U64);
+    /// Resolver for querying the balance of wrapped appchain token faucet
+    fn resolve_wat_faucet_balance_query(&mut self);
+    /// Resolver for claiming an escrowed asset
+    fn resolve_escrowed_asset_claim(&mut self, receiver_id: AccountId, asset: EscrowedAsset);
+    /// Resolver for querying storage balance of a receiver in a token contract
+    fn resolve_storage_balance_query_for_receiver(
+        &mut self,
+        asset: BridgingAsset,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+    ) -> PromiseOrValue<()>;
+    /// Resolver for registering storage of a receiver in a token contract
+    fn resolve_storage_deposit_for_receiver(
+        &mut self,
+        contract_account: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    );
+    /// Resolver for topping up storage fund with accrued bridge fees of native NEAR token
+    fn resolve_storage_fund_topping_up(&mut self, amount: U128);
+    /// Resolver for executing a governance proposal
+    fn resolve_governance_proposal_execution(&mut self, index: U64);
+    /// Resolver for rotating the full access key of a sub-account
+    fn resolve_full_access_key_rotation(&mut self, contract_account: AccountId);
+    /// Resolver for withdrawing rewards of a relayer
+    fn resolve_relayer_rewards_withdrawal(&mut self, relayer: AccountId, amount: U128);
+    /// Resolver for claiming accrued bridge fees
+    fn resolve_bridge_fee_claim(
+        &mut self,
+        asset: BridgingAsset,
+        receiver_id: AccountId,
+        amount: U128,
+    );
 }
 
 #[near_bindgen]
@@ -196,6 +267,93 @@ pub struct AppchainAnchor {
     wrapped_appchain_nfts: LazyOption<WrappedAppchainNFTs>,
     /// The native NEAR token data
     native_near_token: LazyOption<NativeNearToken>,
+    /// The processing status of appchain challenges, mapped by the index of challenge
+    appchain_challenge_statuses: LookupMap<u64, AppchainChallengeStatus>,
+    /// The index of committed equivocation challenges.
+    /// The key in map is `(set_id, round_number, identity)` of the equivocation
+    committed_equivocations: LookupMap<(u32, u32, [u8; 32]), u64>,
+    /// The index of committed conspiracy MMR challenges, mapped by the hash of proof
+    committed_conspiracy_mmrs: LookupMap<[u8; 32], u64>,
+    /// The commission percent of validators which will take effect in next era
+    validator_commission_percents: LookupMap<AccountId, u16>,
+    /// The rewards collected in unfinished batch withdrawals, mapped by account id
+    pending_rewards_of_batch_withdrawal: LookupMap<AccountId, WithdrawnRewards>,
+    /// The beneficiary accounts of rewards, mapped by the account id of validator or delegator
+    reward_beneficiaries: LookupMap<AccountId, AccountId>,
+    /// The failed withdrawals of stake and rewards, mapped by account id
+    failed_withdrawals: LookupMap<AccountId, Vec<FailedWithdrawal>>,
+    /// The era number when a validator is jailed, keyed by the validator id
+    jailed_validators: LookupMap<AccountId, u64>,
+    /// The history of onboarding grants for new validators
+    onboarding_grants: LazyOption<LookupArray<OnboardingGrant>>,
+    /// The index of onboarding grant, mapped by the account id in appchain
+    onboarding_grant_indexes: LookupMap<String, u64>,
+    /// The budget and statistics of onboarding grants
+    onboarding_grants_status: LazyOption<OnboardingGrantsStatus>,
+    /// The fees for bridging assets from NEAR protocol to appchain
+    bridge_fees: UnorderedMap<BridgingAsset, BridgeFee>,
+    /// The accrued bridge fees which can be claimed by relayer
+    accrued_bridge_fees: UnorderedMap<BridgingAsset, U128>,
+    /// The rate limits of bridging assets, mapped by asset and direction
+    bridge_rate_limits: LookupMap<(BridgingAsset, BridgingDirection), BridgeRateLimit>,
+    /// The bridged amounts in the last day grouped by buckets, mapped by asset and direction
+    bridge_flow_buckets: LookupMap<(BridgingAsset, BridgingDirection), Vec<(u64, u128)>>,
+    /// The assets and directions whose bridging is paused
+    paused_bridge_flows: UnorderedSet<(BridgingAsset, BridgingDirection)>,
+    /// Whether the stake withdrawal is paused
+    stake_withdrawal_is_paused: bool,
+    /// The failed appchain messages which can be retried
+    retryable_appchain_messages: UnorderedMap<u32, RetryableAppchainMessage>,
+    /// The processing results of all attempts of retryable appchain messages
+    appchain_message_processing_result_histories:
+        LookupMap<u32, Vec<AppchainMessageProcessingResult>>,
+    /// The assets whose delivery failed, mapped by the receiver account in NEAR protocol
+    escrowed_assets: LookupMap<AccountId, Vec<EscrowedAsset>>,
+    /// The fund for registering storage of receivers in token contracts
+    storage_fund: LazyOption<StorageFund>,
+    /// The storage deposit budget per transfer of bridging assets,
+    /// only the assets in this map will be registered for receivers automatically
+    storage_deposit_budgets: LookupMap<BridgingAsset, U128>,
+    /// The price submissions and the last update time of assets
+    asset_price_records: LookupMap<PricedAsset, AssetPriceRecord>,
+    /// The floor prices (in USD) of wrapped appchain NFTs, mapped by class id
+    floor_prices_of_wrapped_appchain_nfts: LookupMap<String, U128>,
+    /// The accounts explicitly granted with roles, mapped by role
+    role_members: LookupMap<AnchorRole, Vec<AccountId>>,
+    /// The audit records of granting and revoking roles
+    role_audit_records: LazyOption<LookupArray<RoleAuditRecord>>,
+    /// The scheduled calls of timelocked functions
+    governance_proposals: LazyOption<LookupArray<GovernanceProposal>>,
+    /// The account proposed as the new owner, which needs to accept the ownership
+    pending_owner: Option<AccountId>,
+    /// The attestations of witnesses on appchain messages, mapped by the start nonce of messages
+    witness_attestations: LookupMap<u32, Vec<WitnessAttestation>>,
+    /// The start nonces of appchain messages which have pending witness attestations
+    witness_attested_nonces: UnorderedSet<u32>,
+    /// The submission stats of witnesses
+    witness_submission_stats: LookupMap<AccountId, WitnessSubmissionStats>,
+    /// The accounting of relayers, mapped by the account of relayer
+    relayer_stats: UnorderedMap<AccountId, RelayerStats>,
+    /// The relayers which have workload in current era
+    relayers_of_current_era: Vec<AccountId>,
+    /// The relayer rewards which are not minted yet,
+    /// mapped by the nonce of the appchain message which triggered the minting
+    pending_relayer_rewards: LookupMap<u32, Vec<(AccountId, U128)>>,
+    /// The slashed OCT token which is not transferred to treasury account yet
+    pending_slashed_oct_token: Balance,
+    /// The validators which registered their GRANDPA keys, mapped by the hex string of the key
+    validator_ids_of_grandpa_keys: LookupMap<String, AccountId>,
+    /// The era numbers of GRANDPA authority sets of appchain, mapped by set id
+    era_numbers_of_grandpa_set_ids: LookupMap<u32, u64>,
+    /// The latest recorded GRANDPA set id of appchain and its era number,
+    /// which is used to derive the set ids of following eras
+    latest_grandpa_set_id_of_era: Option<(u32, u64)>,
+    /// The bounties of appchain challenges which failed to be minted, mapped by the index of challenge
+    unminted_appchain_challenge_bounties: LookupMap<u64, Balance>,
+    /// The start time of the unlock period of the stakes unbonded for closing the appchain
+    closing_unbonding_timestamp: Option<Timestamp>,
+    /// The validators of which a delegator has unwithdrawn rewards, mapped by the account id of delegator
+    validators_of_unwithdrawn_delegator_rewards: LookupMap<AccountId, Vec<AccountId>>,
 }
 
 #[near_bindgen]
@@ -313,16 +471,113 @@ impl AppchainAnchor {
                 StorageKey::NativeNearToken.into_bytes(),
                 Some(&NativeNearToken::default()),
             ),
+            appchain_challenge_statuses: LookupMap::new(
+                StorageKey::AppchainChallengeStatuses.into_bytes(),
+            ),
+            committed_equivocations: LookupMap::new(
+                StorageKey::CommittedEquivocations.into_bytes(),
+            ),
+            committed_conspiracy_mmrs: LookupMap::new(
+                StorageKey::CommittedConspiracyMmrs.into_bytes(),
+            ),
+            validator_commission_percents: LookupMap::new(
+                StorageKey::ValidatorCommissionPercents.into_bytes(),
+            ),
+            pending_rewards_of_batch_withdrawal: LookupMap::new(
+                StorageKey::PendingRewardsOfBatchWithdrawal.into_bytes(),
+            ),
+            reward_beneficiaries: LookupMap::new(StorageKey::RewardBeneficiaries.into_bytes()),
+            failed_withdrawals: LookupMap::new(StorageKey::FailedWithdrawals.into_bytes()),
+            jailed_validators: LookupMap::new(StorageKey::JailedValidators.into_bytes()),
+            onboarding_grants: LazyOption::new(
+                StorageKey::OnboardingGrants.into_bytes(),
+                Some(&LookupArray::new(StorageKey::OnboardingGrantsMap)),
+            ),
+            onboarding_grant_indexes: LookupMap::new(
+                StorageKey::OnboardingGrantIndexes.into_bytes(),
+            ),
+            onboarding_grants_status: LazyOption::new(
+                StorageKey::OnboardingGrantsStatus.into_bytes(),
+                Some(&OnboardingGrantsStatus::default()),
+            ),
+            bridge_fees: UnorderedMap::new(StorageKey::BridgeFees.into_bytes()),
+            accrued_bridge_fees: UnorderedMap::new(StorageKey::AccruedBridgeFees.into_bytes()),
+            bridge_rate_limits: LookupMap::new(StorageKey::BridgeRateLimits.into_bytes()),
+            bridge_flow_buckets: LookupMap::new(StorageKey::BridgeFlowBuckets.into_bytes()),
+            paused_bridge_flows: UnorderedSet::new(StorageKey::PausedBridgeFlows.into_bytes()),
+            stake_withdrawal_is_paused: false,
+            retryable_appchain_messages: UnorderedMap::new(
+                StorageKey::RetryableAppchainMessages.into_bytes(),
+            ),
+            appchain_message_processing_result_histories: LookupMap::new(
+                StorageKey::AppchainMessageProcessingResultHistories.into_bytes(),
+            ),
+            escrowed_assets: LookupMap::new(StorageKey::EscrowedAssets.into_bytes()),
+            storage_fund: LazyOption::new(
+                StorageKey::StorageFund.into_bytes(),
+                Some(&StorageFund::default()),
+            ),
+            storage_deposit_budgets: LookupMap::new(StorageKey::StorageDepositBudgets.into_bytes()),
+            role_members: LookupMap::new(StorageKey::RoleMembers.into_bytes()),
+            role_audit_records: LazyOption::new(
+                StorageKey::RoleAuditRecords.into_bytes(),
+                Some(&LookupArray::new(StorageKey::RoleAuditRecordsMap)),
+            ),
+            governance_proposals: LazyOption::new(
+                StorageKey::GovernanceProposals.into_bytes(),
+                Some(&LookupArray::new(StorageKey::GovernanceProposalsMap)),
+            ),
+            pending_owner: None,
+            witness_attestations: LookupMap::new(StorageKey::WitnessAttestations.into_bytes()),
+            witness_attested_nonces: UnorderedSet::new(
+                StorageKey::WitnessAttestedNonces.into_bytes(),
+            ),
+            witness_submission_stats: LookupMap::new(
+                StorageKey::WitnessSubmissionStats.into_bytes(),
+            ),
+            relayer_stats: UnorderedMap::new(StorageKey::RelayerStats.into_bytes()),
+            relayers_of_current_era: Vec::new(),
+            pending_relayer_rewards: LookupMap::new(StorageKey::PendingRelayerRewards.into_bytes()),
+            pending_slashed_oct_token: 0,
+            validator_ids_of_grandpa_keys: LookupMap::new(
+                StorageKey::ValidatorIdsOfGrandpaKeys.into_bytes(),
+            ),
+            era_numbers_of_grandpa_set_ids: LookupMap::new(
+                StorageKey::EraNumbersOfGrandpaSetIds.into_bytes(),
+            ),
+            latest_grandpa_set_id_of_era: None,
+            unminted_appchain_challenge_bounties: LookupMap::new(
+                StorageKey::UnmintedAppchainChallengeBounties.into_bytes(),
+            ),
+            closing_unbonding_timestamp: None,
+            validators_of_unwithdrawn_delegator_rewards: LookupMap::new(
+                StorageKey::ValidatorsOfUnwithdrawnDelegatorRewards.into_bytes(),
+            ),
+            asset_price_records: LookupMap::new(StorageKey::AssetPriceRecords.into_bytes()),
+            floor_prices_of_wrapped_appchain_nfts: LookupMap::new(
+                StorageKey::FloorPricesOfWrappedAppchainNFTs.into_bytes(),
+            ),
         }
     }
-    // Assert that the function is called by the owner.
+    // Assert that the function is called by account with role `Owner`.
     fn assert_owner(&self) {
-        assert_eq!(
-            env::predecessor_account_id(),
-            self.owner,
+        assert!(
+            self.has_role(&AnchorRole::Owner, &env::predecessor_account_id()),
             "This function can only be called by owner."
         );
     }
+    // Assert that the function is called by account with role `Owner` or `Operator`.
+    fn assert_operator(&self) {
+        self.assert_any_role(&[AnchorRole::Owner, AnchorRole::Operator]);
+    }
+    // Assert that the function is called by account with role `Owner`, `Operator` or `Pauser`.
+    fn assert_pauser(&self) {
+        self.assert_any_role(&[AnchorRole::Owner, AnchorRole::Operator, AnchorRole::Pauser]);
+    }
+    // Assert that the function is called by account with role `Owner` or `SettingsManager`.
+    fn assert_settings_manager(&self) {
+        self.assert_any_role(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+    }
     // Assert that the function is called by appchain registry.
     fn assert_registry(&self) {
         assert_eq!(
@@ -331,31 +586,17 @@ impl AppchainAnchor {
             "This function can only be called by appchain registry contract."
         )
     }
-    //
-    fn assert_token_price_maintainer(&self) {
-        let anchor_settings = self.anchor_settings.get().unwrap();
-        let token_price_maintainer_account = anchor_settings
-            .token_price_maintainer_account
-            .expect("Token price maintainer account is not set.");
-        assert_eq!(
-            env::predecessor_account_id(),
-            token_price_maintainer_account,
-            "Only '{}' can call this function.",
-            token_price_maintainer_account
+    // Assert that the function is called by the owner or appchain registry.
+    fn assert_owner_or_registry(&self) {
+        assert!(
+            self.has_role(&AnchorRole::Owner, &env::predecessor_account_id())
+                || env::predecessor_account_id().eq(&self.appchain_registry),
+            "This function can only be called by owner or appchain registry contract."
         );
     }
     //
     fn assert_relayer(&self) {
-        let anchor_settings = self.anchor_settings.get().unwrap();
-        let relayer_account = anchor_settings
-            .relayer_account
-            .expect("Relayer account is not set.");
-        assert_eq!(
-            env::predecessor_account_id(),
-            relayer_account,
-            "Only '{}' can call this function.",
-            relayer_account
-        );
+        self.assert_any_role(&[AnchorRole::Relayer]);
     }
     // Assert the given validator is existed in the given validator set.
     fn assert_validator_id<V: ValidatorSetViewer>(
@@ -418,6 +659,28 @@ impl AppchainAnchor {
         );
     }
     //
+    fn assert_stake_withdrawal_is_not_paused(&self) {
+        assert!(
+            !self.stake_withdrawal_is_paused,
+            "Stake withdrawal is now paused."
+        );
+    }
+    //
+    fn assert_bridge_flow_is_not_paused(
+        &self,
+        asset: &BridgingAsset,
+        direction: &BridgingDirection,
+    ) {
+        assert!(
+            !self
+                .paused_bridge_flows
+                .contains(&(asset.clone(), direction.clone())),
+            "Bridging of '{}' in direction '{}' is paused.",
+            serde_json::to_string(asset).unwrap(),
+            serde_json::to_string(direction).unwrap()
+        );
+    }
+    //
     fn assert_contract_account_of_wrapped_appchain_token_is_set(&self) {
         let wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
         assert!(
@@ -450,10 +713,7 @@ impl AppchainAnchor {
     }
     /// Set the price (in USD) of OCT token
     pub fn set_price_of_oct_token(&mut self, price: U128) {
-        self.assert_token_price_maintainer();
-        let mut oct_token = self.oct_token.get().unwrap();
-        oct_token.price_in_usd = price;
-        self.oct_token.set(&oct_token);
+        self.internal_submit_price(PricedAsset::OctToken, price);
     }
     ///
     pub fn get_market_value_of_staked_oct_token(&self) -> U128 {
@@ -470,12 +730,6 @@ impl AppchainAnchor {
     pub fn get_owner(&self) -> AccountId {
         self.owner.clone()
     }
-    //
-    pub fn set_owner(&mut self, owner: AccountId) {
-        self.assert_owner();
-        assert!(!owner.eq(&self.owner), "Owner is not changed.",);
-        self.owner = owner;
-    }
 }
 
 #[near_bindgen]
@@ -519,13 +773,20 @@ impl AppchainAnchor {
                 );
                 self.internal_process_oct_deposit(sender_id, amount, deposit_message)
             }
-            FTDepositMessage::BridgeToAppchain { .. } => self
-                .internal_process_near_fungible_token_deposit(
+            FTDepositMessage::BridgeToAppchain { .. } => {
+                self.assert_bridge_flow_is_not_paused(
+                    &BridgingAsset::NearFungibleToken {
+                        contract_account: predecessor_account_id.clone(),
+                    },
+                    &BridgingDirection::NearToAppchain,
+                );
+                self.internal_process_near_fungible_token_deposit(
                     predecessor_account_id,
                     sender_id,
                     amount,
                     deposit_message,
-                ),
+                )
+            }
         }
     }
 }
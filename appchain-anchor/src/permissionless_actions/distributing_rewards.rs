@@ -1,4 +1,5 @@
 use super::{AppchainMessagesProcessingContext, ResultOfLoopingValidatorSet};
+use crate::appchain_messages::Offender;
 use crate::*;
 use core::convert::{TryFrom, TryInto};
 
@@ -22,6 +23,7 @@ impl AppchainAnchor {
         appchain_message_nonce: u32,
         era_number: u64,
         unprofitable_validator_ids: &Vec<String>,
+        offenders: &Vec<Offender>,
     ) -> MultiTxsOperationProcessingResult {
         if !validator_set_histories.contains(&era_number) {
             return MultiTxsOperationProcessingResult::Error(format!(
@@ -66,19 +68,31 @@ impl AppchainAnchor {
         }
         validator_set.set_unprofitable_validator_ids(unprofitable_validator_ids_in_near);
         validator_set.calculate_valid_total_stake();
-        validator_set.set_processing_status(ValidatorSetProcessingStatus::DistributingReward {
-            appchain_message_nonce,
-            distributing_validator_index: U64::from(0),
-            distributing_delegator_index: U64::from(0),
-        });
+        if offenders.len() > 0 {
+            validator_set.set_processing_status(ValidatorSetProcessingStatus::SlashingOffenders {
+                appchain_message_nonce,
+                slashing_offender_index: U64::from(0),
+                slashing_delegator_index: U64::from(0),
+                slashed_amount: U128::from(0),
+            });
+        } else {
+            validator_set.set_processing_status(ValidatorSetProcessingStatus::DistributingReward {
+                appchain_message_nonce,
+                distributing_validator_index: U64::from(0),
+                distributing_delegator_index: U64::from(0),
+            });
+        }
         validator_set_histories.insert(&era_number, &validator_set);
         processing_context.set_distributing_reward_era_number(era_number);
-        // Mint `total_reward` in the contract of wrapped appchain token.
+        // Mint `total_reward` along with the relayer rewards in the contract of
+        // wrapped appchain token.
         let appchain_settings = self.appchain_settings.get().unwrap();
+        let relayer_rewards =
+            self.internal_distribute_relayer_rewards(era_number, appchain_message_nonce);
         let mut result = self.internal_mint_wrapped_appchain_token(
             None,
             &env::current_account_id(),
-            &appchain_settings.era_reward,
+            &U128::from(appchain_settings.era_reward.0 + relayer_rewards),
             appchain_message_nonce,
             processing_context,
         );
@@ -93,6 +107,7 @@ impl AppchainAnchor {
         processing_context: &mut AppchainMessagesProcessingContext,
         validator_set_histories: &mut LookupArray<ValidatorSetOfEra>,
         era_number: u64,
+        offenders: &Vec<Offender>,
     ) -> MultiTxsOperationProcessingResult {
         let mut validator_set = validator_set_histories.get(&era_number).unwrap();
         match validator_set.processing_status() {
@@ -108,17 +123,22 @@ impl AppchainAnchor {
                     era_number
                 ))
             }
+            ValidatorSetProcessingStatus::SlashingOffenders { .. } => self.slash_offenders_of_era(
+                processing_context,
+                validator_set_histories,
+                era_number,
+                offenders,
+            ),
             ValidatorSetProcessingStatus::DistributingReward {
                 appchain_message_nonce,
                 distributing_validator_index,
                 distributing_delegator_index,
             } => {
-                let validator_commission_percent = u128::from(
-                    self.protocol_settings
-                        .get()
-                        .unwrap()
-                        .validator_commission_percent,
-                );
+                let default_validator_commission_percent = self
+                    .protocol_settings
+                    .get()
+                    .unwrap()
+                    .validator_commission_percent;
                 let mut validator_index = distributing_validator_index.0;
                 let mut delegator_index = distributing_delegator_index.0;
                 let era_reward = self.appchain_settings.get().unwrap().era_reward;
@@ -131,7 +151,7 @@ impl AppchainAnchor {
                         validator_index,
                         delegator_index,
                         era_reward.0,
-                        validator_commission_percent,
+                        default_validator_commission_percent,
                     ) {
                         ResultOfLoopingValidatorSet::NoMoreDelegator => {
                             validator_index += 1;
@@ -164,7 +184,7 @@ impl AppchainAnchor {
             } => {
                 let unprofitable_validators = validator_set.unprofitable_validator_ids();
                 let protocol_settings = self.protocol_settings.get().unwrap();
-                let mut next_validator_set = self.next_validator_set.get().unwrap();
+                let next_validator_set = self.next_validator_set.get().unwrap();
                 while processing_context.used_gas_of_current_function_call()
                     < Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING)
                 {
@@ -187,26 +207,29 @@ impl AppchainAnchor {
                         let start_checking_index = era_number
                             - u64::from(protocol_settings.maximum_allowed_unprofitable_era_count)
                             + 1;
-                        let mut should_be_unbonded = true;
+                        let mut should_be_jailed = true;
                         for index in start_checking_index..era_number {
                             if let Some(set_of_era) = validator_set_histories.get(&index) {
                                 if !set_of_era
                                     .unprofitable_validator_ids()
                                     .contains(validator_id)
                                 {
-                                    should_be_unbonded = false;
+                                    should_be_jailed = false;
                                     break;
                                 }
                             }
                         }
-                        if should_be_unbonded {
-                            self.record_unbonding_validator(
-                                &protocol_settings,
-                                &mut next_validator_set,
+                        if should_be_jailed && self.jailed_validators.get(validator_id).is_none() {
+                            self.jailed_validators.insert(validator_id, &era_number);
+                            self.record_staking_fact(StakingFact::ValidatorJailed {
+                                validator_id: validator_id.clone(),
+                                era_number: U64::from(era_number),
+                            });
+                            log!(
+                                "Validator '{}' is jailed in era '{}'.",
                                 validator_id,
-                                true,
+                                era_number
                             );
-                            self.next_validator_set.set(&next_validator_set);
                         }
                     }
                     unprofitable_validator_index = U64::from(unprofitable_validator_index.0 + 1);
@@ -242,7 +265,7 @@ impl AppchainAnchor {
         validator_index: u64,
         delegator_index: u64,
         era_reward: Balance,
-        validator_commission_percent: u128,
+        default_validator_commission_percent: u16,
     ) -> ResultOfLoopingValidatorSet {
         if validator_index >= validator_set.validator_count() {
             return ResultOfLoopingValidatorSet::NoMoreValidator;
@@ -253,11 +276,17 @@ impl AppchainAnchor {
         if validator_set
             .unprofitable_validator_ids()
             .contains(&validator.validator_id)
+            || validator_set.is_jailed(&validator.validator_id)
         {
             return ResultOfLoopingValidatorSet::NoMoreDelegator;
         }
         let total_reward_of_validator = era_reward * (validator.total_stake / OCT_DECIMALS_VALUE)
             / (validator_set.valid_total_stake() / OCT_DECIMALS_VALUE);
+        let validator_commission_percent = u128::from(
+            validator_set
+                .get_commission_percent_of(&validator.validator_id)
+                .unwrap_or(default_validator_commission_percent),
+        );
         let validator_commission_reward =
             total_reward_of_validator * validator_commission_percent / 100;
         let mut reward_distribution_records = self.reward_distribution_records.get().unwrap();
@@ -373,5 +402,14 @@ impl AppchainAnchor {
             ),
             &unwithdrawn_delegator_reward,
         );
+        let mut validator_ids = self
+            .validators_of_unwithdrawn_delegator_rewards
+            .get(delegator_id)
+            .unwrap_or_default();
+        if !validator_ids.contains(validator_id) {
+            validator_ids.push(validator_id.clone());
+            self.validators_of_unwithdrawn_delegator_rewards
+                .insert(delegator_id, &validator_ids);
+        }
     }
 }
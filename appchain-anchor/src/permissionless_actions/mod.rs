@@ -1,4 +1,7 @@
 mod distributing_rewards;
+mod processing_challenges;
+mod retrying_appchain_messages;
+mod slashing;
 mod switching_era;
 
 use crate::appchain_messages::Offender;
@@ -52,6 +55,10 @@ pub enum AppchainEvent {
 pub struct AppchainMessagesProcessingContext {
     processing_status: PermissionlessActionsStatus,
     prepaid_gas_for_extra_actions: Gas,
+    /// The count of asset transfers which are started in current function call.
+    asset_transfer_count: u32,
+    /// The `failed_at` and `retry_count` of the appchain message which is being retried.
+    retrying_message: Option<(U64, u32)>,
 }
 
 impl AppchainMessagesProcessingContext {
@@ -60,8 +67,27 @@ impl AppchainMessagesProcessingContext {
         Self {
             processing_status: status,
             prepaid_gas_for_extra_actions: Gas::from(0),
+            asset_transfer_count: 0,
+            retrying_message: None,
         }
     }
+    /// Record that an asset transfer is started. An appchain message can only be
+    /// retried as a whole if no asset transfer is started for it.
+    pub fn record_asset_transfer(&mut self) {
+        self.asset_transfer_count += 1;
+    }
+    ///
+    pub fn asset_transfer_count(&self) -> u32 {
+        self.asset_transfer_count
+    }
+    ///
+    pub fn set_retrying_message(&mut self, failed_at: U64, retry_count: u32) {
+        self.retrying_message = Some((failed_at, retry_count));
+    }
+    ///
+    pub fn retrying_message(&self) -> Option<(U64, u32)> {
+        self.retrying_message
+    }
     ///
     pub fn add_prepaid_gas(&mut self, gas: Gas) {
         self.prepaid_gas_for_extra_actions = self.prepaid_gas_for_extra_actions.add(gas);
@@ -126,7 +152,7 @@ impl AppchainMessagesProcessingContext {
     }
 }
 
-enum ResultOfLoopingValidatorSet {
+pub enum ResultOfLoopingValidatorSet {
     NoMoreDelegator,
     NoMoreValidator,
     NeedToContinue,
@@ -189,6 +215,7 @@ impl PermissionlessActions for AppchainAnchor {
                 Ok(flag) => match flag {
                     true => {
                         self.beefy_light_client_state.set(&light_client);
+                        self.internal_record_relayer_workload(0, 0, 1);
                         return MultiTxsOperationProcessingResult::Ok;
                     }
                     false => (),
@@ -215,7 +242,9 @@ impl PermissionlessActions for AppchainAnchor {
     ) {
         let anchor_settings = self.anchor_settings.get().unwrap();
         if anchor_settings.beefy_light_client_witness_mode {
-            self.assert_relayer();
+            if !self.internal_check_witness_attestations(&encoded_messages) {
+                return;
+            }
         } else {
             self.assert_light_client_is_ready();
             let light_client = self.beefy_light_client_state.get().unwrap();
@@ -240,17 +269,87 @@ impl PermissionlessActions for AppchainAnchor {
     //
     fn commit_appchain_challenge(&mut self, appchain_challenge: AppchainChallenge) {
         match &appchain_challenge {
-            AppchainChallenge::EquivocationChallenge {
-                submitter_account: _,
+            AppchainChallenge::EquivocationChallenge { proof, .. } => {
+                assert!(proof.is_valid(), "Invalid equivocation challenge data.");
+                if let Some(index) = self.committed_equivocations.get(&(
+                    proof.set_id,
+                    proof.round_number(),
+                    proof.identity().0,
+                )) {
+                    panic!(
+                        "The equivocation has already been committed in challenge '{}'.",
+                        index
+                    );
+                }
+            }
+            AppchainChallenge::ConspiracyMmr {
+                block_number,
                 proof,
+                ..
             } => {
-                assert!(proof.is_valid(), "Invalid equivocation challenge data.");
+                assert!(
+                    !self
+                        .anchor_settings
+                        .get()
+                        .unwrap()
+                        .beefy_light_client_witness_mode,
+                    "Conspiracy MMR challenge is not supported in witness mode."
+                );
+                if let Some(index) = self.committed_conspiracy_mmrs.get(&proof.hash()) {
+                    panic!(
+                        "The conspiracy MMR proof has already been committed in challenge '{}'.",
+                        index
+                    );
+                }
+                self.assert_light_client_is_ready();
+                if let Err(err) =
+                    proof.verify(&self.beefy_light_client_state.get().unwrap(), *block_number)
+                {
+                    panic!("Invalid conspiracy MMR challenge data: {}", err);
+                }
             }
-            AppchainChallenge::ConspiracyMmr { .. } => (),
         }
         let mut appchain_challenges = self.appchain_challenges.get().unwrap();
         appchain_challenges.append(&mut appchain_challenge.clone());
         self.appchain_challenges.set(&appchain_challenges);
+        let index = appchain_challenges.index_range().end_index.0;
+        match &appchain_challenge {
+            AppchainChallenge::EquivocationChallenge { proof, .. } => {
+                self.committed_equivocations.insert(
+                    &(proof.set_id, proof.round_number(), proof.identity().0),
+                    &index,
+                );
+            }
+            AppchainChallenge::ConspiracyMmr {
+                block_number,
+                proof,
+                ..
+            } => {
+                self.committed_conspiracy_mmrs.insert(&proof.hash(), &index);
+                if !self.asset_transfer_is_paused {
+                    self.asset_transfer_is_paused = true;
+                    log!(
+                        "Asset transfer is paused due to conspiracy MMR of block '{}'.",
+                        block_number
+                    );
+                }
+            }
+        }
+        self.appchain_challenge_statuses
+            .insert(&index, &AppchainChallengeStatus::Pending);
+        log!("Appchain challenge '{}' is committed.", index);
+    }
+    //
+    fn process_appchain_challenge(&mut self, index: U64) -> MultiTxsOperationProcessingResult {
+        self.internal_process_appchain_challenge(index.0)
+    }
+    //
+    fn retry_appchain_message(&mut self, nonce: u32) -> MultiTxsOperationProcessingResult {
+        self.internal_retry_appchain_message(nonce)
+    }
+    //
+    fn retry_minting_bounty_of_appchain_challenge(&mut self, index: U64) {
+        self.internal_retry_minting_bounty_of_appchain_challenge(index.0);
     }
     //
     fn process_appchain_messages_with_all_proofs(
@@ -271,7 +370,11 @@ impl PermissionlessActions for AppchainAnchor {
         }
         let anchor_settings = self.anchor_settings.get().unwrap();
         if anchor_settings.beefy_light_client_witness_mode {
-            self.assert_relayer();
+            if !self.internal_check_witness_attestations(&encoded_messages) {
+                return MultiTxsOperationProcessingResult::Error(format!(
+                    "The appchain messages are not attested by enough witnesses yet."
+                ));
+            }
         } else {
             self.assert_light_client_is_ready();
             let mut light_client = self.beefy_light_client_state.get().unwrap();
@@ -291,6 +394,7 @@ impl PermissionlessActions for AppchainAnchor {
             ) {
                 Ok(()) => {
                     self.beefy_light_client_state.set(&light_client);
+                    self.internal_record_relayer_workload(0, 0, 1);
                 }
                 Err(beefy_light_client::Error::CommitmentAlreadyUpdated) => {}
                 Err(err) => panic!("Failed to update state of beefy light client: {:?}", err),
@@ -337,6 +441,8 @@ impl AppchainAnchor {
     ) -> MultiTxsOperationProcessingResult {
         let processing_status = self.permissionless_actions_status.get().unwrap();
         let appchain_messages = self.appchain_messages.get().unwrap();
+        let latest_applied_nonce_before_processing =
+            processing_status.latest_applied_appchain_message_nonce;
         let mut processing_context = AppchainMessagesProcessingContext::new(processing_status);
         let mut validator_set_histories = self.validator_set_histories.get().unwrap();
         let mut result = MultiTxsOperationProcessingResult::Ok;
@@ -355,6 +461,7 @@ impl AppchainAnchor {
             {
                 if let Some(appchain_message) = appchain_messages.get_message(&processing_nonce) {
                     let start_gas = env::used_gas();
+                    let asset_transfer_count = processing_context.asset_transfer_count();
                     result = self.internal_apply_appchain_message(
                         &mut processing_context,
                         &mut validator_set_histories,
@@ -372,6 +479,17 @@ impl AppchainAnchor {
                         }
                         MultiTxsOperationProcessingResult::NeedMoreGas => (),
                         MultiTxsOperationProcessingResult::Error(..) => {
+                            // The message can be retried later if it failed before
+                            // any asset transfer happened.
+                            if appchain_message.appchain_event.is_asset_transfer()
+                                && processing_context.asset_transfer_count() == asset_transfer_count
+                            {
+                                self.internal_add_retryable_operation(
+                                    processing_nonce,
+                                    RetryableOperation::ApplyAppchainMessage,
+                                    None,
+                                );
+                            }
                             // The loop should continue even if it fails to apply a certain message
                             processing_context.clear_processing_nonce();
                             processing_context.set_latest_applied_nonce(processing_nonce);
@@ -391,6 +509,16 @@ impl AppchainAnchor {
         self.permissionless_actions_status
             .set(processing_context.processing_status());
         self.validator_set_histories.set(&validator_set_histories);
+        if processing_context.latest_applied_nonce() > latest_applied_nonce_before_processing {
+            self.internal_record_relayer_workload(
+                0,
+                u64::from(
+                    processing_context.latest_applied_nonce()
+                        - latest_applied_nonce_before_processing,
+                ),
+                0,
+            );
+        }
         if result.is_ok()
             && processing_context.latest_applied_nonce() < processing_context.max_nonce()
         {
@@ -423,6 +551,32 @@ impl AppchainAnchor {
                     return MultiTxsOperationProcessingResult::Error(message);
                 }
                 //
+                let asset = if contract_account
+                    .eq(&String::from_str(CONTRACT_ACCOUNT_FOR_NATIVE_NEAR_TOKEN).unwrap())
+                {
+                    Some(BridgingAsset::NativeNearToken)
+                } else {
+                    AccountId::from_str(&contract_account)
+                        .ok()
+                        .map(|contract_account| BridgingAsset::NearFungibleToken {
+                            contract_account,
+                        })
+                };
+                if let Some(asset) = &asset {
+                    if let Err(message) = self.internal_check_bridge_flow(
+                        asset,
+                        &BridgingDirection::AppchainToNear,
+                        amount,
+                    ) {
+                        let result = AppchainMessageProcessingResult::Error {
+                            nonce: appchain_message.nonce,
+                            message: message.clone(),
+                        };
+                        self.record_appchain_message_processing_result(&result);
+                        return MultiTxsOperationProcessingResult::Error(message);
+                    }
+                }
+                //
                 if contract_account
                     .eq(&String::from_str(CONTRACT_ACCOUNT_FOR_NATIVE_NEAR_TOKEN).unwrap())
                 {
@@ -433,6 +587,13 @@ impl AppchainAnchor {
                         processing_context,
                     );
                     self.native_near_token.set(&native_near_token);
+                    if result.is_ok() {
+                        self.internal_record_bridge_flow(
+                            &BridgingAsset::NativeNearToken,
+                            &BridgingDirection::AppchainToNear,
+                            amount,
+                        );
+                    }
                     return result;
                 }
                 //
@@ -455,6 +616,13 @@ impl AppchainAnchor {
                     processing_context,
                 );
                 if result.is_ok() {
+                    if let Some(asset) = &asset {
+                        self.internal_record_bridge_flow(
+                            asset,
+                            &BridgingDirection::AppchainToNear,
+                            amount,
+                        );
+                    }
                     let anchor_settings = self.anchor_settings.get().unwrap();
                     result = self.internal_mint_wrapped_appchain_token(
                         Some(owner_id_in_appchain),
@@ -495,6 +663,28 @@ impl AppchainAnchor {
                     self.record_appchain_message_processing_result(&result);
                     return MultiTxsOperationProcessingResult::Error(message);
                 }
+                if let Err(message) = self
+                    .internal_check_collateralization(&BridgingAsset::WrappedAppchainToken, amount)
+                {
+                    let result = AppchainMessageProcessingResult::Error {
+                        nonce: appchain_message.nonce,
+                        message: message.clone(),
+                    };
+                    self.record_appchain_message_processing_result(&result);
+                    return MultiTxsOperationProcessingResult::Error(message);
+                }
+                if let Err(message) = self.internal_check_bridge_flow(
+                    &BridgingAsset::WrappedAppchainToken,
+                    &BridgingDirection::AppchainToNear,
+                    amount,
+                ) {
+                    let result = AppchainMessageProcessingResult::Error {
+                        nonce: appchain_message.nonce,
+                        message: message.clone(),
+                    };
+                    self.record_appchain_message_processing_result(&result);
+                    return MultiTxsOperationProcessingResult::Error(message);
+                }
                 let mut result = self.internal_mint_wrapped_appchain_token(
                     Some(owner_id_in_appchain),
                     receiver_id_in_near,
@@ -503,6 +693,11 @@ impl AppchainAnchor {
                     processing_context,
                 );
                 if result.is_ok() {
+                    self.internal_record_bridge_flow(
+                        &BridgingAsset::WrappedAppchainToken,
+                        &BridgingDirection::AppchainToNear,
+                        amount,
+                    );
                     let anchor_settings = self.anchor_settings.get().unwrap();
                     result = self.internal_mint_wrapped_appchain_token(
                         Some(owner_id_in_appchain),
@@ -522,6 +717,24 @@ impl AppchainAnchor {
                         era_number,
                     )
                 } else {
+                    if !self.appchain_state.eq(&AppchainState::Active) {
+                        // The era switching is skipped rather than failed, it is kept
+                        // in retry queue and can be retried when the appchain is active.
+                        if !self
+                            .retryable_appchain_messages
+                            .contains_key(&appchain_message.nonce)
+                        {
+                            self.internal_add_retryable_operation(
+                                appchain_message.nonce,
+                                RetryableOperation::ApplyAppchainMessage,
+                                None,
+                            );
+                        }
+                        return MultiTxsOperationProcessingResult::Error(format!(
+                            "Era switching is skipped while appchain state is '{}'.",
+                            serde_json::to_string(&self.appchain_state).unwrap()
+                        ));
+                    }
                     let index_range = validator_set_histories.index_range();
                     if u64::from(*era_number) <= index_range.end_index.0 {
                         let message = format!("Switching era number '{}' is too old.", era_number);
@@ -542,13 +755,14 @@ impl AppchainAnchor {
             AppchainEvent::EraRewardConcluded {
                 era_number,
                 unprofitable_validator_ids,
-                offenders: _,
+                offenders,
             } => {
                 if let Some(era_number) = processing_context.distributing_reward_era_number() {
                     self.complete_distributing_reward_of_era(
                         processing_context,
                         validator_set_histories,
                         era_number,
+                        offenders,
                     )
                 } else {
                     self.internal_start_distributing_reward_of_era(
@@ -557,6 +771,7 @@ impl AppchainAnchor {
                         appchain_message.nonce,
                         u64::from(*era_number),
                         unprofitable_validator_ids,
+                        offenders,
                     )
                 }
             }
@@ -577,6 +792,23 @@ impl AppchainAnchor {
                     self.record_appchain_message_processing_result(&result);
                     return MultiTxsOperationProcessingResult::Error(message);
                 }
+                if self.paused_bridge_flows.contains(&(
+                    BridgingAsset::WrappedAppchainNFT {
+                        class_id: class_id.clone(),
+                    },
+                    BridgingDirection::AppchainToNear,
+                )) {
+                    let message = format!(
+                        "Bridging of wrapped appchain NFT of class '{}' to NEAR is paused.",
+                        class_id
+                    );
+                    let result = AppchainMessageProcessingResult::Error {
+                        nonce: appchain_message.nonce,
+                        message: message.clone(),
+                    };
+                    self.record_appchain_message_processing_result(&result);
+                    return MultiTxsOperationProcessingResult::Error(message);
+                }
                 let mut result = self.internal_process_locked_nft_in_appchain(
                     processing_context,
                     appchain_message.nonce,
@@ -608,6 +840,14 @@ impl AppchainAnchor {
         let mut appchain_messages = self.appchain_messages.get().unwrap();
         appchain_messages.insert_processing_result(processing_result.nonce(), processing_result);
         self.appchain_messages.set(&appchain_messages);
+        if let Some(mut processing_results) = self
+            .appchain_message_processing_result_histories
+            .get(&processing_result.nonce())
+        {
+            processing_results.push(processing_result.clone());
+            self.appchain_message_processing_result_histories
+                .insert(&processing_result.nonce(), &processing_results);
+        }
         log!(
             "Processing result of appchain message '{}': '{}'",
             serde_json::to_string::<AppchainMessage>(
@@ -0,0 +1,428 @@
+use super::ResultOfLoopingValidatorSet;
+use crate::appchain_challenge::AppchainChallenge;
+use crate::validator_set::ValidatorSetViewer;
+use crate::*;
+use core::convert::TryFrom;
+use near_sdk::json_types::I128;
+
+/// The kind of offence of an equivocation challenge,
+/// which is used to get the slash percent in protocol settings.
+///
+/// The offence kind in Substrate is a 16 bytes id, so the kind of GRANDPA
+/// equivocation reported by appchain is `"grandpa:equivocation"` truncated to 16 bytes.
+pub const OFFENCE_KIND_OF_EQUIVOCATION: &str = "grandpa:equivoca";
+
+trait AppchainChallengeBountyResolver {
+    /// Resolver for minting bounty of appchain challenge
+    fn resolve_appchain_challenge_bounty_minting(
+        &mut self,
+        challenge_index: U64,
+        submitter_account: AccountId,
+        amount: U128,
+    );
+}
+
+impl AppchainAnchor {
+    /// Record the era number of a GRANDPA set id of appchain,
+    /// and keep the latest one for deriving the set ids of following eras.
+    pub fn internal_record_era_number_of_grandpa_set_id(&mut self, set_id: u32, era_number: u64) {
+        self.era_numbers_of_grandpa_set_ids
+            .insert(&set_id, &era_number);
+        if self
+            .latest_grandpa_set_id_of_era
+            .map_or(true, |(latest_set_id, latest_era_number)| {
+                era_number > latest_era_number
+                    || (era_number == latest_era_number && set_id > latest_set_id)
+            })
+        {
+            self.latest_grandpa_set_id_of_era = Some((set_id, era_number));
+        }
+        log!(
+            "The era number of GRANDPA set id '{}' is recorded as '{}'.",
+            set_id,
+            era_number
+        );
+    }
+    // The GRANDPA authority set of appchain changes once in each era, as the validator set
+    // is only changed at era switch. So the set id of a new era is derived from the
+    // latest recorded one. The set id of era 0 is 0.
+    pub fn derive_grandpa_set_id_of_era(&mut self, era_number: u64) {
+        let set_id = match self.latest_grandpa_set_id_of_era {
+            Some((latest_set_id, latest_era_number)) => {
+                if era_number <= latest_era_number {
+                    return;
+                }
+                latest_set_id + u32::try_from(era_number - latest_era_number).unwrap()
+            }
+            None => {
+                if era_number > 0 {
+                    return;
+                }
+                0
+            }
+        };
+        if !self.era_numbers_of_grandpa_set_ids.contains_key(&set_id) {
+            self.internal_record_era_number_of_grandpa_set_id(set_id, era_number);
+        }
+    }
+    //
+    pub fn internal_process_appchain_challenge(
+        &mut self,
+        index: u64,
+    ) -> MultiTxsOperationProcessingResult {
+        let appchain_challenge = match self.appchain_challenges.get().unwrap().get(&index) {
+            Some(appchain_challenge) => appchain_challenge,
+            None => {
+                return MultiTxsOperationProcessingResult::Error(format!(
+                    "Appchain challenge '{}' does not exist.",
+                    index
+                ))
+            }
+        };
+        match self.appchain_challenge_statuses.get(&index) {
+            Some(AppchainChallengeStatus::Pending) => {
+                self.make_verdict_of_appchain_challenge(index, &appchain_challenge)
+            }
+            Some(AppchainChallengeStatus::Accepted {
+                offender_id,
+                penalizing_delegator_index,
+                slashed_amount,
+            }) => self.penalize_offender_of_appchain_challenge(
+                index,
+                &offender_id,
+                penalizing_delegator_index.0,
+                slashed_amount.0,
+            ),
+            Some(AppchainChallengeStatus::Rejected { .. })
+            | Some(AppchainChallengeStatus::Penalized { .. })
+            | Some(AppchainChallengeStatus::Confirmed) => MultiTxsOperationProcessingResult::Ok,
+            None => MultiTxsOperationProcessingResult::Error(format!(
+                "Missing status of appchain challenge '{}'.",
+                index
+            )),
+        }
+    }
+    //
+    fn make_verdict_of_appchain_challenge(
+        &mut self,
+        index: u64,
+        appchain_challenge: &AppchainChallenge,
+    ) -> MultiTxsOperationProcessingResult {
+        match appchain_challenge {
+            AppchainChallenge::EquivocationChallenge { proof, .. } => {
+                // The era of a GRANDPA set is derived at era switch, or recorded by operator
+                // if it can not be derived. The challenge can only be judged after that.
+                let era_number = match self.era_numbers_of_grandpa_set_ids.get(&proof.set_id) {
+                    Some(era_number) => era_number,
+                    None => {
+                        return MultiTxsOperationProcessingResult::Error(format!(
+                            "Era number of GRANDPA set id '{}' is not recorded yet.",
+                            proof.set_id
+                        ))
+                    }
+                };
+                let validator_set =
+                    match self.validator_set_histories.get().unwrap().get(&era_number) {
+                        Some(validator_set) => validator_set,
+                        None => {
+                            return self.reject_appchain_challenge(
+                                index,
+                                format!("Validator set of era '{}' does not exist.", era_number),
+                            )
+                        }
+                    };
+                // The GRANDPA keys are bound by validators with the signature of the key,
+                // so the binding is trustable. The equivocation of an unbound key can not be
+                // attributed to any validator.
+                let grandpa_key = hex::encode(proof.identity().0);
+                let offender_id = match self.validator_ids_of_grandpa_keys.get(&grandpa_key) {
+                    Some(validator_id) => validator_id,
+                    None => {
+                        return self.reject_appchain_challenge(
+                            index,
+                            format!(
+                                "GRANDPA key '{}' is not bound to any validator.",
+                                grandpa_key
+                            ),
+                        )
+                    }
+                };
+                if !validator_set.contains_validator(&offender_id) {
+                    return self.reject_appchain_challenge(
+                        index,
+                        format!(
+                            "Validator '{}' is not in validator set of era '{}'.",
+                            offender_id, era_number
+                        ),
+                    );
+                }
+                self.appchain_challenge_statuses.insert(
+                    &index,
+                    &AppchainChallengeStatus::Accepted {
+                        offender_id: offender_id.clone(),
+                        penalizing_delegator_index: U64::from(0),
+                        slashed_amount: U128::from(0),
+                    },
+                );
+                log!(
+                    "Appchain challenge '{}' is accepted. Offender: '{}', era: '{}'.",
+                    index,
+                    offender_id,
+                    era_number
+                );
+                MultiTxsOperationProcessingResult::NeedMoreGas
+            }
+            AppchainChallenge::ConspiracyMmr {
+                submitter_account,
+                block_number,
+                ..
+            } => {
+                // The proof of conspiracy MMR is verified when it is committed,
+                // and the asset transfer has already been paused at that time.
+                self.appchain_challenge_statuses
+                    .insert(&index, &AppchainChallengeStatus::Confirmed);
+                log!(
+                    "Appchain challenge '{}' is confirmed. Conspiracy MMR of block '{}'.",
+                    index,
+                    block_number
+                );
+                self.mint_bounty_of_appchain_challenge(index, submitter_account, None);
+                MultiTxsOperationProcessingResult::Ok
+            }
+        }
+    }
+    //
+    fn reject_appchain_challenge(
+        &mut self,
+        index: u64,
+        reason: String,
+    ) -> MultiTxsOperationProcessingResult {
+        log!("Appchain challenge '{}' is rejected: {}", index, reason);
+        self.appchain_challenge_statuses
+            .insert(&index, &AppchainChallengeStatus::Rejected { reason });
+        MultiTxsOperationProcessingResult::Ok
+    }
+    //
+    fn penalize_offender_of_appchain_challenge(
+        &mut self,
+        index: u64,
+        offender_id: &AccountId,
+        penalizing_delegator_index: u64,
+        slashed_amount: Balance,
+    ) -> MultiTxsOperationProcessingResult {
+        let slash_percent = match self
+            .protocol_settings
+            .get()
+            .unwrap()
+            .slash_percent_per_offence_of_kinds
+            .get(OFFENCE_KIND_OF_EQUIVOCATION)
+        {
+            Some(percent) => u128::min(u128::from(*percent), 100),
+            None => 0,
+        };
+        if slash_percent > 0
+            && self
+                .anchor_settings
+                .get()
+                .unwrap()
+                .treasury_account
+                .is_none()
+        {
+            return MultiTxsOperationProcessingResult::Error(
+                "Treasury account is not set.".to_string(),
+            );
+        }
+        let mut delegator_index = penalizing_delegator_index;
+        let mut slashed_amount = slashed_amount;
+        while slash_percent > 0
+            && env::used_gas() < Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING)
+        {
+            match self.slash_validator_in_next_validator_set(
+                offender_id,
+                slash_percent,
+                delegator_index,
+                &mut slashed_amount,
+            ) {
+                ResultOfLoopingValidatorSet::NeedToContinue => delegator_index += 1,
+                ResultOfLoopingValidatorSet::NoMoreDelegator
+                | ResultOfLoopingValidatorSet::NoMoreValidator => {
+                    self.transfer_slashed_oct_token_to_treasury(slashed_amount);
+                    return self.complete_penalizing_offender_of_appchain_challenge(
+                        index,
+                        offender_id,
+                        slashed_amount,
+                    );
+                }
+            }
+        }
+        if slash_percent == 0 {
+            return self.complete_penalizing_offender_of_appchain_challenge(
+                index,
+                offender_id,
+                slashed_amount,
+            );
+        }
+        self.appchain_challenge_statuses.insert(
+            &index,
+            &AppchainChallengeStatus::Accepted {
+                offender_id: offender_id.clone(),
+                penalizing_delegator_index: U64::from(delegator_index),
+                slashed_amount: U128::from(slashed_amount),
+            },
+        );
+        MultiTxsOperationProcessingResult::NeedMoreGas
+    }
+    //
+    fn complete_penalizing_offender_of_appchain_challenge(
+        &mut self,
+        index: u64,
+        offender_id: &AccountId,
+        slashed_amount: Balance,
+    ) -> MultiTxsOperationProcessingResult {
+        self.appchain_challenge_statuses.insert(
+            &index,
+            &AppchainChallengeStatus::Penalized {
+                offender_id: offender_id.clone(),
+                slashed_amount: U128::from(slashed_amount),
+            },
+        );
+        log!(
+            "Offender '{}' of appchain challenge '{}' is penalized. Slashed amount: '{}'.",
+            offender_id,
+            index,
+            slashed_amount
+        );
+        // The bounty is only minted after the offender is penalized.
+        let submitter_account = self
+            .appchain_challenges
+            .get()
+            .unwrap()
+            .get(&index)
+            .unwrap()
+            .submitter_account()
+            .clone();
+        self.mint_bounty_of_appchain_challenge(index, &submitter_account, None);
+        MultiTxsOperationProcessingResult::Ok
+    }
+    //
+    pub fn internal_retry_minting_bounty_of_appchain_challenge(&mut self, index: u64) {
+        let bounty = match self.unminted_appchain_challenge_bounties.get(&index) {
+            Some(bounty) => bounty,
+            None => panic!(
+                "There is no unminted bounty of appchain challenge '{}'.",
+                index
+            ),
+        };
+        self.assert_contract_account_of_wrapped_appchain_token_is_set();
+        self.unminted_appchain_challenge_bounties.remove(&index);
+        let submitter_account = self
+            .appchain_challenges
+            .get()
+            .unwrap()
+            .get(&index)
+            .unwrap()
+            .submitter_account()
+            .clone();
+        self.mint_bounty_of_appchain_challenge(index, &submitter_account, Some(bounty));
+    }
+    // Mint the bounty of an appchain challenge. The bounty which can not be minted
+    // is recorded and can be minted later by `retry_minting_bounty_of_appchain_challenge`.
+    fn mint_bounty_of_appchain_challenge(
+        &mut self,
+        index: u64,
+        submitter_account: &AccountId,
+        bounty: Option<Balance>,
+    ) {
+        let bounty = U128::from(
+            bounty.unwrap_or(
+                self.appchain_settings
+                    .get()
+                    .unwrap()
+                    .bounty_for_appchain_challenge
+                    .0,
+            ),
+        );
+        if bounty.0 == 0 {
+            return;
+        }
+        let wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
+        if wrapped_appchain_token.contract_account.is_none() {
+            self.unminted_appchain_challenge_bounties
+                .insert(&index, &bounty.0);
+            log!(
+                "Contract account of wrapped appchain token is not set. The bounty of appchain challenge '{}' is not minted.",
+                index
+            );
+            return;
+        }
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Args {
+            account_id: AccountId,
+            amount: U128,
+        }
+        let args = Args {
+            account_id: submitter_account.clone(),
+            amount: bounty.clone(),
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Promise::new(wrapped_appchain_token.contract_account.unwrap())
+            .function_call(
+                "mint".to_string(),
+                args,
+                STORAGE_DEPOSIT_FOR_NEP141_TOEKN,
+                Gas::ONE_TERA.mul(T_GAS_FOR_MINT_FUNGIBLE_TOKEN),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_appchain_challenge_bounty_minting(
+                        U64::from(index),
+                        submitter_account.clone(),
+                        bounty,
+                    ),
+            );
+    }
+}
+
+#[near_bindgen]
+impl AppchainChallengeBountyResolver for AppchainAnchor {
+    //
+    fn resolve_appchain_challenge_bounty_minting(
+        &mut self,
+        challenge_index: U64,
+        submitter_account: AccountId,
+        amount: U128,
+    ) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
+                wrapped_appchain_token.changed_balance = I128::from(
+                    wrapped_appchain_token.changed_balance.0 + i128::try_from(amount.0).unwrap(),
+                );
+                self.wrapped_appchain_token.set(&wrapped_appchain_token);
+                log!(
+                    "Bounty of appchain challenge '{}' is minted for '{}'. Amount: '{}'.",
+                    challenge_index.0,
+                    submitter_account,
+                    amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                self.unminted_appchain_challenge_bounties
+                    .insert(&challenge_index.0, &amount.0);
+                log!(
+                    "Failed to mint bounty of appchain challenge '{}' for '{}'. Amount: '{}'.",
+                    challenge_index.0,
+                    submitter_account,
+                    amount.0
+                );
+            }
+        }
+    }
+}
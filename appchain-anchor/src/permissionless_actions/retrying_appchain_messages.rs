@@ -0,0 +1,191 @@
+use super::AppchainMessagesProcessingContext;
+use crate::*;
+
+impl AppchainEvent {
+    /// Whether the event is about transferring assets from appchain to NEAR protocol.
+    pub fn is_asset_transfer(&self) -> bool {
+        match self {
+            AppchainEvent::NearFungibleTokenBurnt { .. }
+            | AppchainEvent::NativeTokenLocked { .. }
+            | AppchainEvent::NonFungibleTokenLocked { .. } => true,
+            AppchainEvent::EraSwitchPlaned { .. } | AppchainEvent::EraRewardConcluded { .. } => {
+                false
+            }
+        }
+    }
+}
+
+impl AppchainAnchor {
+    /// Add an operation of a failed appchain message to the retry queue.
+    ///
+    /// If the operation failed in retrying, the `failed_at` and `retry_count` of
+    /// the retried message should be given, to keep the retry period unchanged.
+    pub fn internal_add_retryable_operation(
+        &mut self,
+        nonce: u32,
+        operation: RetryableOperation,
+        retrying_message: Option<(U64, u32)>,
+    ) {
+        let mut retryable_appchain_message = self
+            .retryable_appchain_messages
+            .get(&nonce)
+            .unwrap_or(match retrying_message {
+                Some((failed_at, retry_count)) => RetryableAppchainMessage {
+                    nonce,
+                    operations: Vec::new(),
+                    failed_at,
+                    retry_count: retry_count + 1,
+                },
+                None => RetryableAppchainMessage {
+                    nonce,
+                    operations: Vec::new(),
+                    failed_at: U64::from(env::block_timestamp()),
+                    retry_count: 0,
+                },
+            });
+        retryable_appchain_message.operations.push(operation);
+        self.retryable_appchain_messages
+            .insert(&nonce, &retryable_appchain_message);
+        // Start tracking the processing results of the message
+        if !self
+            .appchain_message_processing_result_histories
+            .contains_key(&nonce)
+        {
+            let processing_results = self
+                .appchain_messages
+                .get()
+                .unwrap()
+                .get_processing_result(&nonce)
+                .map_or(Vec::new(), |result| vec![result]);
+            self.appchain_message_processing_result_histories
+                .insert(&nonce, &processing_results);
+        }
+        log!("Appchain message '{}' is added to retry queue.", nonce);
+    }
+    ///
+    pub fn internal_retry_appchain_message(
+        &mut self,
+        nonce: u32,
+    ) -> MultiTxsOperationProcessingResult {
+        let retryable_appchain_message = match self.retryable_appchain_messages.get(&nonce) {
+            Some(retryable_appchain_message) => retryable_appchain_message,
+            None => panic!("Appchain message '{}' is not in retry queue.", nonce),
+        };
+        let account_id = env::predecessor_account_id();
+        if !self.has_role(&AnchorRole::Owner, &account_id)
+            && !self.has_role(&AnchorRole::Operator, &account_id)
+        {
+            let protocol_settings = self.protocol_settings.get().unwrap();
+            assert!(
+                env::block_timestamp()
+                    < retryable_appchain_message.failed_at.0
+                        + protocol_settings.retry_period_of_failed_appchain_message.0
+                            * SECONDS_OF_A_DAY
+                            * NANO_SECONDS_MULTIPLE,
+                "The retry period of appchain message '{}' is expired. Only owner or operator can retry it.",
+                nonce
+            );
+        }
+        // Remove the message from the queue first, to ensure that
+        // the operations will not be applied twice.
+        self.retryable_appchain_messages.remove(&nonce);
+        let mut processing_context = AppchainMessagesProcessingContext::new(
+            self.permissionless_actions_status.get().unwrap(),
+        );
+        processing_context.set_retrying_message(
+            retryable_appchain_message.failed_at,
+            retryable_appchain_message.retry_count,
+        );
+        let mut validator_set_histories = self.validator_set_histories.get().unwrap();
+        let mut remained_operations = Vec::<RetryableOperation>::new();
+        let mut result = MultiTxsOperationProcessingResult::Ok;
+        for operation in retryable_appchain_message.operations {
+            let asset_transfer_count = processing_context.asset_transfer_count();
+            let operation_result = match &operation {
+                RetryableOperation::ApplyAppchainMessage => {
+                    match self.appchain_messages.get().unwrap().get_message(&nonce) {
+                        Some(appchain_message) => self.internal_apply_appchain_message(
+                            &mut processing_context,
+                            &mut validator_set_histories,
+                            &appchain_message,
+                        ),
+                        None => MultiTxsOperationProcessingResult::Error(format!(
+                            "Missing appchain message with nonce '{}'.",
+                            nonce
+                        )),
+                    }
+                }
+                RetryableOperation::MintWrappedAppchainToken {
+                    sender_id_in_appchain,
+                    receiver_id_in_near,
+                    amount,
+                } => self.internal_retry_minting_wrapped_appchain_token(
+                    sender_id_in_appchain,
+                    receiver_id_in_near,
+                    amount,
+                    nonce,
+                    &mut processing_context,
+                ),
+            };
+            if operation_result.is_error() {
+                // The operation can be retried again only if no asset transfer happened.
+                if processing_context.asset_transfer_count() == asset_transfer_count {
+                    remained_operations.push(operation);
+                }
+                result = operation_result;
+            }
+        }
+        self.validator_set_histories.set(&validator_set_histories);
+        if remained_operations.len() > 0 {
+            self.retryable_appchain_messages.insert(
+                &nonce,
+                &RetryableAppchainMessage {
+                    nonce,
+                    operations: remained_operations,
+                    failed_at: retryable_appchain_message.failed_at,
+                    retry_count: retryable_appchain_message.retry_count + 1,
+                },
+            );
+        }
+        result
+    }
+    // Mint wrapped appchain token again, with the same checks as applying
+    // the appchain message for the first time.
+    fn internal_retry_minting_wrapped_appchain_token(
+        &mut self,
+        sender_id_in_appchain: &String,
+        receiver_id_in_near: &AccountId,
+        amount: &U128,
+        nonce: u32,
+        processing_context: &mut AppchainMessagesProcessingContext,
+    ) -> MultiTxsOperationProcessingResult {
+        let message = if self.asset_transfer_is_paused {
+            Some(format!("Asset transfer is now paused."))
+        } else {
+            // The amount has been recorded in bridge flow when the message was applied,
+            // so it is only checked here.
+            self.internal_check_bridge_flow(
+                &BridgingAsset::WrappedAppchainToken,
+                &BridgingDirection::AppchainToNear,
+                amount,
+            )
+            .err()
+        };
+        if let Some(message) = message {
+            self.record_appchain_message_processing_result(
+                &AppchainMessageProcessingResult::Error {
+                    nonce,
+                    message: message.clone(),
+                },
+            );
+            return MultiTxsOperationProcessingResult::Error(message);
+        }
+        self.internal_mint_wrapped_appchain_token(
+            Some(sender_id_in_appchain),
+            receiver_id_in_near,
+            amount,
+            nonce,
+            processing_context,
+        )
+    }
+}
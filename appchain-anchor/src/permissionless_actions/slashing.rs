@@ -0,0 +1,261 @@
+use super::{AppchainMessagesProcessingContext, ResultOfLoopingValidatorSet};
+use crate::appchain_messages::Offender;
+use crate::*;
+use core::convert::TryFrom;
+use near_contract_standards::fungible_token::core::ext_ft_core;
+
+trait SlashedOctTokenResolver {
+    /// Resolver for transferring slashed OCT token to treasury account
+    fn resolve_slashed_oct_token_transfer(&mut self, treasury_account: AccountId, amount: U128);
+}
+
+impl AppchainAnchor {
+    //
+    pub fn slash_offenders_of_era(
+        &mut self,
+        processing_context: &mut AppchainMessagesProcessingContext,
+        validator_set_histories: &mut LookupArray<ValidatorSetOfEra>,
+        era_number: u64,
+        offenders: &Vec<Offender>,
+    ) -> MultiTxsOperationProcessingResult {
+        let mut validator_set = validator_set_histories.get(&era_number).unwrap();
+        if let ValidatorSetProcessingStatus::SlashingOffenders {
+            appchain_message_nonce,
+            slashing_offender_index,
+            slashing_delegator_index,
+            slashed_amount,
+        } = validator_set.processing_status()
+        {
+            let protocol_settings = self.protocol_settings.get().unwrap();
+            let mut offender_index = slashing_offender_index.0;
+            let mut delegator_index = slashing_delegator_index.0;
+            let mut slashed_amount = slashed_amount.0;
+            while processing_context.used_gas_of_current_function_call()
+                < Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING)
+            {
+                match self.slash_offender_in_next_validator_set(
+                    &protocol_settings,
+                    offenders,
+                    offender_index,
+                    delegator_index,
+                    &mut slashed_amount,
+                ) {
+                    ResultOfLoopingValidatorSet::NoMoreDelegator => {
+                        offender_index += 1;
+                        delegator_index = 0;
+                    }
+                    ResultOfLoopingValidatorSet::NoMoreValidator => {
+                        if self.transfer_slashed_oct_token_to_treasury(slashed_amount) {
+                            processing_context
+                                .add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER));
+                            processing_context
+                                .add_prepaid_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION));
+                        }
+                        validator_set.set_processing_status(
+                            ValidatorSetProcessingStatus::DistributingReward {
+                                appchain_message_nonce,
+                                distributing_validator_index: U64::from(0),
+                                distributing_delegator_index: U64::from(0),
+                            },
+                        );
+                        validator_set_histories.insert(&era_number, &validator_set);
+                        return MultiTxsOperationProcessingResult::NeedMoreGas;
+                    }
+                    ResultOfLoopingValidatorSet::NeedToContinue => delegator_index += 1,
+                }
+            }
+            validator_set.set_processing_status(ValidatorSetProcessingStatus::SlashingOffenders {
+                appchain_message_nonce,
+                slashing_offender_index: U64::from(offender_index),
+                slashing_delegator_index: U64::from(delegator_index),
+                slashed_amount: U128::from(slashed_amount),
+            });
+            validator_set_histories.insert(&era_number, &validator_set);
+            MultiTxsOperationProcessingResult::NeedMoreGas
+        } else {
+            MultiTxsOperationProcessingResult::Error(format!(
+                "Wrong processing status '{:?}' of validator set '{}'.",
+                validator_set.processing_status(),
+                era_number
+            ))
+        }
+    }
+    /// Slash the stake of a certain offender (and his/her delegators) in next validator set.
+    fn slash_offender_in_next_validator_set(
+        &mut self,
+        protocol_settings: &ProtocolSettings,
+        offenders: &Vec<Offender>,
+        offender_index: u64,
+        delegator_index: u64,
+        slashed_amount: &mut Balance,
+    ) -> ResultOfLoopingValidatorSet {
+        if offender_index >= u64::try_from(offenders.len()).unwrap() {
+            return ResultOfLoopingValidatorSet::NoMoreValidator;
+        }
+        let offender = offenders
+            .get(usize::try_from(offender_index).unwrap())
+            .unwrap();
+        let slash_percent = match protocol_settings
+            .slash_percent_per_offence_of_kinds
+            .get(&offender.kind)
+        {
+            Some(percent) => u128::min(u128::from(*percent) * u128::from(offender.offences), 100),
+            None => 0,
+        };
+        if slash_percent == 0 {
+            return ResultOfLoopingValidatorSet::NoMoreDelegator;
+        }
+        let account_id_in_appchain =
+            AccountIdInAppchain::new(Some(offender.who.clone()), &self.appchain_template_type);
+        match self
+            .validator_profiles
+            .get()
+            .unwrap()
+            .get_by_id_in_appchain(&account_id_in_appchain.to_string())
+        {
+            Some(validator_profile) => self.slash_validator_in_next_validator_set(
+                &validator_profile.validator_id,
+                slash_percent,
+                delegator_index,
+                slashed_amount,
+            ),
+            None => {
+                log!(
+                    "Invalid validator id in appchain: '{}'. The offender is not slashed.",
+                    offender.who
+                );
+                ResultOfLoopingValidatorSet::NoMoreDelegator
+            }
+        }
+    }
+    /// Slash the stake of a certain validator (and his/her delegators) in next validator set.
+    pub fn slash_validator_in_next_validator_set(
+        &mut self,
+        validator_id: &AccountId,
+        slash_percent: u128,
+        delegator_index: u64,
+        slashed_amount: &mut Balance,
+    ) -> ResultOfLoopingValidatorSet {
+        let mut next_validator_set = self.next_validator_set.get().unwrap();
+        let validator = match next_validator_set
+            .validator_set()
+            .get_validator(validator_id)
+        {
+            Some(validator) => validator,
+            None => {
+                log!(
+                    "Validator '{}' is not in next validator set. The validator is not slashed.",
+                    validator_id
+                );
+                return ResultOfLoopingValidatorSet::NoMoreDelegator;
+            }
+        };
+        if delegator_index
+            >= next_validator_set
+                .validator_set()
+                .get_delegator_count_of(validator_id)
+        {
+            let amount = validator.deposit_amount * slash_percent / 100;
+            if amount > 0 {
+                let staking_history = self.record_staking_fact(StakingFact::ValidatorSlashed {
+                    validator_id: validator_id.clone(),
+                    amount: U128::from(amount),
+                });
+                next_validator_set.apply_staking_fact(&staking_history.staking_fact);
+                self.next_validator_set.set(&next_validator_set);
+                *slashed_amount += amount;
+                log!(
+                    "Validator '{}' is slashed. Amount: '{}'.",
+                    validator_id,
+                    amount
+                );
+            }
+            return ResultOfLoopingValidatorSet::NoMoreDelegator;
+        }
+        let delegator = next_validator_set
+            .validator_set()
+            .get_delegator_by_index(&delegator_index, validator_id)
+            .unwrap();
+        let amount = delegator.deposit_amount * slash_percent / 100;
+        if amount > 0 {
+            let staking_history = self.record_staking_fact(StakingFact::DelegatorSlashed {
+                delegator_id: delegator.delegator_id.clone(),
+                validator_id: validator_id.clone(),
+                amount: U128::from(amount),
+            });
+            next_validator_set.apply_staking_fact(&staking_history.staking_fact);
+            self.next_validator_set.set(&next_validator_set);
+            *slashed_amount += amount;
+            log!(
+                "Delegator '{}' of validator '{}' is slashed. Amount: '{}'.",
+                delegator.delegator_id,
+                validator_id,
+                amount
+            );
+        }
+        ResultOfLoopingValidatorSet::NeedToContinue
+    }
+    /// Transfer the slashed OCT token, along with the pending ones which failed to
+    /// be transferred before, to treasury account.
+    ///
+    /// If the treasury account is not set, the slashed OCT token is kept as pending.
+    /// Returns whether a transfer is started.
+    pub fn transfer_slashed_oct_token_to_treasury(&mut self, amount: Balance) -> bool {
+        self.pending_slashed_oct_token += amount;
+        if self.pending_slashed_oct_token == 0 {
+            return false;
+        }
+        match self.anchor_settings.get().unwrap().treasury_account {
+            Some(treasury_account) => {
+                let amount = U128::from(self.pending_slashed_oct_token);
+                self.pending_slashed_oct_token = 0;
+                ext_ft_core::ext(self.oct_token.get().unwrap().contract_account)
+                    .with_attached_deposit(1)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+                    .with_unused_gas_weight(0)
+                    .ft_transfer(treasury_account.clone(), amount, None)
+                    .then(
+                        ext_self::ext(env::current_account_id())
+                            .with_attached_deposit(0)
+                            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                            .with_unused_gas_weight(0)
+                            .resolve_slashed_oct_token_transfer(treasury_account, amount),
+                    );
+                true
+            }
+            None => {
+                log!(
+                    "Treasury account is not set. The slashed OCT token is kept for transferring later. Amount: '{}'.",
+                    self.pending_slashed_oct_token
+                );
+                false
+            }
+        }
+    }
+}
+
+#[near_bindgen]
+impl SlashedOctTokenResolver for AppchainAnchor {
+    //
+    fn resolve_slashed_oct_token_transfer(&mut self, treasury_account: AccountId, amount: U128) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Slashed OCT token is transferred to treasury account '{}'. Amount: '{}'.",
+                    treasury_account,
+                    amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                self.pending_slashed_oct_token += amount.0;
+                log!(
+                    "Failed to transfer slashed OCT token to treasury account '{}'. Amount: '{}'. It can be transferred again later.",
+                    treasury_account,
+                    amount.0
+                );
+            }
+        }
+    }
+}
@@ -25,6 +25,7 @@ impl AppchainAnchor {
                         .0,
                 ),
             );
+            self.derive_grandpa_set_id_of_era(era_number);
         }
         processing_context.set_switching_era_number(era_number);
         MultiTxsOperationProcessingResult::NeedMoreGas
@@ -204,6 +205,7 @@ impl AppchainAnchor {
                     applying_index.0 += 1;
                 }
                 if applying_index.0 > validator_set.staking_history_index() {
+                    self.apply_jailed_validators_to_validator_set(&mut validator_set);
                     validator_set.set_processing_status(
                         ValidatorSetProcessingStatus::SyncingStakingAmountToCouncil,
                     );
@@ -297,6 +299,10 @@ impl AppchainAnchor {
             .unwrap();
         if delegator_index >= source_validator_set.get_delegator_count_of(&validator.validator_id) {
             target_validator_set.insert_validator(&validator);
+            target_validator_set.set_commission_percent_of(
+                &validator.validator_id,
+                self.get_commission_percent_of_validator_in_next_era(&validator.validator_id),
+            );
             return ResultOfLoopingValidatorSet::NoMoreDelegator;
         }
         let delegator = source_validator_set
@@ -328,6 +334,7 @@ impl AppchainAnchor {
                 .validator_set()
                 .get_delegator_count_of(&validator.validator_id)
         {
+            self.jailed_validators.remove(&validator.validator_id);
             self.record_staking_fact(StakingFact::ValidatorUnbonded {
                 validator_id: validator.validator_id,
                 amount: U128::from(validator.deposit_amount),
@@ -368,6 +375,7 @@ impl AppchainAnchor {
                 .validator_set()
                 .get_delegator_count_of(&validator.validator_id)
         {
+            self.jailed_validators.remove(&validator.validator_id);
             self.record_staking_fact(StakingFact::ValidatorAutoUnbonded {
                 validator_id: validator.validator_id,
                 amount: U128::from(validator.deposit_amount),
@@ -385,6 +393,38 @@ impl AppchainAnchor {
         });
         return ResultOfLoopingValidatorSet::NeedToContinue;
     }
+    // Take the snapshot of jailed validators for the validator set of an era,
+    // and auto-unbond the validators which have been jailed for too long.
+    // Only the validators in next validator set are checked, so the cost is bounded
+    // by the maximum validator count.
+    fn apply_jailed_validators_to_validator_set(&mut self, validator_set: &mut ValidatorSetOfEra) {
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        let mut next_validator_set = self.next_validator_set.get().unwrap();
+        let era_number = validator_set.era_number();
+        let mut jailed_validator_ids = Vec::<AccountId>::new();
+        for validator_id in next_validator_set.validator_set().get_validator_ids() {
+            let jailed_era_number = match self.jailed_validators.get(&validator_id) {
+                Some(jailed_era_number) => jailed_era_number,
+                None => continue,
+            };
+            if era_number
+                >= jailed_era_number + u64::from(protocol_settings.maximum_jailed_era_count)
+                && !next_validator_set
+                    .get_auto_unbonding_validator_ids()
+                    .contains(&validator_id)
+            {
+                self.record_unbonding_validator(
+                    &protocol_settings,
+                    &mut next_validator_set,
+                    &validator_id,
+                    true,
+                );
+            }
+            jailed_validator_ids.push(validator_id);
+        }
+        self.next_validator_set.set(&next_validator_set);
+        validator_set.set_jailed_validator_ids(jailed_validator_ids);
+    }
     //
     fn apply_staking_history_to_validator_set_of_era(
         &mut self,
@@ -392,6 +432,13 @@ impl AppchainAnchor {
         staking_history: &StakingHistory,
     ) {
         validator_set.apply_staking_fact(&staking_history.staking_fact);
+        if let StakingFact::ValidatorRegistered { validator_id, .. } = &staking_history.staking_fact
+        {
+            validator_set.set_commission_percent_of(
+                validator_id,
+                self.get_commission_percent_of_validator_in_next_era(validator_id),
+            );
+        }
         match &staking_history.staking_fact {
             StakingFact::StakeDecreased {
                 validator_id,
@@ -451,7 +498,7 @@ impl AppchainAnchor {
         }
     }
     //
-    fn add_unbonded_stake_of(
+    pub fn add_unbonded_stake_of(
         &mut self,
         account_id: &AccountId,
         unbonded_stake_reference: UnbondedStakeReference,
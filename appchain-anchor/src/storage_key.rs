@@ -45,6 +45,44 @@ pub enum StorageKey {
     AppchainMessageProcessingResultsMap,
     AppchainChallenges,
     AppchainChallengesMap,
+    AppchainChallengeStatuses,
+    CommittedEquivocations,
+    CommittedConspiracyMmrs,
+    ValidatorCommissionPercents,
+    PendingRewardsOfBatchWithdrawal,
+    RewardBeneficiaries,
+    FailedWithdrawals,
+    JailedValidators,
+    OnboardingGrants,
+    OnboardingGrantsMap,
+    OnboardingGrantIndexes,
+    OnboardingGrantsStatus,
+    BridgeFees,
+    AccruedBridgeFees,
+    BridgeRateLimits,
+    BridgeFlowBuckets,
+    PausedBridgeFlows,
+    RetryableAppchainMessages,
+    AppchainMessageProcessingResultHistories,
+    EscrowedAssets,
+    StorageFund,
+    StorageDepositBudgets,
+    AssetPriceRecords,
+    FloorPricesOfWrappedAppchainNFTs,
+    RoleMembers,
+    RoleAuditRecords,
+    RoleAuditRecordsMap,
+    GovernanceProposals,
+    GovernanceProposalsMap,
+    WitnessAttestations,
+    WitnessAttestedNonces,
+    WitnessSubmissionStats,
+    RelayerStats,
+    PendingRelayerRewards,
+    ValidatorIdsOfGrandpaKeys,
+    EraNumbersOfGrandpaSetIds,
+    UnmintedAppchainChallengeBounties,
+    ValidatorsOfUnwithdrawnDelegatorRewards,
     WrappedAppchainNFTs,
     WrappedAppchainNFTsClassIds,
     WrappedAppchainNFTsNFTs,
@@ -59,6 +97,8 @@ pub enum StorageKey {
     UnprofitableValidatorIdsOfEra(u64),
     ValidatorRewardsOfEra(u64),
     DelegatorRewardsOfEra(u64),
+    ValidatorCommissionPercentsOfEra(u64),
+    JailedValidatorIdsOfEra(u64),
     DelegatorIdsInMapOfVToDOfEra {
         era_number: u64,
         validator_id: AccountId,
@@ -116,6 +156,44 @@ impl StorageKey {
             StorageKey::AppchainMessageProcessingResultsMap => "amprsm".to_string(),
             StorageKey::AppchainChallenges => "acs".to_string(),
             StorageKey::AppchainChallengesMap => "acsm".to_string(),
+            StorageKey::AppchainChallengeStatuses => "acss".to_string(),
+            StorageKey::CommittedEquivocations => "ceqs".to_string(),
+            StorageKey::CommittedConspiracyMmrs => "ccmmrs".to_string(),
+            StorageKey::ValidatorCommissionPercents => "vcps".to_string(),
+            StorageKey::PendingRewardsOfBatchWithdrawal => "prbw".to_string(),
+            StorageKey::RewardBeneficiaries => "rbs".to_string(),
+            StorageKey::FailedWithdrawals => "fws".to_string(),
+            StorageKey::JailedValidators => "jvs".to_string(),
+            StorageKey::OnboardingGrants => "ogs".to_string(),
+            StorageKey::OnboardingGrantsMap => "ogsm".to_string(),
+            StorageKey::OnboardingGrantIndexes => "ogis".to_string(),
+            StorageKey::OnboardingGrantsStatus => "ogst".to_string(),
+            StorageKey::BridgeFees => "bfs".to_string(),
+            StorageKey::AccruedBridgeFees => "abfs".to_string(),
+            StorageKey::BridgeRateLimits => "brls".to_string(),
+            StorageKey::BridgeFlowBuckets => "bfbs".to_string(),
+            StorageKey::PausedBridgeFlows => "pbfs".to_string(),
+            StorageKey::RetryableAppchainMessages => "rams".to_string(),
+            StorageKey::AppchainMessageProcessingResultHistories => "amprhs".to_string(),
+            StorageKey::EscrowedAssets => "eas".to_string(),
+            StorageKey::StorageFund => "sf".to_string(),
+            StorageKey::StorageDepositBudgets => "sdbs".to_string(),
+            StorageKey::AssetPriceRecords => "aprs".to_string(),
+            StorageKey::FloorPricesOfWrappedAppchainNFTs => "fpwanfts".to_string(),
+            StorageKey::RoleMembers => "rms".to_string(),
+            StorageKey::RoleAuditRecords => "rars".to_string(),
+            StorageKey::RoleAuditRecordsMap => "rarsm".to_string(),
+            StorageKey::GovernanceProposals => "gps".to_string(),
+            StorageKey::GovernanceProposalsMap => "gpsm".to_string(),
+            StorageKey::WitnessAttestations => "was".to_string(),
+            StorageKey::WitnessAttestedNonces => "wans".to_string(),
+            StorageKey::WitnessSubmissionStats => "wss".to_string(),
+            StorageKey::RelayerStats => "rss".to_string(),
+            StorageKey::PendingRelayerRewards => "prrs".to_string(),
+            StorageKey::ValidatorIdsOfGrandpaKeys => "vidsogks".to_string(),
+            StorageKey::EraNumbersOfGrandpaSetIds => "enogsids".to_string(),
+            StorageKey::UnmintedAppchainChallengeBounties => "uacbs".to_string(),
+            StorageKey::ValidatorsOfUnwithdrawnDelegatorRewards => "vouwdrs".to_string(),
             StorageKey::WrappedAppchainNFTs => "wanfts".to_string(),
             StorageKey::WrappedAppchainNFTsClassIds => "wanftscis".to_string(),
             StorageKey::WrappedAppchainNFTsNFTs => "wanftsnfts".to_string(),
@@ -132,6 +210,10 @@ impl StorageKey {
             }
             StorageKey::ValidatorRewardsOfEra(era_number) => format!("{}vrs", era_number),
             StorageKey::DelegatorRewardsOfEra(era_number) => format!("{}drs", era_number),
+            StorageKey::ValidatorCommissionPercentsOfEra(era_number) => {
+                format!("{}vcps", era_number)
+            }
+            StorageKey::JailedValidatorIdsOfEra(era_number) => format!("{}jvis", era_number),
             StorageKey::DelegatorIdsInMapOfVToDOfEra {
                 era_number,
                 validator_id,
@@ -1,8 +1,11 @@
+use crate::appchain_challenge::{ConspiracyMmrProof, EquivocationProof};
 use crate::appchain_messages::Offender;
+use crate::validator_set::ValidatorSet;
 use crate::*;
+use near_sdk::borsh::maybestd::collections::HashMap;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap};
-use near_sdk::{env, near_bindgen, AccountId, Balance};
+use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
+use near_sdk::{env, near_bindgen, AccountId, Balance, BlockHeight};
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -39,6 +42,48 @@ pub enum OldAppchainEvent {
     },
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum OldAppchainNotification {
+    /// A certain amount of a NEAR fungible token has been locked in appchain anchor.
+    NearFungibleTokenLocked {
+        contract_account: String,
+        sender_id_in_near: AccountId,
+        receiver_id_in_appchain: String,
+        amount: U128,
+    },
+    /// A certain amount of wrapped appchain token is burnt in its contract in NEAR protocol.
+    WrappedAppchainTokenBurnt {
+        sender_id_in_near: AccountId,
+        receiver_id_in_appchain: String,
+        amount: U128,
+    },
+    /// A certain wrapped non-fungible token is burnt in its contract in NEAR protocol.
+    WrappedNonFungibleTokenBurnt {
+        sender_id_in_near: AccountId,
+        receiver_id_in_appchain: String,
+        class_id: String,
+        instance_id: String,
+    },
+    /// A certain wrapped appchain NFT is locked in appchain anchor.
+    WrappedAppchainNFTLocked {
+        class_id: String,
+        token_id: String,
+        sender_id_in_near: AccountId,
+        owner_id_in_near: AccountId,
+        receiver_id_in_appchain: String,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OldAppchainNotificationHistory {
+    pub appchain_notification: OldAppchainNotification,
+    pub block_height: U64,
+    pub timestamp: U64,
+    pub index: U64,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct OldAppchainMessage {
@@ -48,6 +93,68 @@ pub struct OldAppchainMessage {
     pub nonce: u32,
 }
 
+#[derive(BorshDeserialize, BorshSerialize)]
+pub enum OldAppchainChallenge {
+    EquivocationChallenge {
+        submitter_account: AccountId,
+        proof: EquivocationProof,
+    },
+    ConspiracyMmr {
+        submitter_account: AccountId,
+        block_number: u32,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldValidatorSetOfEra {
+    pub validator_set: ValidatorSet,
+    pub start_block_height: BlockHeight,
+    pub start_timestamp: Timestamp,
+    pub staking_history_index: u64,
+    pub unprofitable_validator_id_set: UnorderedSet<AccountId>,
+    pub valid_total_stake: Balance,
+    pub validator_rewards: LookupMap<AccountId, Balance>,
+    pub delegator_rewards: LookupMap<(AccountId, AccountId), Balance>,
+    pub processing_status: ValidatorSetProcessingStatus,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldAppchainSettings {
+    pub rpc_endpoint: String,
+    pub subql_endpoint: String,
+    pub era_reward: U128,
+    pub bonus_for_new_validator: U128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldAnchorSettings {
+    pub token_price_maintainer_account: Option<AccountId>,
+    pub relayer_account: Option<AccountId>,
+    pub beefy_light_client_witness_mode: bool,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldProtocolSettings {
+    pub minimum_validator_deposit: U128,
+    pub minimum_validator_deposit_changing_amount: U128,
+    pub maximum_validator_stake_percent: u16,
+    pub minimum_delegator_deposit: U128,
+    pub minimum_delegator_deposit_changing_amount: U128,
+    pub minimum_total_stake_price_for_booting: U128,
+    pub maximum_market_value_percent_of_near_fungible_tokens: u16,
+    pub maximum_market_value_percent_of_wrapped_appchain_token: u16,
+    pub minimum_validator_count: U64,
+    pub maximum_validator_count: U64,
+    pub maximum_validators_per_delegator: U64,
+    pub unlock_period_of_validator_deposit: U64,
+    pub unlock_period_of_delegator_deposit: U64,
+    pub maximum_era_count_of_unwithdrawn_reward: U64,
+    pub maximum_era_count_of_valid_appchain_message: U64,
+    pub validator_commission_percent: u16,
+    pub maximum_allowed_unprofitable_era_count: u16,
+    pub subaccount_for_council_keeper_contract: String,
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct OldAppchainAnchor {
     /// The id of corresponding appchain.
@@ -82,11 +189,11 @@ pub struct OldAppchainAnchor {
     /// The validators' profiles data.
     validator_profiles: LazyOption<ValidatorProfiles>,
     /// The custom settings for appchain.
-    appchain_settings: LazyOption<AppchainSettings>,
+    appchain_settings: LazyOption<OldAppchainSettings>,
     /// The anchor settings for appchain.
-    anchor_settings: LazyOption<AnchorSettings>,
+    anchor_settings: LazyOption<OldAnchorSettings>,
     /// The protocol settings for appchain anchor.
-    protocol_settings: LazyOption<ProtocolSettings>,
+    protocol_settings: LazyOption<OldProtocolSettings>,
     /// The state of the corresponding appchain.
     appchain_state: AppchainState,
     /// The staking history data happened in this contract.
@@ -118,14 +225,22 @@ pub struct OldAppchainAnchor {
 #[near_bindgen]
 impl AppchainAnchor {
     #[init(ignore_state)]
-    pub fn migrate_state() -> Self {
+    pub fn migrate_state(onboarding_grant_budget: U128) -> Self {
         // Deserialize the state using the old contract structure.
         let old_contract: OldAppchainAnchor = env::state_read().expect("Old state doesn't exist");
         //
         near_sdk::assert_self();
         //
+        // Keep the previous trust model if witness mode is on, as the relayer account
+        // is the only witness right after upgrade.
+        let witness_mode_is_on = old_contract
+            .anchor_settings
+            .get()
+            .unwrap()
+            .beefy_light_client_witness_mode;
+        //
         // Create the new contract using the data from the old contract.
-        let new_contract = AppchainAnchor {
+        let mut new_contract = AppchainAnchor {
             appchain_id: old_contract.appchain_id,
             appchain_template_type: old_contract.appchain_template_type,
             appchain_registry: old_contract.appchain_registry,
@@ -140,9 +255,25 @@ impl AppchainAnchor {
             unwithdrawn_delegator_rewards: old_contract.unwithdrawn_delegator_rewards,
             unbonded_stakes: old_contract.unbonded_stakes,
             validator_profiles: old_contract.validator_profiles,
-            appchain_settings: old_contract.appchain_settings,
-            anchor_settings: old_contract.anchor_settings,
-            protocol_settings: old_contract.protocol_settings,
+            appchain_settings: LazyOption::new(
+                StorageKey::AppchainSettings.into_bytes(),
+                Some(&AppchainSettings::from_old_version(
+                    old_contract.appchain_settings.get().unwrap(),
+                )),
+            ),
+            anchor_settings: LazyOption::new(
+                StorageKey::AnchorSettings.into_bytes(),
+                Some(&AnchorSettings::from_old_version(
+                    old_contract.anchor_settings.get().unwrap(),
+                )),
+            ),
+            protocol_settings: LazyOption::new(
+                StorageKey::ProtocolSettings.into_bytes(),
+                Some(&ProtocolSettings::from_old_version(
+                    old_contract.protocol_settings.get().unwrap(),
+                    witness_mode_is_on,
+                )),
+            ),
             appchain_state: old_contract.appchain_state,
             staking_histories: old_contract.staking_histories,
             appchain_notification_histories: old_contract.appchain_notification_histories,
@@ -156,8 +287,124 @@ impl AppchainAnchor {
             appchain_challenges: old_contract.appchain_challenges,
             wrapped_appchain_nfts: old_contract.wrapped_appchain_nfts,
             native_near_token: old_contract.native_near_token,
+            appchain_challenge_statuses: LookupMap::new(
+                StorageKey::AppchainChallengeStatuses.into_bytes(),
+            ),
+            committed_equivocations: LookupMap::new(
+                StorageKey::CommittedEquivocations.into_bytes(),
+            ),
+            committed_conspiracy_mmrs: LookupMap::new(
+                StorageKey::CommittedConspiracyMmrs.into_bytes(),
+            ),
+            validator_commission_percents: LookupMap::new(
+                StorageKey::ValidatorCommissionPercents.into_bytes(),
+            ),
+            pending_rewards_of_batch_withdrawal: LookupMap::new(
+                StorageKey::PendingRewardsOfBatchWithdrawal.into_bytes(),
+            ),
+            reward_beneficiaries: LookupMap::new(StorageKey::RewardBeneficiaries.into_bytes()),
+            failed_withdrawals: LookupMap::new(StorageKey::FailedWithdrawals.into_bytes()),
+            jailed_validators: LookupMap::new(StorageKey::JailedValidators.into_bytes()),
+            onboarding_grants: LazyOption::new(
+                StorageKey::OnboardingGrants.into_bytes(),
+                Some(&LookupArray::new(StorageKey::OnboardingGrantsMap)),
+            ),
+            onboarding_grant_indexes: LookupMap::new(
+                StorageKey::OnboardingGrantIndexes.into_bytes(),
+            ),
+            onboarding_grants_status: LazyOption::new(
+                StorageKey::OnboardingGrantsStatus.into_bytes(),
+                Some(&OnboardingGrantsStatus {
+                    remaining_budget: onboarding_grant_budget,
+                    ..OnboardingGrantsStatus::default()
+                }),
+            ),
+            bridge_fees: UnorderedMap::new(StorageKey::BridgeFees.into_bytes()),
+            accrued_bridge_fees: UnorderedMap::new(StorageKey::AccruedBridgeFees.into_bytes()),
+            bridge_rate_limits: LookupMap::new(StorageKey::BridgeRateLimits.into_bytes()),
+            bridge_flow_buckets: LookupMap::new(StorageKey::BridgeFlowBuckets.into_bytes()),
+            paused_bridge_flows: UnorderedSet::new(StorageKey::PausedBridgeFlows.into_bytes()),
+            stake_withdrawal_is_paused: false,
+            retryable_appchain_messages: UnorderedMap::new(
+                StorageKey::RetryableAppchainMessages.into_bytes(),
+            ),
+            appchain_message_processing_result_histories: LookupMap::new(
+                StorageKey::AppchainMessageProcessingResultHistories.into_bytes(),
+            ),
+            escrowed_assets: LookupMap::new(StorageKey::EscrowedAssets.into_bytes()),
+            storage_fund: LazyOption::new(
+                StorageKey::StorageFund.into_bytes(),
+                Some(&StorageFund::default()),
+            ),
+            storage_deposit_budgets: LookupMap::new(StorageKey::StorageDepositBudgets.into_bytes()),
+            role_members: LookupMap::new(StorageKey::RoleMembers.into_bytes()),
+            role_audit_records: LazyOption::new(
+                StorageKey::RoleAuditRecords.into_bytes(),
+                Some(&LookupArray::new(StorageKey::RoleAuditRecordsMap)),
+            ),
+            governance_proposals: LazyOption::new(
+                StorageKey::GovernanceProposals.into_bytes(),
+                Some(&LookupArray::new(StorageKey::GovernanceProposalsMap)),
+            ),
+            pending_owner: None,
+            witness_attestations: LookupMap::new(StorageKey::WitnessAttestations.into_bytes()),
+            witness_attested_nonces: UnorderedSet::new(
+                StorageKey::WitnessAttestedNonces.into_bytes(),
+            ),
+            witness_submission_stats: LookupMap::new(
+                StorageKey::WitnessSubmissionStats.into_bytes(),
+            ),
+            relayer_stats: UnorderedMap::new(StorageKey::RelayerStats.into_bytes()),
+            relayers_of_current_era: Vec::new(),
+            pending_relayer_rewards: LookupMap::new(StorageKey::PendingRelayerRewards.into_bytes()),
+            pending_slashed_oct_token: 0,
+            validator_ids_of_grandpa_keys: LookupMap::new(
+                StorageKey::ValidatorIdsOfGrandpaKeys.into_bytes(),
+            ),
+            era_numbers_of_grandpa_set_ids: LookupMap::new(
+                StorageKey::EraNumbersOfGrandpaSetIds.into_bytes(),
+            ),
+            latest_grandpa_set_id_of_era: None,
+            unminted_appchain_challenge_bounties: LookupMap::new(
+                StorageKey::UnmintedAppchainChallengeBounties.into_bytes(),
+            ),
+            closing_unbonding_timestamp: None,
+            validators_of_unwithdrawn_delegator_rewards: LookupMap::new(
+                StorageKey::ValidatorsOfUnwithdrawnDelegatorRewards.into_bytes(),
+            ),
+            asset_price_records: LookupMap::new(StorageKey::AssetPriceRecords.into_bytes()),
+            floor_prices_of_wrapped_appchain_nfts: LookupMap::new(
+                StorageKey::FloorPricesOfWrappedAppchainNFTs.into_bytes(),
+            ),
         };
         //
+        // Seed the price records of priced assets with the prices stored before upgrade,
+        // to prevent them from being treated as stale right after upgrade.
+        let mut priced_assets = vec![
+            PricedAsset::OctToken,
+            PricedAsset::WrappedAppchainToken,
+            PricedAsset::NativeNearToken,
+        ];
+        priced_assets.extend(
+            new_contract
+                .near_fungible_tokens
+                .get()
+                .unwrap()
+                .to_vec()
+                .iter()
+                .map(|near_fungible_token| PricedAsset::NearFungibleToken {
+                    symbol: near_fungible_token.metadata.symbol.clone(),
+                }),
+        );
+        for asset in priced_assets {
+            new_contract.asset_price_records.insert(
+                &asset,
+                &AssetPriceRecord {
+                    updated_at: U64::from(env::block_timestamp()),
+                    submissions: Vec::new(),
+                },
+            );
+        }
         //
         new_contract
     }
@@ -191,6 +438,105 @@ impl AppchainAnchor {
         }
         MultiTxsOperationProcessingResult::Ok
     }
+    ///
+    pub fn migrate_appchain_notification_histories(
+        &mut self,
+        start_index: U64,
+    ) -> MultiTxsOperationProcessingResult {
+        near_sdk::assert_self();
+        let appchain_notification_histories = self.appchain_notification_histories.get().unwrap();
+        for index in start_index.0..appchain_notification_histories.end_index + 1 {
+            if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
+                return MultiTxsOperationProcessingResult::Error(format!(
+                    "Not all records are migrated. Call this function again with start_index '{}'.",
+                    index
+                ));
+            }
+            if let Some(old_data) = env::storage_read(&get_storage_key_in_lookup_array(
+                &StorageKey::AppchainNotificationHistoriesMap,
+                &index,
+            )) {
+                if let Ok(old_version) = OldAppchainNotificationHistory::try_from_slice(&old_data) {
+                    env::storage_write(
+                        &get_storage_key_in_lookup_array(
+                            &StorageKey::AppchainNotificationHistoriesMap,
+                            &index,
+                        ),
+                        &AppchainNotificationHistory::from_old_version(old_version)
+                            .try_to_vec()
+                            .unwrap(),
+                    );
+                }
+            }
+        }
+        MultiTxsOperationProcessingResult::Ok
+    }
+    ///
+    pub fn migrate_validator_sets_of_era(
+        &mut self,
+        start_era_number: U64,
+    ) -> MultiTxsOperationProcessingResult {
+        near_sdk::assert_self();
+        let validator_set_histories = self.validator_set_histories.get().unwrap();
+        for era_number in start_era_number.0..validator_set_histories.end_index + 1 {
+            if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
+                return MultiTxsOperationProcessingResult::Error(format!(
+                    "Not all records are migrated. Call this function again with start_era_number '{}'.",
+                    era_number
+                ));
+            }
+            if let Some(old_data) = env::storage_read(&get_storage_key_in_lookup_array(
+                &StorageKey::ValidatorSetHistoriesMap,
+                &era_number,
+            )) {
+                if let Ok(old_version) = OldValidatorSetOfEra::try_from_slice(&old_data) {
+                    env::storage_write(
+                        &get_storage_key_in_lookup_array(
+                            &StorageKey::ValidatorSetHistoriesMap,
+                            &era_number,
+                        ),
+                        &ValidatorSetOfEra::from_old_version(old_version)
+                            .try_to_vec()
+                            .unwrap(),
+                    );
+                }
+            }
+        }
+        MultiTxsOperationProcessingResult::Ok
+    }
+    ///
+    pub fn migrate_appchain_challenges(
+        &mut self,
+        start_index: U64,
+    ) -> MultiTxsOperationProcessingResult {
+        near_sdk::assert_self();
+        let appchain_challenges = self.appchain_challenges.get().unwrap();
+        for index in start_index.0..appchain_challenges.end_index + 1 {
+            if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
+                return MultiTxsOperationProcessingResult::Error(format!(
+                    "Not all records are migrated. Call this function again with start_index '{}'.",
+                    index
+                ));
+            }
+            if let Some(old_data) = env::storage_read(&get_storage_key_in_lookup_array(
+                &StorageKey::AppchainChallengesMap,
+                &index,
+            )) {
+                if let Ok(old_version) = OldAppchainChallenge::try_from_slice(&old_data) {
+                    env::storage_write(
+                        &get_storage_key_in_lookup_array(
+                            &StorageKey::AppchainChallengesMap,
+                            &index,
+                        ),
+                        &AppchainChallenge::from_old_version(old_version)
+                            .try_to_vec()
+                            .unwrap(),
+                    );
+                }
+            }
+        }
+        MultiTxsOperationProcessingResult::Ok
+    }
 }
 
 pub fn get_storage_key_in_lookup_array<T: BorshSerialize>(
@@ -256,6 +602,166 @@ impl AppchainEvent {
     }
 }
 
+impl AppchainNotificationHistory {
+    //
+    pub fn from_old_version(old_version: OldAppchainNotificationHistory) -> Self {
+        Self {
+            appchain_notification: match old_version.appchain_notification {
+                OldAppchainNotification::NearFungibleTokenLocked {
+                    contract_account,
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    amount,
+                } => AppchainNotification::NearFungibleTokenLocked {
+                    contract_account,
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    amount,
+                    fee: 0.into(),
+                },
+                OldAppchainNotification::WrappedAppchainTokenBurnt {
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    amount,
+                } => AppchainNotification::WrappedAppchainTokenBurnt {
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    amount,
+                    fee: 0.into(),
+                },
+                OldAppchainNotification::WrappedNonFungibleTokenBurnt {
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    class_id,
+                    instance_id,
+                } => AppchainNotification::WrappedNonFungibleTokenBurnt {
+                    sender_id_in_near,
+                    receiver_id_in_appchain,
+                    class_id,
+                    instance_id,
+                },
+                OldAppchainNotification::WrappedAppchainNFTLocked {
+                    class_id,
+                    token_id,
+                    sender_id_in_near,
+                    owner_id_in_near,
+                    receiver_id_in_appchain,
+                } => AppchainNotification::WrappedAppchainNFTLocked {
+                    class_id,
+                    token_id,
+                    sender_id_in_near,
+                    owner_id_in_near,
+                    receiver_id_in_appchain,
+                    fee: 0.into(),
+                },
+            },
+            block_height: old_version.block_height,
+            timestamp: old_version.timestamp,
+            index: old_version.index,
+        }
+    }
+}
+
+impl AppchainSettings {
+    //
+    pub fn from_old_version(old_version: OldAppchainSettings) -> Self {
+        Self {
+            rpc_endpoint: old_version.rpc_endpoint,
+            subql_endpoint: old_version.subql_endpoint,
+            era_reward: old_version.era_reward,
+            bonus_for_new_validator: old_version.bonus_for_new_validator,
+            bounty_for_appchain_challenge: U128::from(0),
+            relayer_reward_per_era: U128::from(0),
+        }
+    }
+}
+
+impl AnchorSettings {
+    //
+    pub fn from_old_version(old_version: OldAnchorSettings) -> Self {
+        Self {
+            token_price_maintainer_account: old_version.token_price_maintainer_account,
+            relayer_account: old_version.relayer_account,
+            beefy_light_client_witness_mode: old_version.beefy_light_client_witness_mode,
+            treasury_account: None,
+        }
+    }
+}
+
+impl ProtocolSettings {
+    //
+    pub fn from_old_version(old_version: OldProtocolSettings, witness_mode_is_on: bool) -> Self {
+        Self {
+            minimum_validator_deposit: old_version.minimum_validator_deposit,
+            minimum_validator_deposit_changing_amount: old_version
+                .minimum_validator_deposit_changing_amount,
+            maximum_validator_stake_percent: old_version.maximum_validator_stake_percent,
+            minimum_delegator_deposit: old_version.minimum_delegator_deposit,
+            minimum_delegator_deposit_changing_amount: old_version
+                .minimum_delegator_deposit_changing_amount,
+            minimum_total_stake_price_for_booting: old_version
+                .minimum_total_stake_price_for_booting,
+            maximum_market_value_percent_of_near_fungible_tokens: old_version
+                .maximum_market_value_percent_of_near_fungible_tokens,
+            maximum_market_value_percent_of_wrapped_appchain_token: old_version
+                .maximum_market_value_percent_of_wrapped_appchain_token,
+            minimum_validator_count: old_version.minimum_validator_count,
+            maximum_validator_count: old_version.maximum_validator_count,
+            maximum_validators_per_delegator: old_version.maximum_validators_per_delegator,
+            unlock_period_of_validator_deposit: old_version.unlock_period_of_validator_deposit,
+            unlock_period_of_delegator_deposit: old_version.unlock_period_of_delegator_deposit,
+            maximum_era_count_of_unwithdrawn_reward: old_version
+                .maximum_era_count_of_unwithdrawn_reward,
+            maximum_era_count_of_valid_appchain_message: old_version
+                .maximum_era_count_of_valid_appchain_message,
+            validator_commission_percent: old_version.validator_commission_percent,
+            maximum_allowed_unprofitable_era_count: old_version
+                .maximum_allowed_unprofitable_era_count,
+            subaccount_for_council_keeper_contract: old_version
+                .subaccount_for_council_keeper_contract,
+            slash_percent_per_offence_of_kinds: HashMap::new(),
+            maximum_validator_commission_percent: 50,
+            maximum_validator_commission_percent_change_per_era: 5,
+            minimum_jailed_era_count_before_unjail: 2,
+            maximum_jailed_era_count: 7,
+            retry_period_of_failed_appchain_message: U64::from(7),
+            maximum_price_deviation_percent_per_update: 20,
+            staleness_timeout_of_token_price: U64::from(86400),
+            // Only the token price maintainer account reports prices before upgrade.
+            minimum_price_submission_count: 1,
+            governance_timelock_delay: U64::from(172800),
+            witness_attestation_threshold: match witness_mode_is_on {
+                true => 1,
+                false => 3,
+            },
+        }
+    }
+}
+
+impl AppchainChallenge {
+    //
+    pub fn from_old_version(old_version: OldAppchainChallenge) -> Self {
+        match old_version {
+            OldAppchainChallenge::EquivocationChallenge {
+                submitter_account,
+                proof,
+            } => AppchainChallenge::EquivocationChallenge {
+                submitter_account,
+                proof,
+            },
+            // The conspiracy MMR challenges committed before carry no proof.
+            OldAppchainChallenge::ConspiracyMmr {
+                submitter_account,
+                block_number,
+            } => AppchainChallenge::ConspiracyMmr {
+                submitter_account,
+                block_number,
+                proof: ConspiracyMmrProof::default(),
+            },
+        }
+    }
+}
+
 impl AppchainMessage {
     //
     pub fn from_old_version(old_version: OldAppchainMessage) -> Self {
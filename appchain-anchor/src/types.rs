@@ -2,7 +2,7 @@ use crate::*;
 use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
 use near_contract_standards::non_fungible_token::metadata::NFTContractMetadata;
 use near_sdk::borsh::maybestd::collections::HashMap;
-use near_sdk::json_types::I128;
+use near_sdk::json_types::{Base58CryptoHash, I128};
 
 pub type AppchainId = String;
 
@@ -103,6 +103,10 @@ pub struct AppchainSettings {
     pub subql_endpoint: String,
     pub era_reward: U128,
     pub bonus_for_new_validator: U128,
+    pub bounty_for_appchain_challenge: U128,
+    /// The amount of wrapped appchain token which will be split to relayers
+    /// pro rata by their workload in each era.
+    pub relayer_reward_per_era: U128,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -111,6 +115,7 @@ pub struct AnchorSettings {
     pub token_price_maintainer_account: Option<AccountId>,
     pub relayer_account: Option<AccountId>,
     pub beefy_light_client_witness_mode: bool,
+    pub treasury_account: Option<AccountId>,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -160,11 +165,43 @@ pub struct ProtocolSettings {
     /// this value, the message will be considered as `invalid`.
     pub maximum_era_count_of_valid_appchain_message: U64,
     /// The percent of commission fees of a validator's reward in an era.
+    /// This is the default value for validators which have not set their own commission percent.
     pub validator_commission_percent: u16,
     /// The maximum unprofitable era count for auto-unbonding a validator.
     pub maximum_allowed_unprofitable_era_count: u16,
     /// The subaccount name for council keeper contract.
     pub subaccount_for_council_keeper_contract: String,
+    /// The percent of stake to be slashed for each offence, mapped by the kind of offence.
+    /// The offences of a kind which is not in this map will not be slashed.
+    pub slash_percent_per_offence_of_kinds: HashMap<String, u16>,
+    /// The maximum commission percent that a validator can set.
+    pub maximum_validator_commission_percent: u16,
+    /// The maximum change of commission percent of a validator in an era.
+    pub maximum_validator_commission_percent_change_per_era: u16,
+    /// The minimum era count that a jailed validator needs to wait before unjailing.
+    pub minimum_jailed_era_count_before_unjail: u16,
+    /// The maximum era count that a validator can be jailed.
+    /// The validator will be auto-unbonded after this period.
+    pub maximum_jailed_era_count: u16,
+    /// The period (in days) in which a failed appchain message can be retried by anyone.
+    /// After this period, only the owner can retry the message.
+    pub retry_period_of_failed_appchain_message: U64,
+    /// The maximum percentage of the deviation between a submitted price and
+    /// the current price of an asset.
+    pub maximum_price_deviation_percent_per_update: u16,
+    /// The timeout (in seconds) after which the price of an asset is considered as stale,
+    /// and the bridging of assets into appchain is blocked.
+    /// The value `0` means the prices never become stale.
+    pub staleness_timeout_of_token_price: U64,
+    /// The minimum number of valid submissions from different price reporters,
+    /// before the median of them is accepted as the price of an asset.
+    pub minimum_price_submission_count: u16,
+    /// The delay (in seconds) before a governance proposal can be executed.
+    /// The value `0` means the timelocked functions can also be called directly.
+    pub governance_timelock_delay: U64,
+    /// The number of witnesses which need to attest the same appchain messages
+    /// before they can be staged in witness mode of beefy light client.
+    pub witness_attestation_threshold: u16,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -290,6 +327,29 @@ pub enum StakingFact {
         old_validator_id: AccountId,
         new_validator_id: AccountId,
     },
+    /// A validator's deposit is slashed for the offences in appchain
+    ValidatorSlashed {
+        validator_id: AccountId,
+        amount: U128,
+    },
+    /// A delegator's delegation is slashed for the offences of his/her validator in appchain
+    DelegatorSlashed {
+        delegator_id: AccountId,
+        validator_id: AccountId,
+        amount: U128,
+    },
+    /// A validator or delegator changes the beneficiary account of his/her rewards
+    RewardBeneficiaryChanged {
+        account_id: AccountId,
+        beneficiary_id: Option<AccountId>,
+    },
+    /// A validator is jailed for being unprofitable in too many eras
+    ValidatorJailed {
+        validator_id: AccountId,
+        era_number: U64,
+    },
+    /// A jailed validator is unjailed
+    ValidatorUnjailed { validator_id: AccountId },
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -311,6 +371,7 @@ pub struct AppchainValidator {
     pub delegators_count: U64,
     pub can_be_delegated_to: bool,
     pub is_unbonding: bool,
+    pub commission_percent: Option<u16>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -335,10 +396,116 @@ pub struct UnbondedStake {
     pub unlock_time: U64,
 }
 
+/// The rewards taken from unwithdrawn rewards in a withdrawal.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawnRewards {
+    /// The rewards as a validator, in format `(era_number, amount)`.
+    pub validator_rewards: Vec<(U64, U128)>,
+    /// The rewards as a delegator, in format `(era_number, validator_id, amount)`.
+    pub delegator_rewards: Vec<(U64, AccountId, U128)>,
+}
+
+impl WithdrawnRewards {
+    ///
+    pub fn new() -> Self {
+        Self {
+            validator_rewards: Vec::new(),
+            delegator_rewards: Vec::new(),
+        }
+    }
+    ///
+    pub fn total_amount(&self) -> Balance {
+        self.validator_rewards
+            .iter()
+            .map(|(_, amount)| amount.0)
+            .sum::<Balance>()
+            + self
+                .delegator_rewards
+                .iter()
+                .map(|(_, _, amount)| amount.0)
+                .sum::<Balance>()
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum WithdrawalKind {
+    Stake,
+    Rewards,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FailedWithdrawal {
+    pub kind: WithdrawalKind,
+    pub receiver_id: AccountId,
+    pub token_contract: AccountId,
+    pub amount: U128,
+    pub timestamp: U64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum OnboardingGrantStatus {
+    Pending,
+    Granted,
+    Failed,
+    /// The grant is recorded when the budget is not enough,
+    /// it can be claimed after the budget is topped up.
+    WaitingForBudget,
+}
+
+/// The grant of wrapped appchain token for a new validator,
+/// which is sent to the account of the validator in appchain.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OnboardingGrant {
+    pub index: U64,
+    pub validator_id: AccountId,
+    pub receiver_id_in_appchain: String,
+    pub amount: U128,
+    pub status: OnboardingGrantStatus,
+    /// The timestamp of the last change of status.
+    pub timestamp: U64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OnboardingGrantsStatus {
+    /// The remaining budget for onboarding grants.
+    pub remaining_budget: U128,
+    /// The total amount of onboarding grants which are sent successfully.
+    pub granted_amount: U128,
+    /// The last synced balance of wrapped appchain token faucet.
+    pub wat_faucet_balance: Option<U128>,
+    /// The timestamp when the balance of wrapped appchain token faucet is synced.
+    pub wat_faucet_balance_synced_at: Option<U64>,
+}
+
+impl Default for OnboardingGrantsStatus {
+    fn default() -> Self {
+        Self {
+            remaining_budget: U128::from(0),
+            granted_amount: U128::from(0),
+            wat_faucet_balance: None,
+            wat_faucet_balance_synced_at: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JailedValidator {
+    pub validator_id: AccountId,
+    /// The era number when the validator is jailed.
+    pub jailed_era_number: U64,
+}
+
 /// The actual processing order is:
 /// `CopyingFromLastEra` -> `UnbondingValidator`-> `AutoUnbondingValidator`
 /// -> `ApplyingStakingHistory` -> `SyncingStakingAmountToCouncil` -> `ReadyForDistributingReward`
-/// -> `DistributingReward`
+/// -> `SlashingOffenders` -> `DistributingReward`
 /// -> `CheckingForAutoUnbondingValidator` -> `Completed`
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
@@ -369,6 +536,12 @@ pub enum ValidatorSetProcessingStatus {
         unprofitable_validator_index: U64,
     },
     SyncingStakingAmountToCouncil,
+    SlashingOffenders {
+        appchain_message_nonce: u32,
+        slashing_offender_index: U64,
+        slashing_delegator_index: U64,
+        slashed_amount: U128,
+    },
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -415,6 +588,9 @@ pub struct AnchorStatus {
     pub permissionless_actions_status: PermissionlessActionsStatus,
     pub asset_transfer_is_paused: bool,
     pub rewards_withdrawal_is_paused: bool,
+    pub stake_withdrawal_is_paused: bool,
+    /// The assets and directions whose bridging is paused.
+    pub paused_bridge_flows: Vec<(BridgingAsset, BridgingDirection)>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -436,6 +612,8 @@ pub struct ValidatorSetInfo {
     pub unprofitable_validator_ids: Vec<AccountId>,
     /// Total stake excluding all unprofitable validators' stake.
     pub valid_total_stake: U128,
+    /// The set of validator id which are jailed in this era.
+    pub jailed_validator_ids: Vec<AccountId>,
     /// The status of creation of this set
     pub processing_status: ValidatorSetProcessingStatus,
 }
@@ -460,12 +638,14 @@ pub enum AppchainNotification {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
+        fee: U128,
     },
     /// A certain amount of wrapped appchain token is burnt in its contract in NEAR protocol.
     WrappedAppchainTokenBurnt {
         sender_id_in_near: AccountId,
         receiver_id_in_appchain: String,
         amount: U128,
+        fee: U128,
     },
     /// A certain wrapped non-fungible token is burnt in its contract in NEAR protocol.
     WrappedNonFungibleTokenBurnt {
@@ -481,9 +661,241 @@ pub enum AppchainNotification {
         sender_id_in_near: AccountId,
         owner_id_in_near: AccountId,
         receiver_id_in_appchain: String,
+        fee: U128,
     },
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum GovernanceProposalStatus {
+    Pending,
+    Executing,
+    Executed,
+    Failed,
+    Cancelled,
+}
+
+/// A scheduled call of a timelocked function of this contract.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GovernanceProposal {
+    pub index: U64,
+    pub method_name: String,
+    /// The args of the function call in JSON.
+    pub args: String,
+    pub proposer: AccountId,
+    pub proposed_at: U64,
+    /// The proposal can be executed by anyone after this timestamp.
+    pub executable_at: U64,
+    pub status: GovernanceProposalStatus,
+}
+
+/// The role of accounts for calling permissioned functions of this contract.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AnchorRole {
+    /// Can do everything, including granting and revoking roles.
+    Owner,
+    /// Can do the daily operations, including resuming paused actions.
+    Operator,
+    /// Can only pause actions.
+    Pauser,
+    Relayer,
+    PriceMaintainer,
+    /// Can change protocol settings and appchain settings.
+    SettingsManager,
+    /// Can cancel pending governance proposals.
+    Guardian,
+    /// Can attest appchain messages in witness mode of beefy light client.
+    Witness,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RoleChange {
+    Granted,
+    Revoked,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleAuditRecord {
+    pub role: AnchorRole,
+    pub account_id: AccountId,
+    pub change: RoleChange,
+    /// The account which granted or revoked the role.
+    pub operator: AccountId,
+    pub timestamp: U64,
+    pub index: U64,
+}
+
+/// The attestation of a witness on the appchain messages starting from a certain nonce.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WitnessAttestation {
+    pub witness: AccountId,
+    pub start_nonce: u32,
+    /// The sha256 hash of the encoded appchain messages.
+    pub messages_hash: Base58CryptoHash,
+    pub submitted_at: U64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WitnessSubmissionStats {
+    pub witness: AccountId,
+    /// The count of attestations submitted by the witness.
+    pub submission_count: U64,
+    /// The count of attestations which match the staged appchain messages.
+    pub agreed_count: U64,
+    /// The count of attestations which disagree with the staged appchain messages.
+    pub disagreed_count: U64,
+    pub last_submitted_at: Option<U64>,
+}
+
+/// The accounting of the work done by a relayer (caller of permissionless actions).
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RelayerStats {
+    pub relayer: AccountId,
+    pub staged_message_count: U64,
+    pub applied_message_count: U64,
+    pub completed_light_client_update_count: U64,
+    /// The workload which is not rewarded yet.
+    pub workload_of_current_era: U64,
+    pub total_rewards: U128,
+    pub unwithdrawn_rewards: U128,
+}
+
+/// The asset whose price (in USD) is maintained in this contract.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PricedAsset {
+    OctToken,
+    WrappedAppchainToken,
+    NearFungibleToken {
+        symbol: String,
+    },
+    NativeNearToken,
+    /// The floor price of a single NFT of a class.
+    WrappedAppchainNFT {
+        class_id: String,
+    },
+}
+
+/// The price of an asset submitted by a price reporter.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceSubmission {
+    pub reporter: AccountId,
+    pub price: U128,
+    pub submitted_at: U64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AssetPriceRecord {
+    /// The timestamp of the last update of the price.
+    pub updated_at: U64,
+    /// The valid submissions of price reporters, one for each reporter.
+    pub submissions: Vec<PriceSubmission>,
+}
+
+impl Default for AssetPriceRecord {
+    fn default() -> Self {
+        Self {
+            updated_at: U64::from(0),
+            submissions: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AssetPriceStatus {
+    pub asset: PricedAsset,
+    /// The current price, which is the median of all valid submissions.
+    pub price: U128,
+    pub updated_at: Option<U64>,
+    /// Whether the price is not updated within the staleness timeout.
+    pub is_stale: bool,
+    pub submissions: Vec<PriceSubmission>,
+}
+
+/// The market values (in USD) of bridged assets and the staked OCT token.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CollateralizationStatus {
+    pub market_value_of_staked_oct_token: U128,
+    pub market_value_of_near_fungible_tokens: U128,
+    pub market_value_of_native_near_token: U128,
+    /// Valued by the floor price of each class.
+    pub market_value_of_locked_wrapped_appchain_nfts: U128,
+    pub market_value_of_wrapped_appchain_token: U128,
+    /// The maximum market value of all assets locked in this contract.
+    pub maximum_market_value_of_locked_assets: U128,
+    pub maximum_market_value_of_wrapped_appchain_token: U128,
+    /// The percentage of the market value of staked OCT token to
+    /// the total market value of bridged assets.
+    pub collateralization_ratio_percent: Option<U128>,
+}
+
+/// The asset which can be bridged from NEAR protocol to appchain.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum BridgingAsset {
+    NearFungibleToken { contract_account: AccountId },
+    NativeNearToken,
+    WrappedAppchainToken,
+    WrappedAppchainNFT { class_id: String },
+}
+
+/// The fee for bridging an asset from NEAR protocol to appchain.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum BridgeFee {
+    /// A flat amount of the bridging asset.
+    /// For wrapped appchain NFT, it is the amount of appchain native token
+    /// which is charged in appchain.
+    Flat(U128),
+    /// A certain basis points of the bridging amount.
+    BasisPoints(u16),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum BridgingDirection {
+    NearToAppchain,
+    AppchainToNear,
+}
+
+/// The maximum amounts of an asset which can be bridged in rolling windows.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BridgeRateLimit {
+    pub maximum_amount_per_hour: Option<U128>,
+    pub maximum_amount_per_day: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BridgeFlowStatus {
+    pub asset: BridgingAsset,
+    pub direction: BridgingDirection,
+    pub rate_limit: Option<BridgeRateLimit>,
+    pub amount_in_last_hour: U128,
+    pub amount_in_last_day: U128,
+    pub is_paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccruedBridgeFee {
+    pub asset: BridgingAsset,
+    pub fee: Option<BridgeFee>,
+    pub accrued_amount: U128,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct AppchainNotificationHistory {
@@ -500,6 +912,73 @@ pub enum AppchainMessageProcessingResult {
     Error { nonce: u32, message: String },
 }
 
+/// The fund for registering storage of receivers in token contracts.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageFund {
+    /// The balance of native NEAR token available for storage deposits.
+    pub balance: U128,
+    /// The total amount of native NEAR token spent on storage deposits.
+    pub spent_amount: U128,
+}
+
+impl Default for StorageFund {
+    fn default() -> Self {
+        Self {
+            balance: U128::from(0),
+            spent_amount: U128::from(0),
+        }
+    }
+}
+
+/// The asset transferred from appchain, whose delivery to the receiver failed.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum EscrowedAsset {
+    /// A certain amount of NEAR fungible token held by appchain anchor.
+    NearFungibleToken {
+        contract_account: AccountId,
+        amount: U128,
+    },
+    /// A wrapped appchain NFT locked in appchain anchor.
+    LockedWrappedAppchainNFT {
+        class_id: String,
+        instance_id: String,
+    },
+    /// A wrapped appchain NFT which is failed to be minted.
+    UnmintedWrappedAppchainNFT {
+        class_id: String,
+        instance_id: String,
+        token_metadata: TokenMetadata,
+    },
+}
+
+/// The operation of a failed appchain message which can be retried.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RetryableOperation {
+    /// Apply the whole appchain message again,
+    /// as it failed before any asset transfer happened.
+    ApplyAppchainMessage,
+    /// Mint wrapped appchain token again, as the minting failed in the token contract.
+    MintWrappedAppchainToken {
+        sender_id_in_appchain: String,
+        receiver_id_in_near: AccountId,
+        amount: U128,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RetryableAppchainMessage {
+    pub nonce: u32,
+    pub operations: Vec<RetryableOperation>,
+    /// The timestamp when the message failed for the first time.
+    pub failed_at: U64,
+    /// The count of retried attempts.
+    pub retry_count: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub enum MultiTxsOperationProcessingResult {
@@ -605,6 +1084,32 @@ pub enum NFTTransferMessage {
     BridgeToAppchain { receiver_id_in_appchain: String },
 }
 
+/// The processing status of appchain challenge.
+/// The actual processing order is:
+/// `Pending` -> `Accepted` -> `Penalized`, or `Pending` -> `Rejected`,
+/// or `Pending` -> `Confirmed` (for conspiracy MMR challenge)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainChallengeStatus {
+    /// The challenge is committed and is waiting for verdict.
+    Pending,
+    /// The challenge is accepted and the offender is waiting for penalty.
+    Accepted {
+        offender_id: AccountId,
+        penalizing_delegator_index: U64,
+        slashed_amount: U128,
+    },
+    /// The challenge is rejected for a certain reason.
+    Rejected { reason: String },
+    /// The offender of the challenge has been penalized.
+    Penalized {
+        offender_id: AccountId,
+        slashed_amount: U128,
+    },
+    /// The conspiracy MMR challenge is confirmed and the asset transfer is paused.
+    Confirmed,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct WrappedAppchainNFT {
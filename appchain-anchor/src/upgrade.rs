@@ -41,12 +41,14 @@ pub fn update_self() {
     let current_id = env::current_account_id();
     let input = env::storage_read(&StorageKey::AnchorContractWasm.into_bytes())
         .expect("Wasm file for deployment is not staged yet.");
+    // The input of this function is passed to `migrate_state` as its arguments.
+    let migration_args = env::input().unwrap_or_default();
     let promise_id = env::promise_batch_create(&current_id);
     env::promise_batch_action_deploy_contract(promise_id, &input);
     env::promise_batch_action_function_call(
         promise_id,
         "migrate_state",
-        &[],
+        &migration_args,
         0,
         env::prepaid_gas() - env::used_gas() - GAS_FOR_UPGRADE_SELF_DEPLOY,
     );
@@ -2,12 +2,14 @@ use crate::{
     interfaces::AppchainLifecycleManager,
     permissionless_actions::AppchainMessagesProcessingContext, *,
 };
+use assets::native_near_token::CONTRACT_ACCOUNT_FOR_NATIVE_NEAR_TOKEN;
+use user_actions::UnbondedStakeReference;
 
 #[near_bindgen]
 impl AppchainLifecycleManager for AppchainAnchor {
     //
     fn generate_initial_validator_set(&mut self) {
-        self.assert_owner();
+        self.assert_operator();
         assert_eq!(
             self.appchain_state,
             AppchainState::Booting,
@@ -48,7 +50,7 @@ impl AppchainLifecycleManager for AppchainAnchor {
     }
     //
     fn go_live(&mut self) {
-        self.assert_owner();
+        self.assert_operator();
         assert_eq!(
             self.appchain_state,
             AppchainState::Booting,
@@ -93,4 +95,175 @@ impl AppchainLifecycleManager for AppchainAnchor {
         self.beefy_light_client_state
             .set(&beefy_light_client::new(initial_public_keys));
     }
+    //
+    fn start_closing(&mut self) {
+        self.assert_owner_or_registry();
+        assert_eq!(
+            self.appchain_state,
+            AppchainState::Active,
+            "Appchain state must be 'active'."
+        );
+        //
+        let mut near_fungible_tokens = self.near_fungible_tokens.get().unwrap();
+        for mut near_fungible_token in near_fungible_tokens.to_vec() {
+            near_fungible_token.bridging_state = BridgingState::Closed;
+            near_fungible_tokens.insert(&near_fungible_token);
+        }
+        let mut wrapped_appchain_nfts = self.wrapped_appchain_nfts.get().unwrap();
+        for wrapped_appchain_nft in wrapped_appchain_nfts.to_vec() {
+            let mut internal_wrapped_appchain_nft = wrapped_appchain_nfts
+                .get(&wrapped_appchain_nft.class_id)
+                .unwrap();
+            internal_wrapped_appchain_nft.bridging_state = BridgingState::Closed;
+            wrapped_appchain_nfts.insert(
+                &wrapped_appchain_nft.class_id,
+                &internal_wrapped_appchain_nft,
+            );
+        }
+        let mut native_near_token = self.native_near_token.get().unwrap();
+        native_near_token.bridging_state = BridgingState::Closed;
+        self.native_near_token.set(&native_near_token);
+        //
+        self.appchain_state = AppchainState::Closing;
+        self.sync_state_to_registry();
+    }
+    //
+    fn complete_closing(
+        &mut self,
+        skip_reward_of_last_era: Option<bool>,
+    ) -> MultiTxsOperationProcessingResult {
+        self.assert_owner_or_registry();
+        assert_eq!(
+            self.appchain_state,
+            AppchainState::Closing,
+            "Appchain state must be 'closing'."
+        );
+        let processing_status = self.permissionless_actions_status.get().unwrap();
+        assert!(
+            processing_status.switching_era_number.is_none()
+                && processing_status.distributing_reward_era_number.is_none(),
+            "Switching era or distributing reward is in progress. Please try again later."
+        );
+        // The outstanding reward of the last era should be settled before
+        // the stakes are unbonded, unless it is skipped explicitly.
+        if self.closing_unbonding_timestamp.is_none() {
+            let validator_set_histories = self.validator_set_histories.get().unwrap();
+            let last_era_number = validator_set_histories.index_range().end_index.0;
+            if let Some(validator_set) = validator_set_histories.get(&last_era_number) {
+                if !matches!(
+                    validator_set.processing_status(),
+                    ValidatorSetProcessingStatus::Completed
+                ) {
+                    assert!(
+                        skip_reward_of_last_era.unwrap_or(false),
+                        "The reward of era '{}' is not distributed yet. Please try again later.",
+                        last_era_number
+                    );
+                    log!(
+                        "The reward of era '{}' is skipped for closing the appchain.",
+                        last_era_number
+                    );
+                }
+            }
+            self.closing_unbonding_timestamp = Some(env::block_timestamp());
+        }
+        while self.next_validator_set.get().unwrap().validator_count() > 0 {
+            if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
+                return MultiTxsOperationProcessingResult::NeedMoreGas;
+            }
+            self.auto_unbond_first_staker_in_next_validator_set();
+        }
+        let mut next_validator_set = self.next_validator_set.get().unwrap();
+        next_validator_set.clear_unbonding_validator_ids();
+        next_validator_set.clear_auto_unbonding_validator_ids();
+        self.next_validator_set.set(&next_validator_set);
+        //
+        let mut locked_assets = Vec::<String>::new();
+        for near_fungible_token in self.near_fungible_tokens.get().unwrap().to_vec() {
+            if near_fungible_token.locked_balance.0 > 0 {
+                locked_assets.push(near_fungible_token.metadata.symbol);
+            }
+        }
+        for wrapped_appchain_nft in self.wrapped_appchain_nfts.get().unwrap().to_vec() {
+            if wrapped_appchain_nft.count_of_locked_tokens.0 > 0 {
+                locked_assets.push(wrapped_appchain_nft.class_id);
+            }
+        }
+        if self.native_near_token.get().unwrap().locked_balance.0 > 0 {
+            locked_assets.push(CONTRACT_ACCOUNT_FOR_NATIVE_NEAR_TOKEN.to_string());
+        }
+        if locked_assets.len() > 0 {
+            self.sync_state_to_registry();
+            return MultiTxsOperationProcessingResult::Error(format!(
+                "All validators and delegators are unbonded, but there are still locked assets: {:?}.",
+                locked_assets
+            ));
+        }
+        //
+        self.appchain_state = AppchainState::Closed;
+        self.sync_state_to_registry();
+        MultiTxsOperationProcessingResult::Ok
+    }
+}
+
+impl AppchainAnchor {
+    /// Get the start time of the unlock period of an unbonded stake.
+    pub fn get_unlock_start_timestamp_of(&self, reference: &UnbondedStakeReference) -> Timestamp {
+        match self
+            .validator_set_histories
+            .get()
+            .unwrap()
+            .get(&reference.era_number)
+        {
+            Some(validator_set) => validator_set.start_timestamp(),
+            None => self
+                .closing_unbonding_timestamp
+                .expect("Missing the unbonding time of closing appchain."),
+        }
+    }
+    /// Auto unbond the first delegator of the first validator in next validator set,
+    /// or the validator itself if it has no delegator.
+    fn auto_unbond_first_staker_in_next_validator_set(&mut self) {
+        let mut next_validator_set = self.next_validator_set.get().unwrap();
+        let validator = next_validator_set.get_validator_by_index(&0).unwrap();
+        let staking_fact =
+            match next_validator_set.get_delegator_by_index(&0, &validator.validator_id) {
+                Some(delegator) => StakingFact::DelegatorAutoUnbonded {
+                    delegator_id: delegator.delegator_id,
+                    validator_id: delegator.validator_id,
+                    amount: U128::from(delegator.deposit_amount),
+                },
+                None => StakingFact::ValidatorAutoUnbonded {
+                    validator_id: validator.validator_id,
+                    amount: U128::from(validator.deposit_amount),
+                },
+            };
+        let staking_history = self.record_staking_fact(staking_fact);
+        next_validator_set.apply_staking_fact(&staking_history.staking_fact);
+        self.next_validator_set.set(&next_validator_set);
+        // The unbonded stake is attached to the era after the latest era, which
+        // will never start as there is no more era switching after the appchain
+        // starts closing. The unlock period of the stake starts at the time of
+        // `closing_unbonding_timestamp`.
+        let unbonded_stake_reference = UnbondedStakeReference {
+            era_number: self
+                .validator_set_histories
+                .get()
+                .unwrap()
+                .index_range()
+                .end_index
+                .0
+                + 1,
+            staking_history_index: staking_history.index.0,
+        };
+        match &staking_history.staking_fact {
+            StakingFact::DelegatorAutoUnbonded { delegator_id, .. } => {
+                self.add_unbonded_stake_of(delegator_id, unbonded_stake_reference)
+            }
+            StakingFact::ValidatorAutoUnbonded { validator_id, .. } => {
+                self.add_unbonded_stake_of(validator_id, unbonded_stake_reference)
+            }
+            _ => (),
+        }
+    }
 }
@@ -0,0 +1,214 @@
+use crate::{interfaces::GovernanceProposalManager, *};
+
+/// The functions of `ProtocolSettingsManager` which must be called through governance proposals.
+const TIMELOCKED_PROTOCOL_SETTINGS_ACTIONS: [&str; 29] = [
+    "change_minimum_validator_deposit_changing_amount",
+    "change_maximum_validator_stake_percent",
+    "change_minimum_delegator_deposit",
+    "change_minimum_delegator_deposit_changing_amount",
+    "change_minimum_total_stake_price_for_booting",
+    "change_maximum_market_value_percent_of_near_fungible_tokens",
+    "change_maximum_market_value_percent_of_wrapped_appchain_token",
+    "change_minimum_validator_count",
+    "change_maximum_validator_count",
+    "change_maximum_validators_per_delegator",
+    "change_unlock_period_of_validator_deposit",
+    "change_unlock_period_of_delegator_deposit",
+    "change_maximum_era_count_of_unwithdrawn_reward",
+    "change_maximum_era_count_of_valid_appchain_message",
+    "change_validator_commission_percent",
+    "change_maximum_validator_commission_percent",
+    "change_maximum_validator_commission_percent_change_per_era",
+    "change_maximum_allowed_unprofitable_era_count",
+    "change_minimum_jailed_era_count_before_unjail",
+    "change_retry_period_of_failed_appchain_message",
+    "change_maximum_price_deviation_percent_per_update",
+    "change_staleness_timeout_of_token_price",
+    "change_minimum_price_submission_count",
+    "change_governance_timelock_delay",
+    "change_witness_attestation_threshold",
+    "change_maximum_jailed_era_count",
+    "change_subaccount_for_council_keeper_contract",
+    "change_slash_percent_per_offence_of_kind",
+    "remove_slash_percent_per_offence_of_kind",
+];
+/// The functions of `AppchainSettingsManager` which must be called through governance proposals.
+const TIMELOCKED_APPCHAIN_SETTINGS_ACTIONS: [&str; 1] = ["set_era_reward"];
+/// The functions of `AnchorSettingsManager` which must be called through governance proposals.
+const TIMELOCKED_ANCHOR_SETTINGS_ACTIONS: [&str; 3] = [
+    "set_relayer_account",
+    "turn_on_beefy_light_client_witness_mode",
+    "set_treasury_account",
+];
+/// The functions of `SudoActions` which must be called through governance proposals.
+/// The pause actions and `regenerate_user_staking_histories` are exempt.
+const TIMELOCKED_SUDO_ACTIONS: [&str; 7] = [
+    "set_owner_pk",
+    "set_metadata_of_wrapped_appchain_token",
+    "set_premined_balance_of_wrapped_appchain_token",
+    "reset_beefy_light_client",
+    "change_account_id_in_appchain_of_validator",
+    "set_latest_applied_appchain_message_nonce",
+    "unlock_auto_unbonded_stake_of",
+];
+
+pub trait GovernanceProposalResolver {
+    /// Resolver for executing a governance proposal
+    fn resolve_governance_proposal_execution(&mut self, index: U64);
+}
+
+impl IndexedAndClearable for GovernanceProposal {
+    //
+    fn set_index(&mut self, index: &u64) {
+        self.index = U64::from(*index);
+    }
+    //
+    fn clear_extra_storage(&mut self) -> MultiTxsOperationProcessingResult {
+        MultiTxsOperationProcessingResult::Ok
+    }
+}
+
+impl AppchainAnchor {
+    // Get the roles which can propose a call of the given function.
+    fn get_proposer_roles_of(method_name: &str) -> Vec<AnchorRole> {
+        if TIMELOCKED_PROTOCOL_SETTINGS_ACTIONS.contains(&method_name)
+            || TIMELOCKED_APPCHAIN_SETTINGS_ACTIONS.contains(&method_name)
+        {
+            vec![AnchorRole::Owner, AnchorRole::SettingsManager]
+        } else if TIMELOCKED_ANCHOR_SETTINGS_ACTIONS.contains(&method_name)
+            || TIMELOCKED_SUDO_ACTIONS.contains(&method_name)
+        {
+            vec![AnchorRole::Owner]
+        } else {
+            panic!("Function '{}' is not a timelocked function.", method_name)
+        }
+    }
+    /// Assert that the function is called by executing a governance proposal.
+    ///
+    /// If the governance timelock delay is `0`, the function can also be called directly
+    /// by account with the given roles.
+    pub fn assert_timelocked_call(&self, roles: &[AnchorRole]) {
+        if env::predecessor_account_id().eq(&env::current_account_id()) {
+            return;
+        }
+        assert!(
+            self.protocol_settings
+                .get()
+                .unwrap()
+                .governance_timelock_delay
+                .0
+                == 0,
+            "This function can only be called through a governance proposal."
+        );
+        self.assert_any_role(roles);
+    }
+    /// Create a governance proposal of calling a timelocked function of this contract.
+    pub fn internal_propose_governance_action(
+        &mut self,
+        method_name: String,
+        args: String,
+    ) -> GovernanceProposal {
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        let mut governance_proposals = self.governance_proposals.get().unwrap();
+        let proposal = governance_proposals.append(&mut GovernanceProposal {
+            index: U64::from(0),
+            method_name,
+            args,
+            proposer: env::predecessor_account_id(),
+            proposed_at: U64::from(env::block_timestamp()),
+            executable_at: U64::from(
+                env::block_timestamp()
+                    + protocol_settings.governance_timelock_delay.0 * NANO_SECONDS_MULTIPLE,
+            ),
+            status: GovernanceProposalStatus::Pending,
+        });
+        self.governance_proposals.set(&governance_proposals);
+        log!(
+            "Governance proposal '{}' is created: '{}'",
+            proposal.index.0,
+            serde_json::to_string(&proposal).unwrap()
+        );
+        proposal
+    }
+    //
+    fn set_governance_proposal_status(&mut self, index: u64, status: GovernanceProposalStatus) {
+        let mut governance_proposals = self.governance_proposals.get().unwrap();
+        let mut proposal = governance_proposals.get(&index).unwrap();
+        proposal.status = status;
+        governance_proposals.insert(&index, &proposal);
+    }
+}
+
+#[near_bindgen]
+impl GovernanceProposalManager for AppchainAnchor {
+    //
+    fn propose_governance_action(&mut self, method_name: String, args: String) -> U64 {
+        self.assert_any_role(&AppchainAnchor::get_proposer_roles_of(&method_name));
+        assert!(
+            serde_json::from_str::<serde_json::Value>(&args).is_ok(),
+            "The args should be a valid JSON string."
+        );
+        self.internal_propose_governance_action(method_name, args)
+            .index
+    }
+    //
+    fn cancel_governance_proposal(&mut self, index: U64) {
+        self.assert_any_role(&[AnchorRole::Owner, AnchorRole::Guardian]);
+        let governance_proposals = self.governance_proposals.get().unwrap();
+        let proposal = governance_proposals
+            .get(&index.0)
+            .expect("Invalid index of governance proposal.");
+        assert!(
+            proposal.status.eq(&GovernanceProposalStatus::Pending),
+            "Only pending governance proposal can be cancelled."
+        );
+        self.set_governance_proposal_status(index.0, GovernanceProposalStatus::Cancelled);
+    }
+    //
+    fn execute_governance_proposal(&mut self, index: U64) {
+        let governance_proposals = self.governance_proposals.get().unwrap();
+        let proposal = governance_proposals
+            .get(&index.0)
+            .expect("Invalid index of governance proposal.");
+        assert!(
+            proposal.status.eq(&GovernanceProposalStatus::Pending),
+            "Only pending governance proposal can be executed."
+        );
+        assert!(
+            env::block_timestamp() >= proposal.executable_at.0,
+            "The governance proposal is still in timelock."
+        );
+        self.set_governance_proposal_status(index.0, GovernanceProposalStatus::Executing);
+        Promise::new(env::current_account_id())
+            .function_call(
+                proposal.method_name,
+                proposal.args.into_bytes(),
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_GOVERNANCE_ACTION),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_governance_proposal_execution(index),
+            );
+    }
+}
+
+#[near_bindgen]
+impl GovernanceProposalResolver for AppchainAnchor {
+    //
+    fn resolve_governance_proposal_execution(&mut self, index: U64) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                self.set_governance_proposal_status(index.0, GovernanceProposalStatus::Executed);
+            }
+            PromiseResult::Failed => {
+                self.set_governance_proposal_status(index.0, GovernanceProposalStatus::Failed);
+            }
+        }
+    }
+}
@@ -1,13 +1,20 @@
 use crate::*;
 
 mod appchain_lifecycle;
+mod governance_proposals;
+mod onboarding_grants;
 mod owner_actions;
+mod ownership_transfer;
+mod relayer_rewards;
+mod role_manager;
 mod settings_manager;
 mod staking;
 mod sudo_actions;
 mod validator_actions;
+mod witness_attestations;
 
-#[derive(BorshDeserialize, BorshSerialize, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
 pub struct UnbondedStakeReference {
     /// The number of era in appchain.
     pub era_number: u64,
@@ -0,0 +1,262 @@
+use crate::{interfaces::OnboardingGrantManager, *};
+use std::str::FromStr;
+
+const SUB_ACCOUNT_ID_OF_WAT_FAUCET: &str = "wat-faucet";
+
+pub trait OnboardingGrantResolver {
+    /// Resolver for granting wrapped appchain token to a new validator
+    fn resolve_onboarding_grant(&mut self, index: U64);
+    /// Resolver for querying the balance of wrapped appchain token faucet
+    fn resolve_wat_faucet_balance_query(&mut self);
+}
+
+impl IndexedAndClearable for OnboardingGrant {
+    //
+    fn set_index(&mut self, index: &u64) {
+        self.index = U64::from(*index);
+    }
+    //
+    fn clear_extra_storage(&mut self) -> MultiTxsOperationProcessingResult {
+        MultiTxsOperationProcessingResult::Ok
+    }
+}
+
+impl AppchainAnchor {
+    ///
+    pub fn get_wat_faucet_account(&self) -> AccountId {
+        AccountId::from_str(
+            format!(
+                "{}.{}",
+                SUB_ACCOUNT_ID_OF_WAT_FAUCET,
+                env::current_account_id()
+            )
+            .as_str(),
+        )
+        .unwrap()
+    }
+    /// Grant the bonus for new validator to the account of the validator in appchain,
+    /// through the wrapped appchain token faucet.
+    ///
+    /// Each account in appchain can only be granted once.
+    pub fn internal_make_onboarding_grant(
+        &mut self,
+        validator_id: &AccountId,
+        receiver_id_in_appchain: &String,
+    ) {
+        let amount = self
+            .appchain_settings
+            .get()
+            .unwrap()
+            .bonus_for_new_validator;
+        if amount.0 == 0 {
+            return;
+        }
+        if self
+            .onboarding_grant_indexes
+            .contains_key(receiver_id_in_appchain)
+        {
+            log!(
+                "Account '{}' in appchain has already been granted. Skip the onboarding grant.",
+                receiver_id_in_appchain
+            );
+            return;
+        }
+        let budget_is_enough = self.internal_take_onboarding_grant_budget(amount.0);
+        //
+        let mut onboarding_grants = self.onboarding_grants.get().unwrap();
+        let grant = onboarding_grants.append(&mut OnboardingGrant {
+            index: U64::from(0),
+            validator_id: validator_id.clone(),
+            receiver_id_in_appchain: receiver_id_in_appchain.clone(),
+            amount,
+            status: match budget_is_enough {
+                true => OnboardingGrantStatus::Pending,
+                false => OnboardingGrantStatus::WaitingForBudget,
+            },
+            timestamp: U64::from(env::block_timestamp()),
+        });
+        self.onboarding_grants.set(&onboarding_grants);
+        self.onboarding_grant_indexes
+            .insert(receiver_id_in_appchain, &grant.index.0);
+        //
+        if budget_is_enough {
+            self.send_onboarding_grant(&grant);
+        } else {
+            log!(
+                "The budget of onboarding grants is not enough. Onboarding grant '{}' can be claimed after the budget is topped up.",
+                grant.index.0
+            );
+        }
+    }
+    // Deduct the amount of a grant from the remaining budget.
+    // Returns false and keeps the budget unchanged if the budget is not enough.
+    fn internal_take_onboarding_grant_budget(&mut self, amount: Balance) -> bool {
+        let mut status = self.onboarding_grants_status.get().unwrap();
+        if status.remaining_budget.0 < amount {
+            log!(
+                "The budget of onboarding grants is not enough. Remaining budget: '{}', Amount: '{}'.",
+                status.remaining_budget.0,
+                amount
+            );
+            return false;
+        }
+        status.remaining_budget = U128::from(status.remaining_budget.0 - amount);
+        self.onboarding_grants_status.set(&status);
+        true
+    }
+    //
+    fn send_onboarding_grant(&self, grant: &OnboardingGrant) {
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Input {
+            receiver_id: String,
+            amount: U128,
+        }
+        let args = Input {
+            receiver_id: grant.receiver_id_in_appchain.clone(),
+            amount: grant.amount,
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Promise::new(self.get_wat_faucet_account())
+            .function_call(
+                "burn_wrapped_appchain_token".to_string(),
+                args,
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_BURN_WRAPPED_APPCHAIN_TOKEN),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_onboarding_grant(grant.index),
+            );
+    }
+    //
+    fn set_onboarding_grant_status(&mut self, index: u64, status: OnboardingGrantStatus) {
+        let mut onboarding_grants = self.onboarding_grants.get().unwrap();
+        let mut grant = onboarding_grants.get(&index).unwrap();
+        grant.status = status;
+        grant.timestamp = U64::from(env::block_timestamp());
+        onboarding_grants.insert(&index, &grant);
+        self.onboarding_grants.set(&onboarding_grants);
+    }
+}
+
+#[near_bindgen]
+impl OnboardingGrantManager for AppchainAnchor {
+    //
+    fn set_onboarding_grant_budget(&mut self, budget: U128) {
+        self.assert_operator();
+        let mut status = self.onboarding_grants_status.get().unwrap();
+        status.remaining_budget = budget;
+        self.onboarding_grants_status.set(&status);
+    }
+    //
+    fn claim_onboarding_grant(&mut self, index: U64) {
+        self.assert_asset_transfer_is_not_paused();
+        let grant = match self.onboarding_grants.get().unwrap().get(&index.0) {
+            Some(grant) => grant,
+            None => panic!("Onboarding grant '{}' does not exist.", index.0),
+        };
+        match grant.status {
+            OnboardingGrantStatus::Failed => (),
+            OnboardingGrantStatus::WaitingForBudget => assert!(
+                self.internal_take_onboarding_grant_budget(grant.amount.0),
+                "The budget of onboarding grants is not enough."
+            ),
+            _ => panic!("Only failed or unfunded onboarding grant can be claimed."),
+        }
+        self.set_onboarding_grant_status(index.0, OnboardingGrantStatus::Pending);
+        self.send_onboarding_grant(&grant);
+    }
+    //
+    fn sync_wat_faucet_balance(&mut self) {
+        self.assert_contract_account_of_wrapped_appchain_token_is_set();
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Input {
+            account_id: AccountId,
+        }
+        let args = Input {
+            account_id: self.get_wat_faucet_account(),
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Promise::new(
+            self.wrapped_appchain_token
+                .get()
+                .unwrap()
+                .contract_account
+                .unwrap(),
+        )
+        .function_call(
+            "ft_balance_of".to_string(),
+            args,
+            0,
+            Gas::ONE_TERA.mul(T_GAS_FOR_FT_BALANCE_OF),
+        )
+        .then(
+            ext_self::ext(env::current_account_id())
+                .with_attached_deposit(0)
+                .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                .with_unused_gas_weight(0)
+                .resolve_wat_faucet_balance_query(),
+        );
+    }
+}
+
+#[near_bindgen]
+impl OnboardingGrantResolver for AppchainAnchor {
+    //
+    fn resolve_onboarding_grant(&mut self, index: U64) {
+        assert_self();
+        let granted = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(value) => {
+                near_sdk::serde_json::from_slice::<bool>(&value).unwrap_or(false)
+            }
+            PromiseResult::Failed => false,
+        };
+        let grant = self.onboarding_grants.get().unwrap().get(&index.0).unwrap();
+        if granted {
+            self.set_onboarding_grant_status(index.0, OnboardingGrantStatus::Granted);
+            let mut status = self.onboarding_grants_status.get().unwrap();
+            status.granted_amount = U128::from(status.granted_amount.0 + grant.amount.0);
+            self.onboarding_grants_status.set(&status);
+            log!(
+                "Onboarding grant '{}' is sent to '{}' in appchain. Amount: '{}'.",
+                index.0,
+                grant.receiver_id_in_appchain,
+                grant.amount.0
+            );
+        } else {
+            self.set_onboarding_grant_status(index.0, OnboardingGrantStatus::Failed);
+            log!(
+                "Failed to send onboarding grant '{}' to '{}' in appchain. Amount: '{}'. It can be claimed later.",
+                index.0,
+                grant.receiver_id_in_appchain,
+                grant.amount.0
+            );
+        }
+    }
+    //
+    fn resolve_wat_faucet_balance_query(&mut self) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(value) => {
+                if let Ok(balance) = near_sdk::serde_json::from_slice::<U128>(&value) {
+                    let mut status = self.onboarding_grants_status.get().unwrap();
+                    status.wat_faucet_balance = Some(balance);
+                    status.wat_faucet_balance_synced_at = Some(U64::from(env::block_timestamp()));
+                    self.onboarding_grants_status.set(&status);
+                }
+            }
+            PromiseResult::Failed => {
+                log!("Failed to query the balance of wrapped appchain token faucet.");
+            }
+        }
+    }
+}
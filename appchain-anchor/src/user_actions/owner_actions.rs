@@ -4,25 +4,61 @@ use crate::{interfaces::OwnerActions, *};
 impl OwnerActions for AppchainAnchor {
     //
     fn remove_validator_set_before(&mut self, era_number: U64) {
-        self.assert_owner();
+        self.assert_operator();
         let mut validator_set_histories = self.validator_set_histories.get().unwrap();
         validator_set_histories.remove_before(&era_number.0);
         self.validator_set_histories.set(&validator_set_histories);
     }
     //
     fn remove_staking_history_before(&mut self, index: U64) {
-        self.assert_owner();
+        self.assert_operator();
         let mut staking_histories = self.staking_histories.get().unwrap();
         staking_histories.remove_before(&index.0);
         self.staking_histories.set(&staking_histories);
     }
     //
     fn remove_appchain_notification_history_before(&mut self, index: U64) {
-        self.assert_owner();
+        self.assert_operator();
         let mut appchain_notification_histories =
             self.appchain_notification_histories.get().unwrap();
         appchain_notification_histories.remove_before(&index.0);
         self.appchain_notification_histories
             .set(&appchain_notification_histories);
     }
+    //
+    fn transfer_pending_slashed_oct_token_to_treasury(&mut self) {
+        self.assert_operator();
+        assert!(
+            self.pending_slashed_oct_token > 0,
+            "There is no pending slashed OCT token."
+        );
+        assert!(
+            self.anchor_settings
+                .get()
+                .unwrap()
+                .treasury_account
+                .is_some(),
+            "Treasury account is not set."
+        );
+        self.transfer_slashed_oct_token_to_treasury(0);
+    }
+    //
+    fn set_era_number_of_grandpa_set_id(&mut self, set_id: u32, era_number: U64) {
+        self.assert_operator();
+        assert!(
+            !self.era_numbers_of_grandpa_set_ids.contains_key(&set_id),
+            "The era number of GRANDPA set id '{}' is already set.",
+            set_id
+        );
+        assert!(
+            self.validator_set_histories
+                .get()
+                .unwrap()
+                .get(&era_number.0)
+                .is_some(),
+            "Validator set of era '{}' does not exist.",
+            era_number.0
+        );
+        self.internal_record_era_number_of_grandpa_set_id(set_id, era_number.0);
+    }
 }
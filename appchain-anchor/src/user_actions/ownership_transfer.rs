@@ -0,0 +1,154 @@
+use crate::{interfaces::OwnershipManager, *};
+
+pub trait FullAccessKeyRotationResolver {
+    /// Resolver for rotating the full access key of a sub-account
+    fn resolve_full_access_key_rotation(&mut self, contract_account: AccountId);
+}
+
+impl AppchainAnchor {
+    /// Replace the owner public key, and rotate the full access key of the sub-accounts
+    /// created by this contract to it.
+    pub fn internal_set_owner_pk(&mut self, owner_pk: PublicKey) {
+        if owner_pk.eq(&self.owner_pk) {
+            return;
+        }
+        let old_owner_pk = self.owner_pk.clone();
+        self.owner_pk = owner_pk;
+        self.rotate_full_access_key_of_sub_accounts(&old_owner_pk, &self.owner_pk);
+    }
+    // Replace the old owner public key with the new one in the sub-accounts
+    // created by this contract. Only the native NEAR token vault supports the key
+    // rotation. The wrapped appchain NFT contracts are deployed from the wasm stored by
+    // `store_wasm_of_wrapped_appchain_nft_contract`, which is built outside this repository
+    // and has no function for the anchor to rotate its keys, so they need to be re-keyed
+    // manually with the old key.
+    // The failures are only logged, the owner can re-key the sub-account manually
+    // with the old key.
+    fn rotate_full_access_key_of_sub_accounts(
+        &self,
+        old_public_key: &PublicKey,
+        new_public_key: &PublicKey,
+    ) {
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct Input {
+            old_public_key: PublicKey,
+            new_public_key: PublicKey,
+        }
+        let args = Input {
+            old_public_key: old_public_key.clone(),
+            new_public_key: new_public_key.clone(),
+        };
+        let args = near_sdk::serde_json::to_vec(&args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        let contract_account = self.get_near_vault_account();
+        Promise::new(contract_account.clone())
+            .function_call(
+                "rotate_full_access_key".to_string(),
+                args,
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_ROTATE_FULL_ACCESS_KEY),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_full_access_key_rotation(contract_account),
+            );
+        for wrapped_appchain_nft in self.wrapped_appchain_nfts.get().unwrap().to_vec() {
+            log!(
+                "Full access key of '{}' needs to be re-keyed manually.",
+                wrapped_appchain_nft.contract_account
+            );
+        }
+    }
+    // Revoke the explicit members of role `Owner`, so that they won't keep the power
+    // of the previous owner after the ownership is transferred.
+    fn revoke_explicit_owner_role_members(&mut self) {
+        let members = self
+            .role_members
+            .get(&AnchorRole::Owner)
+            .unwrap_or_default();
+        for member in members {
+            self.internal_revoke_role(AnchorRole::Owner, member);
+        }
+    }
+}
+
+#[near_bindgen]
+impl OwnershipManager for AppchainAnchor {
+    //
+    fn propose_owner(&mut self, owner: AccountId) {
+        self.assert_owner();
+        assert!(!owner.eq(&self.owner), "Owner is not changed.");
+        self.pending_owner = Some(owner);
+    }
+    //
+    fn cancel_owner_proposal(&mut self) {
+        self.assert_owner();
+        assert!(
+            self.pending_owner.is_some(),
+            "There is no pending owner to cancel."
+        );
+        self.pending_owner = None;
+    }
+    //
+    fn accept_ownership(&mut self, owner_pk: Option<PublicKey>) {
+        assert!(
+            self.pending_owner
+                .as_ref()
+                .map_or(false, |pending_owner| pending_owner
+                    .eq(&env::predecessor_account_id())),
+            "This function can only be called by the pending owner."
+        );
+        self.owner = self.pending_owner.take().unwrap();
+        log!("Ownership is transferred to '{}'.", self.owner);
+        self.revoke_explicit_owner_role_members();
+        if let Some(owner_pk) = owner_pk {
+            if owner_pk.eq(&self.owner_pk) {
+                return;
+            }
+            // The owner public key is changed through a governance proposal, unless
+            // the governance timelock is disabled.
+            if self
+                .protocol_settings
+                .get()
+                .unwrap()
+                .governance_timelock_delay
+                .0
+                == 0
+            {
+                self.internal_set_owner_pk(owner_pk);
+            } else {
+                self.internal_propose_governance_action(
+                    "set_owner_pk".to_string(),
+                    near_sdk::serde_json::json!({ "public_key": owner_pk }).to_string(),
+                );
+            }
+        }
+    }
+}
+
+#[near_bindgen]
+impl FullAccessKeyRotationResolver for AppchainAnchor {
+    //
+    fn resolve_full_access_key_rotation(&mut self, contract_account: AccountId) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Full access key of '{}' is rotated to the new owner public key.",
+                    contract_account
+                );
+            }
+            PromiseResult::Failed => {
+                log!(
+                    "Failed to rotate full access key of '{}'. It needs to be re-keyed manually.",
+                    contract_account
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{interfaces::RelayerRewardsManager, *};
+use near_contract_standards::fungible_token::core::ext_ft_core;
+
+pub trait RelayerRewardsResolver {
+    /// Resolver for withdrawing rewards of a relayer
+    fn resolve_relayer_rewards_withdrawal(&mut self, relayer: AccountId, amount: U128);
+}
+
+impl RelayerStats {
+    ///
+    pub fn new(relayer: AccountId) -> Self {
+        Self {
+            relayer,
+            staged_message_count: U64::from(0),
+            applied_message_count: U64::from(0),
+            completed_light_client_update_count: U64::from(0),
+            workload_of_current_era: U64::from(0),
+            total_rewards: U128::from(0),
+            unwithdrawn_rewards: U128::from(0),
+        }
+    }
+}
+
+impl AppchainAnchor {
+    /// Record the work done by the caller of permissionless actions.
+    ///
+    /// Each staged message, applied message and completed light client update
+    /// counts as one unit of workload in current era. Only the workload of the first
+    /// `MAXIMUM_RELAYER_COUNT_PER_ERA` relayers in an era is recorded.
+    pub fn internal_record_relayer_workload(
+        &mut self,
+        staged_message_count: u64,
+        applied_message_count: u64,
+        completed_light_client_update_count: u64,
+    ) {
+        let workload =
+            staged_message_count + applied_message_count + completed_light_client_update_count;
+        if workload == 0 {
+            return;
+        }
+        let relayer = env::predecessor_account_id();
+        let mut stats = self
+            .relayer_stats
+            .get(&relayer)
+            .unwrap_or(RelayerStats::new(relayer.clone()));
+        stats.staged_message_count = U64::from(stats.staged_message_count.0 + staged_message_count);
+        stats.applied_message_count =
+            U64::from(stats.applied_message_count.0 + applied_message_count);
+        stats.completed_light_client_update_count = U64::from(
+            stats.completed_light_client_update_count.0 + completed_light_client_update_count,
+        );
+        if !self.relayers_of_current_era.contains(&relayer) {
+            if self.relayers_of_current_era.len() >= MAXIMUM_RELAYER_COUNT_PER_ERA {
+                self.relayer_stats.insert(&relayer, &stats);
+                log!(
+                    "Too many relayers in current era. The workload of '{}' is not rewarded.",
+                    relayer
+                );
+                return;
+            }
+            self.relayers_of_current_era.push(relayer.clone());
+        }
+        stats.workload_of_current_era = U64::from(stats.workload_of_current_era.0 + workload);
+        self.relayer_stats.insert(&relayer, &stats);
+    }
+    /// Split the relayer reward of an era to the relayers of current era pro rata by
+    /// their workload, and reset the workload of them.
+    ///
+    /// The rewards are kept as pending with the nonce of the appchain message, and are only
+    /// credited to relayers after they are minted successfully.
+    /// Returns the total amount of rewards which need to be minted.
+    pub fn internal_distribute_relayer_rewards(
+        &mut self,
+        era_number: u64,
+        appchain_message_nonce: u32,
+    ) -> Balance {
+        let reward_pool = self
+            .appchain_settings
+            .get()
+            .unwrap()
+            .relayer_reward_per_era
+            .0;
+        let all_stats: Vec<RelayerStats> = self
+            .relayers_of_current_era
+            .iter()
+            .map(|relayer| self.relayer_stats.get(relayer).unwrap())
+            .collect();
+        self.relayers_of_current_era.clear();
+        let total_workload: u64 = all_stats
+            .iter()
+            .map(|stats| stats.workload_of_current_era.0)
+            .sum();
+        let mut pending_rewards: Vec<(AccountId, U128)> = Vec::new();
+        let mut distributed_amount: Balance = 0;
+        for mut stats in all_stats {
+            if stats.workload_of_current_era.0 == 0 {
+                continue;
+            }
+            if reward_pool > 0 {
+                let reward = reward_pool * u128::from(stats.workload_of_current_era.0)
+                    / u128::from(total_workload);
+                pending_rewards.push((stats.relayer.clone(), U128::from(reward)));
+                distributed_amount += reward;
+            }
+            stats.workload_of_current_era = U64::from(0);
+            self.relayer_stats.insert(&stats.relayer, &stats);
+        }
+        if distributed_amount > 0 {
+            self.pending_relayer_rewards
+                .insert(&appchain_message_nonce, &pending_rewards);
+            log!(
+                "Relayer rewards of era '{}' are distributed. Total amount: '{}'.",
+                era_number,
+                distributed_amount
+            );
+        }
+        distributed_amount
+    }
+    /// Credit the pending relayer rewards to relayers, after they are minted successfully
+    /// along with the era reward.
+    pub fn internal_credit_pending_relayer_rewards(&mut self, appchain_message_nonce: u32) {
+        if let Some(pending_rewards) = self.pending_relayer_rewards.remove(&appchain_message_nonce)
+        {
+            for (relayer, reward) in pending_rewards {
+                let mut stats = self
+                    .relayer_stats
+                    .get(&relayer)
+                    .unwrap_or(RelayerStats::new(relayer.clone()));
+                stats.total_rewards = U128::from(stats.total_rewards.0 + reward.0);
+                stats.unwithdrawn_rewards = U128::from(stats.unwithdrawn_rewards.0 + reward.0);
+                self.relayer_stats.insert(&relayer, &stats);
+            }
+        }
+    }
+}
+
+#[near_bindgen]
+impl RelayerRewardsManager for AppchainAnchor {
+    //
+    fn withdraw_relayer_rewards(&mut self) {
+        self.assert_rewards_withdrawal_is_not_paused();
+        self.assert_contract_account_of_wrapped_appchain_token_is_set();
+        let relayer = env::predecessor_account_id();
+        let mut stats = self
+            .relayer_stats
+            .get(&relayer)
+            .expect("The caller has no relayer rewards.");
+        let amount = stats.unwithdrawn_rewards;
+        assert!(amount.0 > 0, "The caller has no relayer rewards.");
+        stats.unwithdrawn_rewards = U128::from(0);
+        self.relayer_stats.insert(&relayer, &stats);
+        ext_ft_core::ext(
+            self.wrapped_appchain_token
+                .get()
+                .unwrap()
+                .contract_account
+                .unwrap(),
+        )
+        .with_attached_deposit(1)
+        .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+        .with_unused_gas_weight(0)
+        .ft_transfer(relayer.clone(), amount, None)
+        .then(
+            ext_self::ext(env::current_account_id())
+                .with_attached_deposit(0)
+                .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                .with_unused_gas_weight(0)
+                .resolve_relayer_rewards_withdrawal(relayer, amount),
+        );
+    }
+}
+
+#[near_bindgen]
+impl RelayerRewardsResolver for AppchainAnchor {
+    //
+    fn resolve_relayer_rewards_withdrawal(&mut self, relayer: AccountId, amount: U128) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Relayer rewards of '{}' are withdrawn. Amount: '{}'.",
+                    relayer,
+                    amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                let mut stats = self.relayer_stats.get(&relayer).unwrap();
+                stats.unwithdrawn_rewards = U128::from(stats.unwithdrawn_rewards.0 + amount.0);
+                self.relayer_stats.insert(&relayer, &stats);
+                log!(
+                    "Failed to withdraw relayer rewards of '{}'. Amount: '{}'.",
+                    relayer,
+                    amount.0
+                );
+            }
+        }
+    }
+}
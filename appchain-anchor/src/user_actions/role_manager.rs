@@ -0,0 +1,143 @@
+use crate::{interfaces::RoleManager, *};
+
+impl AnchorRole {
+    ///
+    pub fn all() -> Vec<AnchorRole> {
+        vec![
+            AnchorRole::Owner,
+            AnchorRole::Operator,
+            AnchorRole::Pauser,
+            AnchorRole::Relayer,
+            AnchorRole::PriceMaintainer,
+            AnchorRole::SettingsManager,
+            AnchorRole::Guardian,
+            AnchorRole::Witness,
+        ]
+    }
+}
+
+impl IndexedAndClearable for RoleAuditRecord {
+    //
+    fn set_index(&mut self, index: &u64) {
+        self.index = U64::from(*index);
+    }
+    //
+    fn clear_extra_storage(&mut self) -> MultiTxsOperationProcessingResult {
+        MultiTxsOperationProcessingResult::Ok
+    }
+}
+
+impl AppchainAnchor {
+    /// Whether an account holds a role.
+    ///
+    /// The owner of this contract holds role `Owner` implicitly. And the relayer account and
+    /// token price maintainer account in anchor settings hold role `Relayer` and
+    /// `PriceMaintainer` implicitly. The relayer account also holds role `Witness` implicitly.
+    pub fn has_role(&self, role: &AnchorRole, account_id: &AccountId) -> bool {
+        let anchor_settings = self.anchor_settings.get().unwrap();
+        let is_implicit_member = match role {
+            AnchorRole::Owner => self.owner.eq(account_id),
+            AnchorRole::Relayer | AnchorRole::Witness => anchor_settings
+                .relayer_account
+                .map_or(false, |relayer_account| relayer_account.eq(account_id)),
+            AnchorRole::PriceMaintainer => anchor_settings
+                .token_price_maintainer_account
+                .map_or(false, |maintainer| maintainer.eq(account_id)),
+            AnchorRole::Operator
+            | AnchorRole::Pauser
+            | AnchorRole::SettingsManager
+            | AnchorRole::Guardian => false,
+        };
+        is_implicit_member
+            || self
+                .role_members
+                .get(role)
+                .map_or(false, |members| members.contains(account_id))
+    }
+    /// Assert that the function is called by an account holding one of the given roles.
+    pub fn assert_any_role(&self, roles: &[AnchorRole]) {
+        let account_id = env::predecessor_account_id();
+        assert!(
+            roles.iter().any(|role| self.has_role(role, &account_id)),
+            "This function can only be called by account with role(s): {}.",
+            roles
+                .iter()
+                .map(|role| serde_json::to_string(role).unwrap())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+    ///
+    pub fn internal_grant_role(&mut self, role: AnchorRole, account_id: AccountId) {
+        let mut members = self.role_members.get(&role).unwrap_or_default();
+        assert!(
+            !members.contains(&account_id),
+            "Account '{}' already has role '{}'.",
+            account_id,
+            serde_json::to_string(&role).unwrap()
+        );
+        members.push(account_id.clone());
+        self.role_members.insert(&role, &members);
+        self.append_role_audit_record(role, account_id, RoleChange::Granted);
+    }
+    ///
+    pub fn internal_revoke_role(&mut self, role: AnchorRole, account_id: AccountId) {
+        let mut members = self.role_members.get(&role).unwrap_or_default();
+        let position = members.iter().position(|member| member.eq(&account_id));
+        assert!(
+            position.is_some(),
+            "Account '{}' does not have role '{}'.",
+            account_id,
+            serde_json::to_string(&role).unwrap()
+        );
+        members.remove(position.unwrap());
+        self.role_members.insert(&role, &members);
+        self.append_role_audit_record(role, account_id, RoleChange::Revoked);
+    }
+    //
+    fn append_role_audit_record(
+        &mut self,
+        role: AnchorRole,
+        account_id: AccountId,
+        change: RoleChange,
+    ) {
+        let mut role_audit_records = self.role_audit_records.get().unwrap();
+        let record = role_audit_records.append(&mut RoleAuditRecord {
+            role,
+            account_id,
+            change,
+            operator: env::predecessor_account_id(),
+            timestamp: U64::from(env::block_timestamp()),
+            index: U64::from(0),
+        });
+        self.role_audit_records.set(&role_audit_records);
+        log!(
+            "Role audit record: '{}'",
+            serde_json::to_string(&record).unwrap()
+        );
+    }
+}
+
+#[near_bindgen]
+impl RoleManager for AppchainAnchor {
+    //
+    fn grant_role(&mut self, role: AnchorRole, account_id: AccountId) {
+        self.assert_owner();
+        self.internal_grant_role(role, account_id);
+    }
+    //
+    fn revoke_role(&mut self, role: AnchorRole, account_id: AccountId) {
+        self.assert_owner();
+        let is_witness = role.eq(&AnchorRole::Witness);
+        self.internal_revoke_role(role, account_id);
+        if is_witness
+            && self
+                .anchor_settings
+                .get()
+                .unwrap()
+                .beefy_light_client_witness_mode
+        {
+            self.assert_witness_attestation_threshold_is_reachable();
+        }
+    }
+}
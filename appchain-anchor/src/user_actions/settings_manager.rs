@@ -3,6 +3,7 @@ use crate::{
     *,
 };
 use core::convert::From;
+use near_sdk::borsh::maybestd::collections::HashMap;
 
 impl Default for ProtocolSettings {
     fn default() -> Self {
@@ -25,6 +26,17 @@ impl Default for ProtocolSettings {
             validator_commission_percent: 20,
             maximum_allowed_unprofitable_era_count: 3,
             subaccount_for_council_keeper_contract: "octopus-council".to_string(),
+            slash_percent_per_offence_of_kinds: HashMap::new(),
+            maximum_validator_commission_percent: 50,
+            maximum_validator_commission_percent_change_per_era: 5,
+            minimum_jailed_era_count_before_unjail: 2,
+            maximum_jailed_era_count: 7,
+            retry_period_of_failed_appchain_message: U64::from(7),
+            maximum_price_deviation_percent_per_update: 20,
+            staleness_timeout_of_token_price: U64::from(86400),
+            minimum_price_submission_count: 3,
+            governance_timelock_delay: U64::from(172800),
+            witness_attestation_threshold: 3,
         }
     }
 }
@@ -35,6 +47,7 @@ impl Default for AnchorSettings {
             token_price_maintainer_account: None,
             relayer_account: None,
             beefy_light_client_witness_mode: false,
+            treasury_account: None,
         }
     }
 }
@@ -46,6 +59,8 @@ impl Default for AppchainSettings {
             subql_endpoint: String::new(),
             era_reward: U128::from(0),
             bonus_for_new_validator: U128::from(0),
+            bounty_for_appchain_challenge: U128::from(0),
+            relayer_reward_per_era: U128::from(0),
         }
     }
 }
@@ -72,7 +87,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_minimum_validator_deposit_changing_amount(&mut self, value: U128) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0
@@ -90,7 +105,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_validator_stake_percent(&mut self, value: u16) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         assert!(value < 100, "Invalid percent value.");
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
@@ -102,7 +117,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_minimum_delegator_deposit(&mut self, value: U128) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.minimum_delegator_deposit.0,
@@ -120,7 +135,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_minimum_delegator_deposit_changing_amount(&mut self, value: U128) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0
@@ -138,7 +153,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_minimum_total_stake_price_for_booting(&mut self, value: U128) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.minimum_total_stake_price_for_booting.0,
@@ -149,7 +164,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_market_value_percent_of_near_fungible_tokens(&mut self, value: u16) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value != protocol_settings.maximum_market_value_percent_of_near_fungible_tokens,
@@ -160,7 +175,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_market_value_percent_of_wrapped_appchain_token(&mut self, value: u16) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value != protocol_settings.maximum_market_value_percent_of_wrapped_appchain_token,
@@ -171,7 +186,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_minimum_validator_count(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.minimum_validator_count.0,
@@ -186,7 +201,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_validator_count(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.maximum_validator_count.0,
@@ -201,7 +216,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_validators_per_delegator(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.maximum_validators_per_delegator.0,
@@ -212,7 +227,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_unlock_period_of_validator_deposit(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.unlock_period_of_validator_deposit.0,
@@ -223,7 +238,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_unlock_period_of_delegator_deposit(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.unlock_period_of_delegator_deposit.0,
@@ -234,7 +249,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_era_count_of_unwithdrawn_reward(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0 != protocol_settings.maximum_era_count_of_unwithdrawn_reward.0,
@@ -245,7 +260,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_maximum_era_count_of_valid_appchain_message(&mut self, value: U64) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
             value.0
@@ -259,7 +274,7 @@ impl ProtocolSettingsManager for AppchainAnchor {
     }
     //
     fn change_validator_commission_percent(&mut self, value: u16) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         assert!(value < 100, "Invalid percent value.");
         let mut protocol_settings = self.protocol_settings.get().unwrap();
         assert!(
@@ -270,8 +285,32 @@ impl ProtocolSettingsManager for AppchainAnchor {
         self.protocol_settings.set(&protocol_settings);
     }
     //
+    fn change_maximum_validator_commission_percent(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        assert!(value <= 100, "Invalid percent value.");
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value != protocol_settings.maximum_validator_commission_percent,
+            "The value is not changed."
+        );
+        protocol_settings.maximum_validator_commission_percent = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_maximum_validator_commission_percent_change_per_era(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        assert!(value <= 100, "Invalid percent value.");
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value != protocol_settings.maximum_validator_commission_percent_change_per_era,
+            "The value is not changed."
+        );
+        protocol_settings.maximum_validator_commission_percent_change_per_era = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
     fn change_maximum_allowed_unprofitable_era_count(&mut self, value: u16) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         assert!(
             value < 10,
             "Invalid value for maximum allowed unprofitable era count."
@@ -285,8 +324,111 @@ impl ProtocolSettingsManager for AppchainAnchor {
         self.protocol_settings.set(&protocol_settings);
     }
     //
+    fn change_minimum_jailed_era_count_before_unjail(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value <= protocol_settings.maximum_jailed_era_count,
+            "The value should not be greater than maximum jailed era count."
+        );
+        assert!(
+            value != protocol_settings.minimum_jailed_era_count_before_unjail,
+            "The value is not changed."
+        );
+        protocol_settings.minimum_jailed_era_count_before_unjail = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_retry_period_of_failed_appchain_message(&mut self, value: U64) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value.0 != protocol_settings.retry_period_of_failed_appchain_message.0,
+            "The value is not changed."
+        );
+        protocol_settings.retry_period_of_failed_appchain_message = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_maximum_price_deviation_percent_per_update(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value != protocol_settings.maximum_price_deviation_percent_per_update,
+            "The value is not changed."
+        );
+        assert!(value <= 100, "The value should not be greater than 100.");
+        protocol_settings.maximum_price_deviation_percent_per_update = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_staleness_timeout_of_token_price(&mut self, value: U64) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value.0 != protocol_settings.staleness_timeout_of_token_price.0,
+            "The value is not changed."
+        );
+        protocol_settings.staleness_timeout_of_token_price = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_minimum_price_submission_count(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(value > 0, "The value should be greater than 0.");
+        assert!(
+            value != protocol_settings.minimum_price_submission_count,
+            "The value is not changed."
+        );
+        protocol_settings.minimum_price_submission_count = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_governance_timelock_delay(&mut self, value: U64) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value.0 != protocol_settings.governance_timelock_delay.0,
+            "The value is not changed."
+        );
+        protocol_settings.governance_timelock_delay = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_witness_attestation_threshold(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(value > 0, "The value should be greater than 0.");
+        assert!(
+            value != protocol_settings.witness_attestation_threshold,
+            "The value is not changed."
+        );
+        assert!(
+            value <= self.get_witness_count(),
+            "The value should not be greater than the count of witnesses."
+        );
+        protocol_settings.witness_attestation_threshold = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn change_maximum_jailed_era_count(&mut self, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            value >= protocol_settings.minimum_jailed_era_count_before_unjail,
+            "The value should not be less than minimum jailed era count before unjail."
+        );
+        assert!(
+            value != protocol_settings.maximum_jailed_era_count,
+            "The value is not changed."
+        );
+        protocol_settings.maximum_jailed_era_count = value;
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
     fn change_subaccount_for_council_keeper_contract(&mut self, subaccount_name: String) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         assert!(
             !subaccount_name.trim().is_empty(),
             "The subaccount name can not be empty."
@@ -299,38 +441,84 @@ impl ProtocolSettingsManager for AppchainAnchor {
         protocol_settings.subaccount_for_council_keeper_contract = subaccount_name;
         self.protocol_settings.set(&protocol_settings);
     }
+    //
+    fn change_slash_percent_per_offence_of_kind(&mut self, kind: String, value: u16) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        assert!(!kind.trim().is_empty(), "The offence kind can not be empty.");
+        assert!(value <= 100, "Invalid percent value.");
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            protocol_settings
+                .slash_percent_per_offence_of_kinds
+                .get(&kind)
+                .map_or(true, |percent| *percent != value),
+            "The value is not changed."
+        );
+        protocol_settings
+            .slash_percent_per_offence_of_kinds
+            .insert(kind, value);
+        self.protocol_settings.set(&protocol_settings);
+    }
+    //
+    fn remove_slash_percent_per_offence_of_kind(&mut self, kind: String) {
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
+        let mut protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            protocol_settings
+                .slash_percent_per_offence_of_kinds
+                .remove(&kind)
+                .is_some(),
+            "The offence kind '{}' is not configured.",
+            kind
+        );
+        self.protocol_settings.set(&protocol_settings);
+    }
 }
 
 #[near_bindgen]
 impl AppchainSettingsManager for AppchainAnchor {
     //
     fn set_rpc_endpoint(&mut self, rpc_endpoint: String) {
-        self.assert_owner();
+        self.assert_settings_manager();
         let mut appchain_settings = self.appchain_settings.get().unwrap();
         appchain_settings.rpc_endpoint = rpc_endpoint;
         self.appchain_settings.set(&appchain_settings);
     }
     //
     fn set_subql_endpoint(&mut self, subql_endpoint: String) {
-        self.assert_owner();
+        self.assert_settings_manager();
         let mut appchain_settings = self.appchain_settings.get().unwrap();
         appchain_settings.subql_endpoint = subql_endpoint;
         self.appchain_settings.set(&appchain_settings);
     }
     //
     fn set_era_reward(&mut self, era_reward: U128) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner, AnchorRole::SettingsManager]);
         let mut appchain_settings = self.appchain_settings.get().unwrap();
         appchain_settings.era_reward = era_reward;
         self.appchain_settings.set(&appchain_settings);
     }
     //
     fn set_bonus_for_new_validator(&mut self, bonus_amount: U128) {
-        self.assert_owner();
+        self.assert_settings_manager();
         let mut appchain_settings = self.appchain_settings.get().unwrap();
         appchain_settings.bonus_for_new_validator = bonus_amount;
         self.appchain_settings.set(&appchain_settings);
     }
+    //
+    fn set_bounty_for_appchain_challenge(&mut self, bounty_amount: U128) {
+        self.assert_settings_manager();
+        let mut appchain_settings = self.appchain_settings.get().unwrap();
+        appchain_settings.bounty_for_appchain_challenge = bounty_amount;
+        self.appchain_settings.set(&appchain_settings);
+    }
+    //
+    fn set_relayer_reward_per_era(&mut self, reward_amount: U128) {
+        self.assert_settings_manager();
+        let mut appchain_settings = self.appchain_settings.get().unwrap();
+        appchain_settings.relayer_reward_per_era = reward_amount;
+        self.appchain_settings.set(&appchain_settings);
+    }
 }
 
 #[near_bindgen]
@@ -348,7 +536,7 @@ impl AnchorSettingsManager for AppchainAnchor {
     }
     //
     fn set_relayer_account(&mut self, account_id: AccountId) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         assert!(
             !account_id.eq(&self.owner),
             "This account should not be the same as the owner account."
@@ -356,10 +544,13 @@ impl AnchorSettingsManager for AppchainAnchor {
         let mut anchor_settings = self.anchor_settings.get().unwrap();
         anchor_settings.relayer_account = Some(account_id);
         self.anchor_settings.set(&anchor_settings);
+        if anchor_settings.beefy_light_client_witness_mode {
+            self.assert_witness_attestation_threshold_is_reachable();
+        }
     }
     //
     fn turn_on_beefy_light_client_witness_mode(&mut self) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         let mut anchor_settings = self.anchor_settings.get().unwrap();
         assert!(
             !anchor_settings.beefy_light_client_witness_mode,
@@ -367,6 +558,7 @@ impl AnchorSettingsManager for AppchainAnchor {
         );
         anchor_settings.beefy_light_client_witness_mode = true;
         self.anchor_settings.set(&anchor_settings);
+        self.assert_witness_attestation_threshold_is_reachable();
     }
     //
     fn turn_off_beefy_light_client_witness_mode(&mut self) {
@@ -379,4 +571,18 @@ impl AnchorSettingsManager for AppchainAnchor {
         anchor_settings.beefy_light_client_witness_mode = false;
         self.anchor_settings.set(&anchor_settings);
     }
+    //
+    fn set_treasury_account(&mut self, account_id: AccountId) {
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
+        let mut anchor_settings = self.anchor_settings.get().unwrap();
+        assert!(
+            !anchor_settings
+                .treasury_account
+                .as_ref()
+                .map_or(false, |treasury_account| treasury_account.eq(&account_id)),
+            "The value is not changed."
+        );
+        anchor_settings.treasury_account = Some(account_id);
+        self.anchor_settings.set(&anchor_settings);
+    }
 }
@@ -1,10 +1,28 @@
-use crate::{interfaces::StakingManager, *};
+use crate::{
+    interfaces::{AnchorViewer, StakingManager},
+    *,
+};
 use borsh::maybestd::collections::HashMap;
 use near_contract_standards::fungible_token::core::ext_ft_core;
 use near_sdk::serde_json;
-use std::str::FromStr;
 
-const SUB_ACCOUNT_ID_OF_WAT_FAUCET: &str = "wat-faucet";
+pub trait WithdrawalResolver {
+    /// Resolver for withdrawing unbonded stake
+    fn resolve_stake_withdrawal(&mut self, account_id: AccountId, stake_amount: U128);
+    /// Resolver for withdrawing rewards
+    fn resolve_rewards_withdrawal(
+        &mut self,
+        account_id: AccountId,
+        receiver_id: AccountId,
+        withdrawn_rewards: WithdrawnRewards,
+    );
+    /// Resolver for claiming a failed withdrawal
+    fn resolve_failed_withdrawal_claim(
+        &mut self,
+        account_id: AccountId,
+        failed_withdrawal: FailedWithdrawal,
+    );
+}
 
 impl AppchainAnchor {
     //
@@ -126,40 +144,16 @@ impl AppchainAnchor {
         self.sync_state_to_registry();
         //
         validator_profiles.insert(ValidatorProfile {
-            validator_id,
+            validator_id: validator_id.clone(),
             validator_id_in_appchain: formatted_validator_id_in_appchain.to_string(),
             profile,
         });
         self.validator_profiles.set(&validator_profiles);
         //
         if self.appchain_state.eq(&AppchainState::Active) {
-            let wat_faucet_account = AccountId::from_str(
-                format!(
-                    "{}.{}",
-                    SUB_ACCOUNT_ID_OF_WAT_FAUCET,
-                    env::current_account_id()
-                )
-                .as_str(),
-            )
-            .unwrap();
-            let appchain_settings = self.appchain_settings.get().unwrap();
-            #[derive(near_sdk::serde::Serialize)]
-            #[serde(crate = "near_sdk::serde")]
-            struct Input {
-                receiver_id: String,
-                amount: U128,
-            }
-            let args = Input {
-                receiver_id: formatted_validator_id_in_appchain.to_string(),
-                amount: appchain_settings.bonus_for_new_validator,
-            };
-            let args = near_sdk::serde_json::to_vec(&args)
-                .expect("Failed to serialize the cross contract args using JSON.");
-            Promise::new(wat_faucet_account).function_call(
-                "burn_wrapped_appchain_token".to_string(),
-                args,
-                0,
-                Gas::ONE_TERA.mul(T_GAS_FOR_BURN_WRAPPED_APPCHAIN_TOKEN),
+            self.internal_make_onboarding_grant(
+                &validator_id,
+                &formatted_validator_id_in_appchain.to_string(),
             );
         }
     }
@@ -495,18 +489,13 @@ impl StakingManager for AppchainAnchor {
     }
     //
     fn withdraw_stake(&mut self, account_id: AccountId) {
-        self.assert_asset_transfer_is_not_paused();
+        self.assert_stake_withdrawal_is_not_paused();
         let protocol_settings = self.protocol_settings.get().unwrap();
         let mut balance_to_withdraw: u128 = 0;
         let mut remained_stakes = Vec::<UnbondedStakeReference>::new();
         if let Some(unbonded_stake_references) = self.unbonded_stakes.get(&account_id) {
             unbonded_stake_references.iter().for_each(|reference| {
-                let validator_set = self
-                    .validator_set_histories
-                    .get()
-                    .unwrap()
-                    .get(&reference.era_number)
-                    .unwrap();
+                let unlock_start_timestamp = self.get_unlock_start_timestamp_of(reference);
                 let staking_history = self
                     .staking_histories
                     .get()
@@ -526,7 +515,7 @@ impl StakingManager for AppchainAnchor {
                         validator_id: _,
                         amount,
                     } => {
-                        if validator_set.start_timestamp()
+                        if unlock_start_timestamp
                             + protocol_settings.unlock_period_of_validator_deposit.0
                                 * SECONDS_OF_A_DAY
                                 * NANO_SECONDS_MULTIPLE
@@ -552,7 +541,7 @@ impl StakingManager for AppchainAnchor {
                         validator_id: _,
                         amount,
                     } => {
-                        if validator_set.start_timestamp()
+                        if unlock_start_timestamp
                             + protocol_settings.unlock_period_of_delegator_deposit.0
                                 * SECONDS_OF_A_DAY
                                 * NANO_SECONDS_MULTIPLE
@@ -571,117 +560,96 @@ impl StakingManager for AppchainAnchor {
             } else {
                 self.unbonded_stakes.remove(&account_id);
             }
-            if balance_to_withdraw > 0 {
-                ext_ft_core::ext(self.oct_token.get().unwrap().contract_account)
-                    .with_attached_deposit(1)
-                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
-                    .with_unused_gas_weight(0)
-                    .ft_transfer(account_id, balance_to_withdraw.into(), None);
-            }
         };
+        if balance_to_withdraw > 0 {
+            ext_ft_core::ext(self.oct_token.get().unwrap().contract_account)
+                .with_attached_deposit(1)
+                .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+                .with_unused_gas_weight(0)
+                .ft_transfer(account_id.clone(), balance_to_withdraw.into(), None)
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_attached_deposit(0)
+                        .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                        .with_unused_gas_weight(0)
+                        .resolve_stake_withdrawal(account_id, U128::from(balance_to_withdraw)),
+                );
+        }
     }
     //
     fn withdraw_validator_rewards(&mut self, validator_id: AccountId) {
-        self.assert_asset_transfer_is_not_paused();
         self.assert_rewards_withdrawal_is_not_paused();
         self.assert_contract_account_of_wrapped_appchain_token_is_set();
-        let end_era = self
-            .validator_set_histories
-            .get()
-            .unwrap()
-            .index_range()
-            .end_index
-            .0;
-        let protocol_settings = self.protocol_settings.get().unwrap();
-        let start_era = match end_era > protocol_settings.maximum_era_count_of_unwithdrawn_reward.0
-        {
-            true => end_era - protocol_settings.maximum_era_count_of_unwithdrawn_reward.0 + 1,
-            false => 0,
-        };
-        let mut reward_to_withdraw: u128 = 0;
-        for era_number in start_era..end_era + 1 {
-            if let Some(reward) = self
-                .unwithdrawn_validator_rewards
-                .get(&(era_number, validator_id.clone()))
-            {
-                reward_to_withdraw += reward;
-                self.unwithdrawn_validator_rewards
-                    .remove(&(era_number, validator_id.clone()));
-                log!(
-                    "Era reward is withdrawn. Era number: '{}', Validator id: '{}', Amount: '{}'.",
-                    era_number,
-                    validator_id,
-                    reward,
-                );
-            }
-        }
-        if reward_to_withdraw > 0 {
-            ext_ft_core::ext(
-                self.wrapped_appchain_token
-                    .get()
-                    .unwrap()
-                    .contract_account
-                    .unwrap(),
-            )
-            .with_attached_deposit(1)
-            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
-            .with_unused_gas_weight(0)
-            .ft_transfer(validator_id, reward_to_withdraw.into(), None);
-        }
+        let mut withdrawn_rewards = WithdrawnRewards::new();
+        self.take_unwithdrawn_validator_rewards_of(&validator_id, &mut withdrawn_rewards);
+        self.transfer_rewards_to(validator_id, withdrawn_rewards);
     }
     //
     fn withdraw_delegator_rewards(&mut self, delegator_id: AccountId, validator_id: AccountId) {
-        self.assert_asset_transfer_is_not_paused();
         self.assert_rewards_withdrawal_is_not_paused();
         self.assert_contract_account_of_wrapped_appchain_token_is_set();
-        let end_era = self
-            .validator_set_histories
-            .get()
-            .unwrap()
-            .index_range()
-            .end_index
-            .0;
-        let protocol_settings = self.protocol_settings.get().unwrap();
-        let start_era = match end_era > protocol_settings.maximum_era_count_of_unwithdrawn_reward.0
+        let mut withdrawn_rewards = WithdrawnRewards::new();
+        self.take_unwithdrawn_delegator_rewards_of(
+            &delegator_id,
+            &validator_id,
+            &mut withdrawn_rewards,
+        );
+        self.transfer_rewards_to(delegator_id, withdrawn_rewards);
+    }
+    //
+    fn withdraw_all_rewards_of(
+        &mut self,
+        account_id: AccountId,
+    ) -> MultiTxsOperationProcessingResult {
+        self.assert_rewards_withdrawal_is_not_paused();
+        self.assert_contract_account_of_wrapped_appchain_token_is_set();
+        let mut withdrawn_rewards = match self.pending_rewards_of_batch_withdrawal.get(&account_id)
         {
-            true => end_era - protocol_settings.maximum_era_count_of_unwithdrawn_reward.0 + 1,
-            false => 0,
+            Some(withdrawn_rewards) => withdrawn_rewards,
+            None => {
+                let mut withdrawn_rewards = WithdrawnRewards::new();
+                self.take_unwithdrawn_validator_rewards_of(&account_id, &mut withdrawn_rewards);
+                // The rewards of current delegations may be distributed before the validators
+                // of unwithdrawn delegator rewards are recorded.
+                let mut validator_ids = self
+                    .validators_of_unwithdrawn_delegator_rewards
+                    .get(&account_id)
+                    .unwrap_or_default();
+                for delegation in self.get_delegations_of(account_id.clone(), None) {
+                    if !validator_ids.contains(&delegation.validator_id) {
+                        validator_ids.push(delegation.validator_id);
+                    }
+                }
+                self.validators_of_unwithdrawn_delegator_rewards
+                    .insert(&account_id, &validator_ids);
+                withdrawn_rewards
+            }
         };
-        let mut reward_to_withdraw: u128 = 0;
-        for era_number in start_era..end_era {
-            if let Some(reward) = self.unwithdrawn_delegator_rewards.get(&(
-                era_number,
-                delegator_id.clone(),
-                validator_id.clone(),
-            )) {
-                reward_to_withdraw += reward;
-                self.unwithdrawn_delegator_rewards.remove(&(
-                    era_number,
-                    delegator_id.clone(),
-                    validator_id.clone(),
-                ));
-                log!(
-                    "Era reward is withdrawn. Era number: '{}', Delegator id: '{}', Validator id: '{}', Amount: '{}'.",
-                    era_number,
-                    delegator_id,
-                    validator_id,
-                    reward,
-                );
+        // The validators are taken from the recorded list one by one, so that
+        // the withdrawal can be resumed from where it stopped.
+        let mut validator_ids = self
+            .validators_of_unwithdrawn_delegator_rewards
+            .get(&account_id)
+            .unwrap_or_default();
+        while let Some(validator_id) = validator_ids.pop() {
+            self.take_unwithdrawn_delegator_rewards_of(
+                &account_id,
+                &validator_id,
+                &mut withdrawn_rewards,
+            );
+            if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
+                self.validators_of_unwithdrawn_delegator_rewards
+                    .insert(&account_id, &validator_ids);
+                self.pending_rewards_of_batch_withdrawal
+                    .insert(&account_id, &withdrawn_rewards);
+                return MultiTxsOperationProcessingResult::NeedMoreGas;
             }
         }
-        if reward_to_withdraw > 0 {
-            ext_ft_core::ext(
-                self.wrapped_appchain_token
-                    .get()
-                    .unwrap()
-                    .contract_account
-                    .unwrap(),
-            )
-            .with_attached_deposit(1)
-            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
-            .with_unused_gas_weight(0)
-            .ft_transfer(delegator_id, reward_to_withdraw.into(), None);
-        }
+        self.validators_of_unwithdrawn_delegator_rewards
+            .remove(&account_id);
+        self.pending_rewards_of_batch_withdrawal.remove(&account_id);
+        self.transfer_rewards_to(account_id, withdrawn_rewards);
+        MultiTxsOperationProcessingResult::Ok
     }
     //
     fn change_delegated_validator(
@@ -728,6 +696,76 @@ impl StakingManager for AppchainAnchor {
         next_validator_set.apply_staking_fact(&staking_history.staking_fact);
         self.next_validator_set.set(&next_validator_set);
     }
+    //
+    fn set_reward_beneficiary(&mut self, beneficiary_id: Option<AccountId>) {
+        let account_id = env::predecessor_account_id();
+        let next_validator_set = self.next_validator_set.get().unwrap();
+        assert!(
+            next_validator_set.contains_validator(&account_id)
+                || next_validator_set.get_validator_count_of(&account_id) > 0,
+            "Account '{}' is neither a validator nor a delegator.",
+            &account_id
+        );
+        let beneficiary_id =
+            beneficiary_id.filter(|beneficiary_id| !beneficiary_id.eq(&account_id));
+        assert!(
+            self.reward_beneficiaries.get(&account_id) != beneficiary_id,
+            "The beneficiary of rewards is not changed."
+        );
+        match &beneficiary_id {
+            Some(beneficiary_id) => self
+                .reward_beneficiaries
+                .insert(&account_id, beneficiary_id),
+            None => self.reward_beneficiaries.remove(&account_id),
+        };
+        self.record_staking_fact(StakingFact::RewardBeneficiaryChanged {
+            account_id,
+            beneficiary_id,
+        });
+    }
+    //
+    fn claim_failed_withdrawals(&mut self, account_id: AccountId) {
+        self.assert_stake_withdrawal_is_not_paused();
+        self.assert_rewards_withdrawal_is_not_paused();
+        let mut failed_withdrawals = self.failed_withdrawals.get(&account_id).unwrap_or_default();
+        assert!(
+            failed_withdrawals.len() > 0,
+            "There is no failed withdrawal of '{}'.",
+            account_id
+        );
+        // Each claim takes a transfer and a resolver, the remained ones
+        // can be claimed in another call.
+        let gas_for_each_claim =
+            Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER + T_GAS_FOR_RESOLVER_FUNCTION);
+        while failed_withdrawals.len() > 0
+            && env::prepaid_gas() - env::used_gas()
+                > gas_for_each_claim + Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION)
+        {
+            let failed_withdrawal = failed_withdrawals.remove(0);
+            ext_ft_core::ext(failed_withdrawal.token_contract.clone())
+                .with_attached_deposit(1)
+                .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+                .with_unused_gas_weight(0)
+                .ft_transfer(
+                    failed_withdrawal.receiver_id.clone(),
+                    failed_withdrawal.amount,
+                    None,
+                )
+                .then(
+                    ext_self::ext(env::current_account_id())
+                        .with_attached_deposit(0)
+                        .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                        .with_unused_gas_weight(0)
+                        .resolve_failed_withdrawal_claim(account_id.clone(), failed_withdrawal),
+                );
+        }
+        if failed_withdrawals.len() > 0 {
+            self.failed_withdrawals
+                .insert(&account_id, &failed_withdrawals);
+        } else {
+            self.failed_withdrawals.remove(&account_id);
+        }
+    }
 }
 
 impl AppchainAnchor {
@@ -749,6 +787,124 @@ impl AppchainAnchor {
             "Market value of stake deposited in anchor is too low."
         );
     }
+    // Get the era range in which the unwithdrawn rewards can be withdrawn.
+    fn get_era_range_of_unwithdrawn_rewards(&self) -> (u64, u64) {
+        let end_era = self
+            .validator_set_histories
+            .get()
+            .unwrap()
+            .index_range()
+            .end_index
+            .0;
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        let start_era = match end_era > protocol_settings.maximum_era_count_of_unwithdrawn_reward.0
+        {
+            true => end_era - protocol_settings.maximum_era_count_of_unwithdrawn_reward.0 + 1,
+            false => 0,
+        };
+        (start_era, end_era)
+    }
+    // Remove the unwithdrawn rewards of a validator and record them in `withdrawn_rewards`.
+    fn take_unwithdrawn_validator_rewards_of(
+        &mut self,
+        validator_id: &AccountId,
+        withdrawn_rewards: &mut WithdrawnRewards,
+    ) {
+        let (start_era, end_era) = self.get_era_range_of_unwithdrawn_rewards();
+        for era_number in start_era..end_era + 1 {
+            if let Some(reward) = self
+                .unwithdrawn_validator_rewards
+                .get(&(era_number, validator_id.clone()))
+            {
+                withdrawn_rewards
+                    .validator_rewards
+                    .push((U64::from(era_number), U128::from(reward)));
+                self.unwithdrawn_validator_rewards
+                    .remove(&(era_number, validator_id.clone()));
+                log!(
+                    "Era reward is withdrawn. Era number: '{}', Validator id: '{}', Amount: '{}'.",
+                    era_number,
+                    validator_id,
+                    reward,
+                );
+            }
+        }
+    }
+    // Remove the unwithdrawn rewards of a delegator to a validator
+    // and record them in `withdrawn_rewards`.
+    fn take_unwithdrawn_delegator_rewards_of(
+        &mut self,
+        delegator_id: &AccountId,
+        validator_id: &AccountId,
+        withdrawn_rewards: &mut WithdrawnRewards,
+    ) {
+        let (start_era, end_era) = self.get_era_range_of_unwithdrawn_rewards();
+        for era_number in start_era..end_era + 1 {
+            if let Some(reward) = self.unwithdrawn_delegator_rewards.get(&(
+                era_number,
+                delegator_id.clone(),
+                validator_id.clone(),
+            )) {
+                withdrawn_rewards.delegator_rewards.push((
+                    U64::from(era_number),
+                    validator_id.clone(),
+                    U128::from(reward),
+                ));
+                self.unwithdrawn_delegator_rewards.remove(&(
+                    era_number,
+                    delegator_id.clone(),
+                    validator_id.clone(),
+                ));
+                log!(
+                    "Era reward is withdrawn. Era number: '{}', Delegator id: '{}', Validator id: '{}', Amount: '{}'.",
+                    era_number,
+                    delegator_id,
+                    validator_id,
+                    reward,
+                );
+            }
+        }
+    }
+    // Transfer rewards to the beneficiary of the account (or the account itself
+    // if the beneficiary is not set).
+    fn transfer_rewards_to(&self, account_id: AccountId, withdrawn_rewards: WithdrawnRewards) {
+        let amount = withdrawn_rewards.total_amount();
+        if amount > 0 {
+            let receiver_id = self
+                .reward_beneficiaries
+                .get(&account_id)
+                .unwrap_or(account_id.clone());
+            ext_ft_core::ext(
+                self.wrapped_appchain_token
+                    .get()
+                    .unwrap()
+                    .contract_account
+                    .unwrap(),
+            )
+            .with_attached_deposit(1)
+            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+            .with_unused_gas_weight(0)
+            .ft_transfer(receiver_id.clone(), amount.into(), None)
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_attached_deposit(0)
+                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_unused_gas_weight(0)
+                    .resolve_rewards_withdrawal(account_id, receiver_id, withdrawn_rewards),
+            );
+        }
+    }
+    //
+    fn record_failed_withdrawal(
+        &mut self,
+        account_id: &AccountId,
+        failed_withdrawal: FailedWithdrawal,
+    ) {
+        let mut failed_withdrawals = self.failed_withdrawals.get(account_id).unwrap_or_default();
+        failed_withdrawals.push(failed_withdrawal);
+        self.failed_withdrawals
+            .insert(account_id, &failed_withdrawals);
+    }
     //
     pub fn record_unbonding_validator(
         &self,
@@ -771,3 +927,109 @@ impl AppchainAnchor {
         }
     }
 }
+
+#[near_bindgen]
+impl WithdrawalResolver for AppchainAnchor {
+    //
+    fn resolve_stake_withdrawal(&mut self, account_id: AccountId, stake_amount: U128) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Unbonded stake is withdrawn. Account id: '{}', Amount: '{}'.",
+                    account_id,
+                    stake_amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                self.record_failed_withdrawal(
+                    &account_id,
+                    FailedWithdrawal {
+                        kind: WithdrawalKind::Stake,
+                        receiver_id: account_id.clone(),
+                        token_contract: self.oct_token.get().unwrap().contract_account,
+                        amount: stake_amount,
+                        timestamp: U64::from(env::block_timestamp()),
+                    },
+                );
+                log!(
+                    "Failed to withdraw unbonded stake of '{}'. Amount: '{}'. It can be claimed later.",
+                    account_id,
+                    stake_amount.0
+                );
+            }
+        }
+    }
+    //
+    fn resolve_rewards_withdrawal(
+        &mut self,
+        account_id: AccountId,
+        receiver_id: AccountId,
+        withdrawn_rewards: WithdrawnRewards,
+    ) {
+        assert_self();
+        let amount = withdrawn_rewards.total_amount();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Rewards of '{}' are transferred to '{}'. Amount: '{}'.",
+                    account_id,
+                    receiver_id,
+                    amount
+                );
+            }
+            PromiseResult::Failed => {
+                self.record_failed_withdrawal(
+                    &account_id,
+                    FailedWithdrawal {
+                        kind: WithdrawalKind::Rewards,
+                        receiver_id: receiver_id.clone(),
+                        token_contract: self
+                            .wrapped_appchain_token
+                            .get()
+                            .unwrap()
+                            .contract_account
+                            .unwrap(),
+                        amount: U128::from(amount),
+                        timestamp: U64::from(env::block_timestamp()),
+                    },
+                );
+                log!(
+                    "Failed to transfer rewards of '{}' to '{}'. Amount: '{}'. It can be claimed later.",
+                    account_id,
+                    receiver_id,
+                    amount
+                );
+            }
+        }
+    }
+    //
+    fn resolve_failed_withdrawal_claim(
+        &mut self,
+        account_id: AccountId,
+        failed_withdrawal: FailedWithdrawal,
+    ) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => {
+                log!(
+                    "Failed withdrawal of '{}' is claimed by '{}'. Amount: '{}'.",
+                    account_id,
+                    failed_withdrawal.receiver_id,
+                    failed_withdrawal.amount.0
+                );
+            }
+            PromiseResult::Failed => {
+                log!(
+                    "Failed to claim failed withdrawal of '{}'. Amount: '{}'.",
+                    account_id,
+                    failed_withdrawal.amount.0
+                );
+                self.record_failed_withdrawal(&account_id, failed_withdrawal);
+            }
+        }
+    }
+}
@@ -7,12 +7,12 @@ use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
 impl SudoActions for AppchainAnchor {
     //
     fn set_owner_pk(&mut self, public_key: PublicKey) {
-        self.assert_owner();
-        self.owner_pk = public_key;
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
+        self.internal_set_owner_pk(public_key);
     }
     //
     fn set_metadata_of_wrapped_appchain_token(&mut self, metadata: FungibleTokenMetadata) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
         wrapped_appchain_token.metadata = metadata;
         self.wrapped_appchain_token.set(&wrapped_appchain_token);
@@ -23,7 +23,7 @@ impl SudoActions for AppchainAnchor {
         premined_beneficiary: AccountId,
         premined_balance: U128,
     ) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         let mut wrapped_appchain_token = self.wrapped_appchain_token.get().unwrap();
         wrapped_appchain_token.premined_beneficiary = Some(premined_beneficiary);
         wrapped_appchain_token.premined_balance = premined_balance;
@@ -49,13 +49,13 @@ impl SudoActions for AppchainAnchor {
     }
     //
     fn reset_beefy_light_client(&mut self, initial_public_keys: Vec<String>) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         self.beefy_light_client_state
             .set(&beefy_light_client::new(initial_public_keys));
     }
     //
     fn pause_asset_transfer(&mut self) {
-        self.assert_owner();
+        self.assert_pauser();
         assert!(
             !self.asset_transfer_is_paused,
             "Asset transfer is already paused."
@@ -64,7 +64,7 @@ impl SudoActions for AppchainAnchor {
     }
     //
     fn resume_asset_transfer(&mut self) {
-        self.assert_owner();
+        self.assert_operator();
         assert!(
             self.asset_transfer_is_paused,
             "Asset transfer is already resumed."
@@ -73,7 +73,7 @@ impl SudoActions for AppchainAnchor {
     }
     //
     fn pause_rewards_withdrawal(&mut self) {
-        self.assert_owner();
+        self.assert_pauser();
         assert!(
             !self.rewards_withdrawal_is_paused,
             "Rewards withdrawal is already paused."
@@ -82,7 +82,7 @@ impl SudoActions for AppchainAnchor {
     }
     //
     fn resume_rewards_withdrawal(&mut self) {
-        self.assert_owner();
+        self.assert_operator();
         assert!(
             self.rewards_withdrawal_is_paused,
             "Rewards withdrawal is already resumed."
@@ -90,12 +90,30 @@ impl SudoActions for AppchainAnchor {
         self.rewards_withdrawal_is_paused = false;
     }
     //
+    fn pause_stake_withdrawal(&mut self) {
+        self.assert_pauser();
+        assert!(
+            !self.stake_withdrawal_is_paused,
+            "Stake withdrawal is already paused."
+        );
+        self.stake_withdrawal_is_paused = true;
+    }
+    //
+    fn resume_stake_withdrawal(&mut self) {
+        self.assert_operator();
+        assert!(
+            self.stake_withdrawal_is_paused,
+            "Stake withdrawal is already resumed."
+        );
+        self.stake_withdrawal_is_paused = false;
+    }
+    //
     fn change_account_id_in_appchain_of_validator(
         &mut self,
         validator_id: AccountId,
         account_id_in_appchain: String,
     ) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         self.internal_change_account_id_in_appchain_of_validator(
             &validator_id,
             &account_id_in_appchain,
@@ -103,7 +121,7 @@ impl SudoActions for AppchainAnchor {
     }
     //
     fn set_latest_applied_appchain_message_nonce(&mut self, nonce: u32) {
-        self.assert_owner();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
         let mut permissionless_actions_status = self.permissionless_actions_status.get().unwrap();
         permissionless_actions_status.latest_applied_appchain_message_nonce = nonce;
         permissionless_actions_status.processing_appchain_message_nonce = None;
@@ -117,8 +135,8 @@ impl SudoActions for AppchainAnchor {
         validator_id: AccountId,
         staking_history_index: U64,
     ) {
-        self.assert_owner();
-        self.assert_asset_transfer_is_not_paused();
+        self.assert_timelocked_call(&[AnchorRole::Owner]);
+        self.assert_stake_withdrawal_is_not_paused();
         let unbonded_stake_references = match delegator_id.clone() {
             Some(delegator_id) => self.unbonded_stakes.get(&delegator_id).unwrap(),
             None => self.unbonded_stakes.get(&validator_id).unwrap(),
@@ -1,4 +1,8 @@
-use crate::{interfaces::ValidatorActions, *};
+use crate::{
+    appchain_challenge::{GrandpaPublicKey, SignatureData},
+    interfaces::ValidatorActions,
+    *,
+};
 
 use borsh::maybestd::collections::HashMap;
 
@@ -23,9 +27,139 @@ impl ValidatorActions for AppchainAnchor {
         validator_profiles.insert(validator_profile);
         self.validator_profiles.set(&validator_profiles);
     }
+    //
+    fn set_validator_commission_percent(&mut self, commission_percent: u16) {
+        let validator_id = env::predecessor_account_id();
+        let next_validator_set = self.next_validator_set.get().unwrap();
+        self.assert_validator_id(&validator_id, &next_validator_set);
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        assert!(
+            commission_percent <= protocol_settings.maximum_validator_commission_percent,
+            "The commission percent should not be greater than {}.",
+            protocol_settings.maximum_validator_commission_percent
+        );
+        assert!(
+            commission_percent
+                != self.get_commission_percent_of_validator_in_next_era(&validator_id),
+            "The commission percent is not changed."
+        );
+        let validator_set_histories = self.validator_set_histories.get().unwrap();
+        let current_commission_percent = validator_set_histories
+            .get(&validator_set_histories.index_range().end_index.0)
+            .map_or(None, |validator_set| {
+                validator_set.get_commission_percent_of(&validator_id)
+            })
+            .unwrap_or(protocol_settings.validator_commission_percent);
+        assert!(
+            u16::max(commission_percent, current_commission_percent)
+                - u16::min(commission_percent, current_commission_percent)
+                <= protocol_settings.maximum_validator_commission_percent_change_per_era,
+            "The change of commission percent should not be greater than {} in an era.",
+            protocol_settings.maximum_validator_commission_percent_change_per_era
+        );
+        self.validator_commission_percents
+            .insert(&validator_id, &commission_percent);
+        log!(
+            "The commission percent of validator '{}' is set to '{}'. It will take effect in next era.",
+            validator_id,
+            commission_percent
+        );
+    }
+    //
+    fn set_grandpa_key_in_appchain(&mut self, grandpa_key: String, signature: String) {
+        let validator_id = env::predecessor_account_id();
+        let next_validator_set = self.next_validator_set.get().unwrap();
+        self.assert_validator_id(&validator_id, &next_validator_set);
+        let grandpa_key = grandpa_key.trim_start_matches("0x").to_lowercase();
+        let key_bytes: [u8; 32] = match hex::decode(&grandpa_key) {
+            Ok(bytes) => match bytes.try_into() {
+                Ok(key_bytes) => key_bytes,
+                Err(_) => panic!("Invalid GRANDPA key: '{}'.", grandpa_key),
+            },
+            Err(_) => panic!("Invalid GRANDPA key: '{}'.", grandpa_key),
+        };
+        let signature = match hex::decode(signature.trim_start_matches("0x")) {
+            Ok(bytes) => SignatureData(bytes),
+            Err(_) => panic!("Invalid signature: '{}'.", signature),
+        };
+        assert!(
+            GrandpaPublicKey(key_bytes).verify(
+                format!("{}:{}", env::current_account_id(), validator_id).as_bytes(),
+                &signature
+            ),
+            "The signature is not signed by GRANDPA key '{}'.",
+            grandpa_key
+        );
+        if let Some(bound_validator_id) = self.validator_ids_of_grandpa_keys.get(&grandpa_key) {
+            assert!(
+                bound_validator_id.eq(&validator_id),
+                "GRANDPA key '{}' is already bound to validator '{}'.",
+                grandpa_key,
+                bound_validator_id
+            );
+            panic!(
+                "GRANDPA key '{}' is already bound to the caller.",
+                grandpa_key
+            );
+        }
+        self.validator_ids_of_grandpa_keys
+            .insert(&grandpa_key, &validator_id);
+        log!(
+            "GRANDPA key '{}' is bound to validator '{}'.",
+            grandpa_key,
+            validator_id
+        );
+    }
+    //
+    fn unjail(&mut self) {
+        let validator_id = env::predecessor_account_id();
+        let next_validator_set = self.next_validator_set.get().unwrap();
+        self.assert_validator_id(&validator_id, &next_validator_set);
+        let jailed_era_number = match self.jailed_validators.get(&validator_id) {
+            Some(era_number) => era_number,
+            None => panic!("Validator '{}' is not jailed.", validator_id),
+        };
+        assert!(
+            !next_validator_set
+                .get_auto_unbonding_validator_ids()
+                .contains(&validator_id),
+            "Validator '{}' is being auto-unbonded.",
+            validator_id
+        );
+        let protocol_settings = self.protocol_settings.get().unwrap();
+        let unjail_era_number =
+            jailed_era_number + u64::from(protocol_settings.minimum_jailed_era_count_before_unjail);
+        let current_era_number = self
+            .validator_set_histories
+            .get()
+            .unwrap()
+            .index_range()
+            .end_index
+            .0;
+        assert!(
+            current_era_number >= unjail_era_number,
+            "Validator '{}' cannot be unjailed before era '{}'.",
+            validator_id,
+            unjail_era_number
+        );
+        self.jailed_validators.remove(&validator_id);
+        self.record_staking_fact(StakingFact::ValidatorUnjailed { validator_id });
+    }
 }
 
 impl AppchainAnchor {
+    ///
+    pub fn get_commission_percent_of_validator_in_next_era(&self, validator_id: &AccountId) -> u16 {
+        match self.validator_commission_percents.get(validator_id) {
+            Some(commission_percent) => commission_percent,
+            None => {
+                self.protocol_settings
+                    .get()
+                    .unwrap()
+                    .validator_commission_percent
+            }
+        }
+    }
     ///
     pub fn internal_change_account_id_in_appchain_of_validator(
         &mut self,
@@ -0,0 +1,201 @@
+use crate::{appchain_messages::RawMessage, interfaces::WitnessActions, *};
+use near_sdk::json_types::Base58CryptoHash;
+use parity_scale_codec::Decode;
+
+impl WitnessSubmissionStats {
+    ///
+    pub fn new(witness: AccountId) -> Self {
+        Self {
+            witness,
+            submission_count: U64::from(0),
+            agreed_count: U64::from(0),
+            disagreed_count: U64::from(0),
+            last_submitted_at: None,
+        }
+    }
+}
+
+impl AppchainAnchor {
+    ///
+    pub fn internal_get_witness_submission_stats_of(
+        &self,
+        witness: &AccountId,
+    ) -> WitnessSubmissionStats {
+        self.witness_submission_stats
+            .get(witness)
+            .unwrap_or(WitnessSubmissionStats::new(witness.clone()))
+    }
+    /// The count of witnesses, including the relayer account which holds role `Witness`
+    /// implicitly.
+    pub fn get_witness_count(&self) -> u16 {
+        let members = self
+            .role_members
+            .get(&AnchorRole::Witness)
+            .unwrap_or_default();
+        let implicit_count =
+            self.anchor_settings
+                .get()
+                .unwrap()
+                .relayer_account
+                .map_or(0, |relayer_account| {
+                    match members.contains(&relayer_account) {
+                        true => 0,
+                        false => 1,
+                    }
+                });
+        u16::try_from(members.len() + implicit_count).unwrap_or(u16::MAX)
+    }
+    /// Assert that the witness attestation threshold is not greater than the count of witnesses.
+    pub fn assert_witness_attestation_threshold_is_reachable(&self) {
+        let threshold = self
+            .protocol_settings
+            .get()
+            .unwrap()
+            .witness_attestation_threshold;
+        assert!(
+            threshold <= self.get_witness_count(),
+            "The witness attestation threshold {} is greater than the count of witnesses.",
+            threshold
+        );
+    }
+    // Remove the attestations of the appchain messages which are already staged.
+    fn prune_stale_witness_attestations(&mut self) {
+        let max_nonce_of_staged_appchain_messages = self
+            .permissionless_actions_status
+            .get()
+            .unwrap()
+            .max_nonce_of_staged_appchain_messages;
+        for start_nonce in self.witness_attested_nonces.to_vec() {
+            if start_nonce <= max_nonce_of_staged_appchain_messages {
+                self.witness_attestations.remove(&start_nonce);
+                self.witness_attested_nonces.remove(&start_nonce);
+            }
+        }
+    }
+    // Record the attestation of the caller for the appchain messages starting from a nonce.
+    // An attestation submitted before by the same witness will be replaced.
+    fn internal_attest_appchain_messages(
+        &mut self,
+        start_nonce: u32,
+        messages_hash: Base58CryptoHash,
+    ) {
+        assert!(
+            self.anchor_settings
+                .get()
+                .unwrap()
+                .beefy_light_client_witness_mode,
+            "Beefy light client is not in witness mode."
+        );
+        self.assert_any_role(&[AnchorRole::Witness]);
+        assert!(
+            start_nonce
+                > self
+                    .permissionless_actions_status
+                    .get()
+                    .unwrap()
+                    .max_nonce_of_staged_appchain_messages,
+            "The appchain messages of nonce '{}' are already staged.",
+            start_nonce
+        );
+        self.prune_stale_witness_attestations();
+        let witness = env::predecessor_account_id();
+        let mut attestations: Vec<WitnessAttestation> = self
+            .witness_attestations
+            .get(&start_nonce)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|attestation| !attestation.witness.eq(&witness))
+            .collect();
+        attestations
+            .iter()
+            .filter(|attestation| !attestation.messages_hash.eq(&messages_hash))
+            .for_each(|attestation| {
+                log!(
+                    "Witness '{}' disagrees with witness '{}' on appchain messages of nonce '{}'.",
+                    witness,
+                    attestation.witness,
+                    start_nonce
+                )
+            });
+        attestations.push(WitnessAttestation {
+            witness: witness.clone(),
+            start_nonce,
+            messages_hash,
+            submitted_at: U64::from(env::block_timestamp()),
+        });
+        self.witness_attestations
+            .insert(&start_nonce, &attestations);
+        self.witness_attested_nonces.insert(&start_nonce);
+        let mut stats = self.internal_get_witness_submission_stats_of(&witness);
+        stats.submission_count = U64::from(stats.submission_count.0 + 1);
+        stats.last_submitted_at = Some(U64::from(env::block_timestamp()));
+        self.witness_submission_stats.insert(&witness, &stats);
+    }
+    /// Check whether the encoded appchain messages are attested by enough witnesses.
+    ///
+    /// If the caller is a witness, the call is counted as its attestation. Once the threshold
+    /// is reached, the attestations of the messages are settled and the submission stats of
+    /// the witnesses are updated.
+    pub fn internal_check_witness_attestations(&mut self, encoded_messages: &Vec<u8>) -> bool {
+        let messages: Vec<RawMessage> = match Decode::decode(&mut &encoded_messages[..]) {
+            Ok(messages) => messages,
+            Err(err) => panic!("Failed to decode messages: {}", err),
+        };
+        assert!(messages.len() > 0, "There is no message data to be staged.");
+        let start_nonce = messages[0].nonce();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&env::sha256(encoded_messages));
+        let messages_hash = Base58CryptoHash::from(hash);
+        if self.has_role(&AnchorRole::Witness, &env::predecessor_account_id()) {
+            self.internal_attest_appchain_messages(start_nonce, messages_hash);
+        }
+        let attestations = self
+            .witness_attestations
+            .get(&start_nonce)
+            .unwrap_or_default();
+        let agreed_count = attestations
+            .iter()
+            .filter(|attestation| attestation.messages_hash.eq(&messages_hash))
+            .count();
+        let threshold = self
+            .protocol_settings
+            .get()
+            .unwrap()
+            .witness_attestation_threshold;
+        if agreed_count < usize::from(threshold) {
+            log!(
+                "Appchain messages of nonce '{}' are attested by {} of {} required witness(es).",
+                start_nonce,
+                agreed_count,
+                threshold
+            );
+            return false;
+        }
+        for attestation in attestations {
+            let mut stats = self.internal_get_witness_submission_stats_of(&attestation.witness);
+            if attestation.messages_hash.eq(&messages_hash) {
+                stats.agreed_count = U64::from(stats.agreed_count.0 + 1);
+            } else {
+                stats.disagreed_count = U64::from(stats.disagreed_count.0 + 1);
+                log!(
+                    "The attestation of witness '{}' on appchain messages of nonce '{}' is rejected.",
+                    attestation.witness,
+                    start_nonce
+                );
+            }
+            self.witness_submission_stats
+                .insert(&attestation.witness, &stats);
+        }
+        self.witness_attestations.remove(&start_nonce);
+        self.witness_attested_nonces.remove(&start_nonce);
+        true
+    }
+}
+
+#[near_bindgen]
+impl WitnessActions for AppchainAnchor {
+    //
+    fn attest_appchain_messages(&mut self, start_nonce: u32, messages_hash: Base58CryptoHash) {
+        self.internal_attest_appchain_messages(start_nonce, messages_hash);
+    }
+}
@@ -448,6 +448,36 @@ impl ValidatorSet {
                 new_validator.total_stake += old_delegator.deposit_amount;
                 self.validators.insert(&new_validator_id, &new_validator);
             }
+            StakingFact::ValidatorSlashed {
+                validator_id,
+                amount,
+            } => {
+                let mut validator = self.validators.get(validator_id).unwrap();
+                validator.deposit_amount -= amount.0;
+                validator.total_stake -= amount.0;
+                self.validators.insert(validator_id, &validator);
+                self.total_stake -= amount.0;
+            }
+            StakingFact::DelegatorSlashed {
+                delegator_id,
+                validator_id,
+                amount,
+            } => {
+                let mut delegator = self
+                    .delegators
+                    .get(&(delegator_id.clone(), validator_id.clone()))
+                    .unwrap();
+                delegator.deposit_amount -= amount.0;
+                self.delegators
+                    .insert(&(delegator_id.clone(), validator_id.clone()), &delegator);
+                let mut validator = self.validators.get(validator_id).unwrap();
+                validator.total_stake -= amount.0;
+                self.validators.insert(validator_id, &validator);
+                self.total_stake -= amount.0;
+            }
+            StakingFact::RewardBeneficiaryChanged { .. }
+            | StakingFact::ValidatorJailed { .. }
+            | StakingFact::ValidatorUnjailed { .. } => (),
         }
     }
 }
@@ -547,7 +577,12 @@ impl ValidatorSetViewer for ValidatorSet {
 
 impl AppchainValidator {
     ///
-    pub fn from_validator(validator: Validator, delegators_count: u64, is_unbonding: bool) -> Self {
+    pub fn from_validator(
+        validator: Validator,
+        delegators_count: u64,
+        is_unbonding: bool,
+        commission_percent: Option<u16>,
+    ) -> Self {
         Self {
             validator_id: validator.validator_id,
             validator_id_in_appchain: validator.validator_id_in_appchain,
@@ -556,6 +591,7 @@ impl AppchainValidator {
             delegators_count: U64::from(delegators_count),
             can_be_delegated_to: validator.can_be_delegated_to,
             is_unbonding,
+            commission_percent,
         }
     }
 }
@@ -85,6 +85,7 @@ impl NextValidatorSet {
                     self.validator_set.get_delegator_count_of(validator_id),
                     self.unbonding_validator_ids.contains(validator_id)
                         || self.auto_unbonding_validator_ids.contains(validator_id),
+                    None,
                 )
             })
             .collect()
@@ -3,6 +3,7 @@ use near_sdk::BlockHeight;
 use crate::*;
 
 use super::{Delegator, Validator, ValidatorSet};
+use crate::storage_migration::OldValidatorSetOfEra;
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct ValidatorSetOfEra {
@@ -24,6 +25,10 @@ pub struct ValidatorSetOfEra {
     delegator_rewards: LookupMap<(AccountId, AccountId), Balance>,
     /// The status of creation of this set
     processing_status: ValidatorSetProcessingStatus,
+    /// The snapshot of commission percent of validators in this era
+    validator_commission_percents: LookupMap<AccountId, u16>,
+    /// The set of validator id which are jailed in this era.
+    jailed_validator_id_set: UnorderedSet<AccountId>,
 }
 
 impl ValidatorSetOfEra {
@@ -48,6 +53,33 @@ impl ValidatorSetOfEra {
                 copying_validator_index: U64::from(0),
                 copying_delegator_index: U64::from(0),
             },
+            validator_commission_percents: LookupMap::new(
+                StorageKey::ValidatorCommissionPercentsOfEra(era_number).into_bytes(),
+            ),
+            jailed_validator_id_set: UnorderedSet::new(
+                StorageKey::JailedValidatorIdsOfEra(era_number).into_bytes(),
+            ),
+        }
+    }
+    ///
+    pub fn from_old_version(old_version: OldValidatorSetOfEra) -> Self {
+        let era_number = old_version.validator_set.era_number();
+        Self {
+            validator_set: old_version.validator_set,
+            start_block_height: old_version.start_block_height,
+            start_timestamp: old_version.start_timestamp,
+            staking_history_index: old_version.staking_history_index,
+            unprofitable_validator_id_set: old_version.unprofitable_validator_id_set,
+            valid_total_stake: old_version.valid_total_stake,
+            validator_rewards: old_version.validator_rewards,
+            delegator_rewards: old_version.delegator_rewards,
+            processing_status: old_version.processing_status,
+            validator_commission_percents: LookupMap::new(
+                StorageKey::ValidatorCommissionPercentsOfEra(era_number).into_bytes(),
+            ),
+            jailed_validator_id_set: UnorderedSet::new(
+                StorageKey::JailedValidatorIdsOfEra(era_number).into_bytes(),
+            ),
         }
     }
     ///
@@ -72,10 +104,22 @@ impl ValidatorSetOfEra {
             .get(&(delegator_id.clone(), validator_id.clone()))
     }
     ///
+    pub fn get_commission_percent_of(&self, validator_id: &AccountId) -> Option<u16> {
+        self.validator_commission_percents.get(validator_id)
+    }
+    ///
     pub fn unprofitable_validator_ids(&self) -> Vec<AccountId> {
         self.unprofitable_validator_id_set.to_vec()
     }
     ///
+    pub fn jailed_validator_ids(&self) -> Vec<AccountId> {
+        self.jailed_validator_id_set.to_vec()
+    }
+    ///
+    pub fn is_jailed(&self, validator_id: &AccountId) -> bool {
+        self.jailed_validator_id_set.contains(validator_id)
+    }
+    ///
     pub fn processing_status(&self) -> ValidatorSetProcessingStatus {
         self.processing_status.clone()
     }
@@ -88,6 +132,7 @@ impl ValidatorSetOfEra {
         match self.processing_status {
             ValidatorSetProcessingStatus::SyncingStakingAmountToCouncil
             | ValidatorSetProcessingStatus::ReadyForDistributingReward
+            | ValidatorSetProcessingStatus::SlashingOffenders { .. }
             | ValidatorSetProcessingStatus::DistributingReward { .. }
             | ValidatorSetProcessingStatus::CheckingForAutoUnbondingValidator { .. }
             | ValidatorSetProcessingStatus::Completed => true,
@@ -113,6 +158,12 @@ impl ValidatorSetOfEra {
         });
     }
     ///
+    pub fn set_jailed_validator_ids(&mut self, jailed_validator_ids: Vec<AccountId>) {
+        jailed_validator_ids.iter().for_each(|v_id| {
+            self.jailed_validator_id_set.insert(&v_id);
+        });
+    }
+    ///
     pub fn insert_validator(&mut self, validator: &Validator) {
         self.validator_set
             .validator_id_set
@@ -122,6 +173,11 @@ impl ValidatorSetOfEra {
             .insert(&validator.validator_id, &validator);
     }
     ///
+    pub fn set_commission_percent_of(&mut self, validator_id: &AccountId, commission_percent: u16) {
+        self.validator_commission_percents
+            .insert(validator_id, &commission_percent);
+    }
+    ///
     pub fn insert_delegator(&mut self, delegator: &Delegator) {
         let delegator_id = &delegator.delegator_id;
         let validator_id = &delegator.validator_id;
@@ -202,6 +258,14 @@ impl ValidatorSetOfEra {
             let validator = self.validator_set.validators.get(v_id).unwrap();
             self.valid_total_stake -= validator.total_stake;
         });
+        let jailed_validator_ids = self.jailed_validator_id_set.to_vec();
+        jailed_validator_ids.iter().for_each(|v_id| {
+            if !self.unprofitable_validator_id_set.contains(v_id) {
+                if let Some(validator) = self.validator_set.validators.get(v_id) {
+                    self.valid_total_stake -= validator.total_stake;
+                }
+            }
+        });
     }
     ///
     pub fn to_validator_set_info(&self) -> ValidatorSetInfo {
@@ -214,6 +278,7 @@ impl ValidatorSetOfEra {
             staking_history_index: U64::from(self.staking_history_index),
             unprofitable_validator_ids: self.unprofitable_validator_id_set.to_vec(),
             valid_total_stake: U128::from(self.valid_total_stake),
+            jailed_validator_ids: self.jailed_validator_id_set.to_vec(),
             processing_status: self.processing_status.clone(),
         }
     }
@@ -285,6 +350,7 @@ impl ValidatorSetOfEra {
                     .remove(&validator_id);
             }
             self.validator_rewards.remove(&validator_id);
+            self.validator_commission_percents.remove(&validator_id);
             self.validator_set.validators.remove(&validator_id);
             self.validator_set.validator_id_set.remove(&validator_id);
             if env::used_gas() > Gas::ONE_TERA.mul(T_GAS_CAP_FOR_MULTI_TXS_PROCESSING) {
@@ -294,6 +360,7 @@ impl ValidatorSetOfEra {
         self.validator_set.total_stake = 0;
         self.valid_total_stake = 0;
         self.unprofitable_validator_id_set.clear();
+        self.jailed_validator_id_set.clear();
         MultiTxsOperationProcessingResult::Ok
     }
     //
@@ -308,11 +375,13 @@ impl ValidatorSetOfEra {
         self.validator_set
             .get_validator_ids()
             .iter()
+            .filter(|validator_id| !self.jailed_validator_id_set.contains(validator_id))
             .map(|validator_id| {
                 AppchainValidator::from_validator(
                     self.validator_set.get_validator(validator_id).unwrap(),
                     self.validator_set.get_delegator_count_of(validator_id),
                     false,
+                    self.validator_commission_percents.get(validator_id),
                 )
             })
             .collect()
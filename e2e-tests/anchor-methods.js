@@ -1,7 +1,9 @@
 module.exports = {
   changeMethods: [
     'new',
-    'set_owner',
+    'propose_owner',
+    'cancel_owner_proposal',
+    'accept_ownership',
     // protocol settings
     'change_minimum_validator_deposit',
     'change_minimum_delegator_deposit',
@@ -40,6 +42,7 @@ module.exports = {
   ],
   viewMethods: [
     'get_owner',
+    'get_pending_owner',
     'get_anchor_settings',
     'get_appchain_settings',
     'get_protocol_settings',
@@ -1,11 +1,14 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::json_types::U128;
-use near_sdk::{env, near_bindgen, AccountId, Gas, PanicOnDefault, Promise};
+use near_sdk::{
+    env, near_bindgen, AccountId, Gas, PanicOnDefault, Promise, PromiseResult, PublicKey,
+};
 use std::ops::Mul;
 use std::str::FromStr;
 
 /// Constants for gas.
 const T_GAS_FOR_GENERATE_APPCHAIN_NOTIFICATION: u64 = 50;
+const T_GAS_FOR_RESOLVER_FUNCTION: u64 = 10;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
@@ -46,6 +49,11 @@ impl NativeNearTokenReceiver {
             env::attached_deposit() >= near_amount.0,
             "Attached deposit is not equal to the requested amount."
         );
+        let sender_id = env::predecessor_account_id();
+        // Refund the excess attached deposit to the sender.
+        if env::attached_deposit() > near_amount.0 {
+            Promise::new(sender_id.clone()).transfer(env::attached_deposit() - near_amount.0);
+        }
         //
         #[derive(near_sdk::serde::Serialize)]
         #[serde(crate = "near_sdk::serde")]
@@ -55,18 +63,52 @@ impl NativeNearTokenReceiver {
             amount: U128,
         }
         let args = Input {
-            sender_id_in_near: env::predecessor_account_id(),
+            sender_id_in_near: sender_id.clone(),
             receiver_id_in_appchain,
             amount: near_amount,
         };
         let args = near_sdk::serde_json::to_vec(&args)
             .expect("Failed to serialize the cross contract args using JSON.");
-        Promise::new(self.appchain_anchor_account.clone()).function_call(
-            "generate_appchain_notification_for_near_deposit".to_string(),
-            args,
-            0,
-            Gas::ONE_TERA.mul(T_GAS_FOR_GENERATE_APPCHAIN_NOTIFICATION),
-        );
+        #[derive(near_sdk::serde::Serialize)]
+        #[serde(crate = "near_sdk::serde")]
+        struct ResolverInput {
+            sender_id: AccountId,
+            amount: U128,
+        }
+        let resolver_args = ResolverInput {
+            sender_id,
+            amount: near_amount,
+        };
+        let resolver_args = near_sdk::serde_json::to_vec(&resolver_args)
+            .expect("Failed to serialize the cross contract args using JSON.");
+        Promise::new(self.appchain_anchor_account.clone())
+            .function_call(
+                "generate_appchain_notification_for_near_deposit".to_string(),
+                args,
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_GENERATE_APPCHAIN_NOTIFICATION),
+            )
+            .then(Promise::new(env::current_account_id()).function_call(
+                "resolve_near_deposit".to_string(),
+                resolver_args,
+                0,
+                Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION),
+            ));
+    }
+    /// Refund the deposit to the sender if it is rejected by appchain anchor.
+    #[private]
+    pub fn resolve_near_deposit(&mut self, sender_id: AccountId, amount: U128) {
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(value) => {
+                if !near_sdk::serde_json::from_slice::<bool>(&value).unwrap_or(true) {
+                    Promise::new(sender_id).transfer(amount.0);
+                }
+            }
+            PromiseResult::Failed => {
+                Promise::new(sender_id).transfer(amount.0);
+            }
+        }
     }
     ///
     pub fn unlock_near(&mut self, receiver_id: AccountId, amount: U128) {
@@ -77,4 +119,12 @@ impl NativeNearTokenReceiver {
         );
         Promise::new(receiver_id).transfer(amount.0);
     }
+    /// Replace the full access key of this contract account, when the owner public key
+    /// of appchain anchor is changed.
+    pub fn rotate_full_access_key(&mut self, old_public_key: PublicKey, new_public_key: PublicKey) {
+        self.assert_anchor();
+        Promise::new(env::current_account_id())
+            .delete_key(old_public_key)
+            .add_full_access_key(new_public_key);
+    }
 }
@@ -2,6 +2,7 @@ use appchain_anchor::types::AppchainTemplateType;
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::json_types::Base64VecU8;
 use near_sdk::json_types::U128;
+use near_sdk::json_types::U64;
 use near_sdk::serde_json::json;
 use near_units::parse_near;
 use workspaces::network::Sandbox;
@@ -163,6 +164,29 @@ pub async fn initialize_contracts_and_users(
                 .await
                 .unwrap()
                 .is_success());
+            // Disable the governance timelock for tests, by a call from the anchor itself.
+            assert!(appchain_anchor
+                .as_account()
+                .call(appchain_anchor.id(), "change_governance_timelock_delay")
+                .args_json(json!({ "value": U64::from(0) }))
+                .gas(300_000_000_000_000)
+                .transact()
+                .await
+                .unwrap()
+                .is_success());
+            // There is only one price maintainer in tests.
+            assert!(appchain_anchor
+                .as_account()
+                .call(
+                    appchain_anchor.id(),
+                    "change_minimum_price_submission_count"
+                )
+                .args_json(json!({ "value": 1 }))
+                .gas(300_000_000_000_000)
+                .transact()
+                .await
+                .unwrap()
+                .is_success());
         }
     };
     //
@@ -567,13 +567,22 @@ pub async fn test_normal_actions(
             .is_success()
     );
     assert!(
-        settings_manager::turn_on_beefy_light_client_witness_mode(&root, &anchor)
+        settings_manager::set_relayer_account(&root, &anchor, &users[5])
             .await
             .unwrap()
             .is_success()
     );
+    if !with_old_anchor {
+        // The relayer account is the only witness.
+        assert!(
+            settings_manager::change_witness_attestation_threshold(&root, &anchor, 1)
+                .await
+                .unwrap()
+                .is_success()
+        );
+    }
     assert!(
-        settings_manager::set_relayer_account(&root, &anchor, &users[5])
+        settings_manager::turn_on_beefy_light_client_witness_mode(&root, &anchor)
             .await
             .unwrap()
             .is_success()
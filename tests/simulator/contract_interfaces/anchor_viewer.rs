@@ -1,13 +1,16 @@
 use appchain_anchor::appchain_challenge::AppchainChallenge;
 use appchain_anchor::types::{
-    AnchorSettings, AnchorStatus, AppchainDelegator, AppchainMessageProcessingResult,
-    AppchainNotificationHistory, AppchainSettings, AppchainState, AppchainValidator,
-    BeefyLightClientStatus, IndexRange, NativeNearToken, NearFungibleToken, RewardHistory,
-    StakingHistory, UnbondedStake, UserStakingHistory, ValidatorProfile, ValidatorSetInfo,
+    AccruedBridgeFee, AnchorSettings, AnchorStatus, AppchainChallengeStatus, AppchainDelegator,
+    AppchainMessageProcessingResult, AppchainNotificationHistory, AppchainSettings, AppchainState,
+    AppchainValidator, AssetPriceStatus, BeefyLightClientStatus, BridgeFee, BridgingAsset,
+    CollateralizationStatus, FailedWithdrawal, GovernanceProposal, IndexRange, NativeNearToken,
+    NearFungibleToken, PricedAsset, RelayerStats, RetryableAppchainMessage, RewardHistory,
+    StakingHistory, StorageFund, UnbondedStake, UserStakingHistory, ValidatorProfile,
+    ValidatorSetInfo, WitnessAttestation, WitnessSubmissionStats, WrappedAppchainNFT,
     WrappedAppchainToken,
 };
 use appchain_anchor::AppchainMessage;
-use near_sdk::json_types::U64;
+use near_sdk::json_types::{U128, U64};
 use near_sdk::serde_json::json;
 use near_sdk::AccountId;
 use workspaces::{error::Error, Account, Contract};
@@ -328,3 +331,229 @@ pub async fn get_appchain_challenges(
         .await?
         .json::<Vec<AppchainChallenge>>()
 }
+
+pub async fn get_appchain_challenge_status(
+    anchor: &Contract,
+    index: u64,
+) -> Result<Option<AppchainChallengeStatus>, Error> {
+    anchor
+        .call("get_appchain_challenge_status")
+        .args_json(json!({ "index": U64::from(index) }))
+        .view()
+        .await?
+        .json::<Option<AppchainChallengeStatus>>()
+}
+
+pub async fn get_validator_deposit_of(
+    anchor: &Contract,
+    validator: &Account,
+    era_number: Option<u64>,
+) -> Result<U128, Error> {
+    anchor
+        .call("get_validator_deposit_of")
+        .args_json(json!({
+            "validator_id": validator.id(),
+            "era_number": era_number.map(U64::from)
+        }))
+        .view()
+        .await?
+        .json::<U128>()
+}
+
+pub async fn get_delegator_deposit_of(
+    anchor: &Contract,
+    delegator: &Account,
+    validator: &Account,
+    era_number: Option<u64>,
+) -> Result<U128, Error> {
+    anchor
+        .call("get_delegator_deposit_of")
+        .args_json(json!({
+            "delegator_id": delegator.id(),
+            "validator_id": validator.id(),
+            "era_number": era_number.map(U64::from)
+        }))
+        .view()
+        .await?
+        .json::<U128>()
+}
+
+pub async fn get_pending_slashed_oct_token(anchor: &Contract) -> Result<U128, Error> {
+    anchor
+        .call("get_pending_slashed_oct_token")
+        .view()
+        .await?
+        .json::<U128>()
+}
+
+pub async fn get_era_number_of_grandpa_set_id(
+    anchor: &Contract,
+    set_id: u32,
+) -> Result<Option<U64>, Error> {
+    anchor
+        .call("get_era_number_of_grandpa_set_id")
+        .args_json(json!({ "set_id": set_id }))
+        .view()
+        .await?
+        .json::<Option<U64>>()
+}
+
+pub async fn get_price_reporters(anchor: &Contract) -> Result<Vec<AccountId>, Error> {
+    anchor
+        .call("get_price_reporters")
+        .view()
+        .await?
+        .json::<Vec<AccountId>>()
+}
+
+pub async fn get_price_status_of(
+    anchor: &Contract,
+    asset: PricedAsset,
+) -> Result<AssetPriceStatus, Error> {
+    anchor
+        .call("get_price_status_of")
+        .args_json(json!({ "asset": asset }))
+        .view()
+        .await?
+        .json::<AssetPriceStatus>()
+}
+
+pub async fn get_failed_withdrawals_of(
+    anchor: &Contract,
+    user: &Account,
+) -> Result<Vec<FailedWithdrawal>, Error> {
+    anchor
+        .call("get_failed_withdrawals_of")
+        .args_json(json!({ "account_id": user.id() }))
+        .view()
+        .await?
+        .json::<Vec<FailedWithdrawal>>()
+}
+
+pub async fn get_bridge_fee_of(
+    anchor: &Contract,
+    asset: BridgingAsset,
+) -> Result<Option<BridgeFee>, Error> {
+    anchor
+        .call("get_bridge_fee_of")
+        .args_json(json!({ "asset": asset }))
+        .view()
+        .await?
+        .json::<Option<BridgeFee>>()
+}
+
+pub async fn get_accrued_bridge_fees(anchor: &Contract) -> Result<Vec<AccruedBridgeFee>, Error> {
+    anchor
+        .call("get_accrued_bridge_fees")
+        .view()
+        .await?
+        .json::<Vec<AccruedBridgeFee>>()
+}
+
+pub async fn get_retryable_appchain_messages(
+    anchor: &Contract,
+) -> Result<Vec<RetryableAppchainMessage>, Error> {
+    anchor
+        .call("get_retryable_appchain_messages")
+        .view()
+        .await?
+        .json::<Vec<RetryableAppchainMessage>>()
+}
+
+pub async fn get_processing_result_history_of_appchain_message(
+    anchor: &Contract,
+    nonce: u32,
+) -> Result<Vec<AppchainMessageProcessingResult>, Error> {
+    anchor
+        .call("get_processing_result_history_of_appchain_message")
+        .args_json(json!({ "nonce": nonce }))
+        .view()
+        .await?
+        .json::<Vec<AppchainMessageProcessingResult>>()
+}
+
+pub async fn get_storage_fund(anchor: &Contract) -> Result<StorageFund, Error> {
+    anchor
+        .call("get_storage_fund")
+        .view()
+        .await?
+        .json::<StorageFund>()
+}
+
+pub async fn get_storage_deposit_budget_of(
+    anchor: &Contract,
+    asset: BridgingAsset,
+) -> Result<Option<U128>, Error> {
+    anchor
+        .call("get_storage_deposit_budget_of")
+        .args_json(json!({ "asset": asset }))
+        .view()
+        .await?
+        .json::<Option<U128>>()
+}
+
+pub async fn get_wrapped_appchain_nfts(
+    anchor: &Contract,
+) -> Result<Vec<WrappedAppchainNFT>, Error> {
+    anchor
+        .call("get_wrapped_appchain_nfts")
+        .view()
+        .await?
+        .json::<Vec<WrappedAppchainNFT>>()
+}
+
+pub async fn get_collateralization_status(
+    anchor: &Contract,
+) -> Result<CollateralizationStatus, Error> {
+    anchor
+        .call("get_collateralization_status")
+        .view()
+        .await?
+        .json::<CollateralizationStatus>()
+}
+
+pub async fn get_governance_proposal(
+    anchor: &Contract,
+    index: u64,
+) -> Result<Option<GovernanceProposal>, Error> {
+    anchor
+        .call("get_governance_proposal")
+        .args_json(json!({ "index": U64::from(index) }))
+        .view()
+        .await?
+        .json::<Option<GovernanceProposal>>()
+}
+
+pub async fn get_witness_attestations_of(
+    anchor: &Contract,
+    start_nonce: u32,
+) -> Result<Vec<WitnessAttestation>, Error> {
+    anchor
+        .call("get_witness_attestations_of")
+        .args_json(json!({ "start_nonce": start_nonce }))
+        .view()
+        .await?
+        .json::<Vec<WitnessAttestation>>()
+}
+
+pub async fn get_witness_submission_stats(
+    anchor: &Contract,
+) -> Result<Vec<WitnessSubmissionStats>, Error> {
+    anchor
+        .call("get_witness_submission_stats")
+        .view()
+        .await?
+        .json::<Vec<WitnessSubmissionStats>>()
+}
+
+pub async fn get_relayer_stats_of(
+    anchor: &Contract,
+    relayer: &Account,
+) -> Result<Option<RelayerStats>, Error> {
+    anchor
+        .call("get_relayer_stats_of")
+        .args_json(json!({ "relayer": relayer.id() }))
+        .view()
+        .await?
+        .json::<Option<RelayerStats>>()
+}
@@ -0,0 +1,33 @@
+use appchain_anchor::types::{BridgeFee, BridgingAsset};
+use near_sdk::serde_json::json;
+use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
+
+pub async fn set_bridge_fee(
+    signer: &Account,
+    anchor: &Contract,
+    asset: BridgingAsset,
+    fee: Option<BridgeFee>,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_bridge_fee")
+        .args_json(json!({
+            "asset": asset,
+            "fee": fee
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn claim_bridge_fees(
+    signer: &Account,
+    anchor: &Contract,
+    asset: BridgingAsset,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "claim_bridge_fees")
+        .args_json(json!({ "asset": asset }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
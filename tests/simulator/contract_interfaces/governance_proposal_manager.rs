@@ -0,0 +1,45 @@
+use near_sdk::{json_types::U64, serde_json::json};
+use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
+
+pub async fn propose_governance_action(
+    signer: &Account,
+    anchor: &Contract,
+    method_name: &str,
+    args: String,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "propose_governance_action")
+        .args_json(json!({
+            "method_name": method_name,
+            "args": args
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn cancel_governance_proposal(
+    signer: &Account,
+    anchor: &Contract,
+    index: u64,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "cancel_governance_proposal")
+        .args_json(json!({ "index": U64::from(index) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn execute_governance_proposal(
+    signer: &Account,
+    anchor: &Contract,
+    index: u64,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "execute_governance_proposal")
+        .args_json(json!({ "index": U64::from(index) }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await
+}
@@ -32,3 +32,27 @@ pub async fn initialize_beefy_light_client(
         .transact()
         .await
 }
+
+pub async fn start_closing(
+    signer: &Account,
+    anchor: &Contract,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "start_closing")
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn complete_closing(
+    signer: &Account,
+    anchor: &Contract,
+    skip_reward_of_last_era: Option<bool>,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "complete_closing")
+        .args_json(json!({ "skip_reward_of_last_era": skip_reward_of_last_era }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await
+}
@@ -1,10 +1,15 @@
 pub mod anchor_viewer;
+pub mod bridge_fee_manager;
+pub mod governance_proposal_manager;
 pub mod lifecycle_actions;
 pub mod native_near_token;
 pub mod near_fungible_token_manager;
 pub mod permissionless_actions;
+pub mod price_oracle_manager;
 pub mod settings_manager;
 pub mod staking_actions;
+pub mod storage_fund_manager;
+pub mod sudo_actions;
 pub mod validator_actions;
 pub mod wrapped_appchain_nft_manager;
 pub mod wrapped_appchain_token_manager;
@@ -23,3 +23,33 @@ pub async fn register_near_fungible_token(
         .transact()
         .await
 }
+
+pub async fn set_price_of_near_fungible_token(
+    signer: &Account,
+    anchor: &Contract,
+    symbol: String,
+    price: U128,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_price_of_near_fungible_token")
+        .args_json(json!({
+            "symbol": symbol,
+            "price": price
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn open_bridging_of_near_fungible_token(
+    signer: &Account,
+    anchor: &Contract,
+    symbol: String,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "open_bridging_of_near_fungible_token")
+        .args_json(json!({ "symbol": symbol }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -1,5 +1,6 @@
+use appchain_anchor::appchain_challenge::AppchainChallenge;
 use appchain_anchor::types::{MultiTxsOperationProcessingResult, ValidatorMerkleProof};
-use near_sdk::serde_json::json;
+use near_sdk::{json_types::U64, serde_json::json};
 use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
 
 pub async fn verify_and_stage_appchain_messages(
@@ -71,3 +72,45 @@ pub async fn process_appchain_messages_with_all_proofs(
     println!();
     result.json::<MultiTxsOperationProcessingResult>()
 }
+
+pub async fn retry_appchain_message(
+    signer: &Account,
+    anchor: &Contract,
+    nonce: u32,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "retry_appchain_message")
+        .args_json(json!({ "nonce": nonce }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn commit_appchain_challenge(
+    signer: &Account,
+    anchor: &Contract,
+    appchain_challenge: &AppchainChallenge,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "commit_appchain_challenge")
+        .args_json(json!({ "appchain_challenge": appchain_challenge }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn process_appchain_challenge(
+    signer: &Account,
+    anchor: &Contract,
+    index: u64,
+) -> Result<MultiTxsOperationProcessingResult, Error> {
+    let result = signer
+        .call(anchor.id(), "process_appchain_challenge")
+        .args_json(json!({ "index": U64::from(index) }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await?;
+    println!("{:?}", result);
+    println!();
+    result.json::<MultiTxsOperationProcessingResult>()
+}
@@ -0,0 +1,32 @@
+use near_sdk::serde_json::json;
+use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
+
+pub async fn add_price_reporter(
+    signer: &Account,
+    anchor: &Contract,
+    account: &Account,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "add_price_reporter")
+        .args_json(json!({
+            "account_id": account.id()
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn remove_price_reporter(
+    signer: &Account,
+    anchor: &Contract,
+    account: &Account,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "remove_price_reporter")
+        .args_json(json!({
+            "account_id": account.id()
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -69,6 +69,19 @@ pub async fn set_bonus_for_new_validator(
         .await
 }
 
+pub async fn set_bounty_for_appchain_challenge(
+    signer: &Account,
+    anchor: &Contract,
+    value: u128,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_bounty_for_appchain_challenge")
+        .args_json(json!({ "bounty_amount": U128::from(value) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
 pub async fn set_token_price_maintainer_account(
     signer: &Account,
     anchor: &Contract,
@@ -99,6 +112,40 @@ pub async fn set_relayer_account(
         .await
 }
 
+pub async fn grant_role(
+    signer: &Account,
+    anchor: &Contract,
+    role: &str,
+    account: &Account,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "grant_role")
+        .args_json(json!({
+            "role": role,
+            "account_id": account.id()
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn revoke_role(
+    signer: &Account,
+    anchor: &Contract,
+    role: &str,
+    account: &Account,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "revoke_role")
+        .args_json(json!({
+            "role": role,
+            "account_id": account.id()
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
 pub async fn set_price_of_oct_token(
     signer: &Account,
     anchor: &Contract,
@@ -138,6 +185,48 @@ pub async fn change_unlock_period_of_delegator_deposit(
         .await
 }
 
+pub async fn change_minimum_price_submission_count(
+    signer: &Account,
+    anchor: &Contract,
+    value: u16,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "change_minimum_price_submission_count")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_maximum_market_value_percent_of_wrapped_appchain_token(
+    signer: &Account,
+    anchor: &Contract,
+    value: u16,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(
+            anchor.id(),
+            "change_maximum_market_value_percent_of_wrapped_appchain_token",
+        )
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_witness_attestation_threshold(
+    signer: &Account,
+    anchor: &Contract,
+    value: u16,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "change_witness_attestation_threshold")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
 pub async fn turn_on_beefy_light_client_witness_mode(
     signer: &Account,
     anchor: &Contract,
@@ -148,3 +237,59 @@ pub async fn turn_on_beefy_light_client_witness_mode(
         .transact()
         .await
 }
+
+pub async fn change_governance_timelock_delay(
+    signer: &Account,
+    anchor: &Contract,
+    value: u64,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "change_governance_timelock_delay")
+        .args_json(json!({ "value": U64::from(value) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn set_relayer_reward_per_era(
+    signer: &Account,
+    anchor: &Contract,
+    value: u128,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_relayer_reward_per_era")
+        .args_json(json!({ "reward_amount": U128::from(value) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn set_treasury_account(
+    signer: &Account,
+    anchor: &Contract,
+    account: &Account,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_treasury_account")
+        .args_json(json!({ "account_id": account.id() }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_slash_percent_per_offence_of_kind(
+    signer: &Account,
+    anchor: &Contract,
+    kind: &str,
+    value: u16,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "change_slash_percent_per_offence_of_kind")
+        .args_json(json!({
+            "kind": kind,
+            "value": value
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -217,3 +217,40 @@ pub async fn change_delegated_validator(
     println!("Result of 'change_delegated_validator': {:?}", result);
     result
 }
+
+pub async fn set_reward_beneficiary(
+    signer: &Account,
+    anchor: &Contract,
+    beneficiary_id: Option<&AccountId>,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_reward_beneficiary")
+        .args_json(json!({ "beneficiary_id": beneficiary_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn claim_failed_withdrawals(
+    signer: &Account,
+    anchor: &Contract,
+    account_id: &AccountId,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "claim_failed_withdrawals")
+        .args_json(json!({ "account_id": account_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn withdraw_relayer_rewards(
+    signer: &Account,
+    anchor: &Contract,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "withdraw_relayer_rewards")
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
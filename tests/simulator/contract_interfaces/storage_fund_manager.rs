@@ -0,0 +1,33 @@
+use appchain_anchor::types::BridgingAsset;
+use near_sdk::{json_types::U128, serde_json::json};
+use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
+
+pub async fn set_storage_deposit_budget(
+    signer: &Account,
+    anchor: &Contract,
+    asset: BridgingAsset,
+    budget: Option<U128>,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_storage_deposit_budget")
+        .args_json(json!({
+            "asset": asset,
+            "budget": budget
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn deposit_to_storage_fund(
+    signer: &Account,
+    anchor: &Contract,
+    amount: u128,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "deposit_to_storage_fund")
+        .gas(200_000_000_000_000)
+        .deposit(amount)
+        .transact()
+        .await
+}
@@ -0,0 +1,23 @@
+use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
+
+pub async fn pause_asset_transfer(
+    signer: &Account,
+    anchor: &Contract,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "pause_asset_transfer")
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn resume_asset_transfer(
+    signer: &Account,
+    anchor: &Contract,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "resume_asset_transfer")
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -51,3 +51,20 @@ pub async fn disable_delegation(
         .transact()
         .await
 }
+
+pub async fn set_grandpa_key_in_appchain(
+    signer: &Account,
+    anchor: &Contract,
+    grandpa_key: &str,
+    signature: &str,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_grandpa_key_in_appchain")
+        .args_json(json!({
+            "grandpa_key": grandpa_key,
+            "signature": signature
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
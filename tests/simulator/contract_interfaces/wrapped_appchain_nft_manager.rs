@@ -1,5 +1,5 @@
 use near_contract_standards::non_fungible_token::metadata::NFTContractMetadata;
-use near_sdk::serde_json::json;
+use near_sdk::{json_types::U128, serde_json::json};
 use workspaces::{error::Error, result::ExecutionFinalResult, Account, Contract};
 
 pub async fn register_wrapped_appchain_nft(
@@ -31,3 +31,20 @@ pub async fn open_bridging_of_wrapped_appchain_nft(
         .transact()
         .await
 }
+
+pub async fn set_floor_price_of_wrapped_appchain_nft(
+    signer: &Account,
+    anchor: &Contract,
+    class_id: String,
+    price: U128,
+) -> Result<ExecutionFinalResult, Error> {
+    signer
+        .call(anchor.id(), "set_floor_price_of_wrapped_appchain_nft")
+        .args_json(json!({
+            "class_id": class_id,
+            "price": price
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -2,11 +2,23 @@ mod common;
 mod contract_interfaces;
 
 mod test_anchor_actions;
+mod test_appchain_challenges;
 mod test_beefy_light_client_1;
+mod test_bridge_fees;
+mod test_closing;
+mod test_collateralization;
 mod test_equivocation_challenge;
+mod test_failed_withdrawals;
+mod test_governance_proposals;
 mod test_migration;
+mod test_price_oracle;
+mod test_relayer_rewards;
+mod test_retrying_appchain_messages;
+mod test_slashing;
+mod test_storage_fund;
 mod test_sync_staking_amount;
 mod test_transfer_native_near;
 mod test_transfer_nft;
 mod test_transfer_oct_to_appchain;
+mod test_witness_attestations;
 mod test_wrapped_appchain_token;
@@ -0,0 +1,266 @@
+use crate::{
+    common::{self, to_actual_amount},
+    contract_interfaces::{
+        anchor_viewer, permissionless_actions, settings_manager, validator_actions,
+    },
+};
+use appchain_anchor::{
+    appchain_challenge::AppchainChallenge,
+    types::{AppchainChallengeStatus, MultiTxsOperationProcessingResult},
+};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use near_sdk::serde_json::{self, json};
+use parity_scale_codec::Encode;
+use workspaces::{Account, Contract};
+
+fn grandpa_keypair_of(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn sign_grandpa_prevote(
+    keypair: &Keypair,
+    target_hash: [u8; 32],
+    target_number: u32,
+    round_number: u32,
+    set_id: u32,
+) -> Vec<u8> {
+    // The encoded `(GrandpaMessage::Prevote, round, set_id)`, which is signed in appchain.
+    let message = (
+        0u8,
+        target_hash,
+        target_number,
+        u64::from(round_number),
+        u64::from(set_id),
+    )
+        .encode();
+    keypair.sign(&message).to_bytes().to_vec()
+}
+
+fn equivocation_challenge_of(
+    submitter: &Account,
+    keypair: &Keypair,
+    set_id: u32,
+) -> AppchainChallenge {
+    let round_number = 2;
+    let first_target_hash = [1u8; 32];
+    let second_target_hash = [2u8; 32];
+    let first_signature = sign_grandpa_prevote(keypair, first_target_hash, 2, round_number, set_id);
+    let second_signature =
+        sign_grandpa_prevote(keypair, second_target_hash, 1, round_number, set_id);
+    serde_json::from_value(json!({
+        "EquivocationChallenge": {
+            "submitter_account": submitter.id(),
+            "proof": {
+                "set_id": set_id,
+                "equivocation": {
+                    "Prevote": {
+                        "round_number": round_number,
+                        "identity": keypair.public.to_bytes(),
+                        "first": [
+                            { "target_hash": first_target_hash, "target_number": 2 },
+                            first_signature,
+                        ],
+                        "second": [
+                            { "target_hash": second_target_hash, "target_number": 1 },
+                            second_signature,
+                        ]
+                    }
+                }
+            }
+        }
+    }))
+    .unwrap()
+}
+
+async fn process_appchain_challenge_till_done(signer: &Account, anchor: &Contract, index: u64) {
+    loop {
+        match permissionless_actions::process_appchain_challenge(signer, anchor, index)
+            .await
+            .unwrap()
+        {
+            MultiTxsOperationProcessingResult::Ok => break,
+            MultiTxsOperationProcessingResult::NeedMoreGas => (),
+            MultiTxsOperationProcessingResult::Error(message) => {
+                panic!("Failed to process appchain challenge: {}", &message);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_appchain_challenges() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        _,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let bound_keypair = grandpa_keypair_of(7);
+    let unbound_keypair = grandpa_keypair_of(8);
+    let bound_key = hex::encode(bound_keypair.public.to_bytes());
+    //
+    // Set the slash percent of equivocation, the treasury account and the bounty
+    //
+    assert!(settings_manager::change_slash_percent_per_offence_of_kind(
+        &root,
+        &anchor,
+        "grandpa:equivoca",
+        10
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        settings_manager::set_treasury_account(&root, &anchor, &users[5])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert!(settings_manager::set_bounty_for_appchain_challenge(
+        &root,
+        &anchor,
+        to_actual_amount(1, 18)
+    )
+    .await
+    .unwrap()
+    .is_success());
+    //
+    // The era number of GRANDPA set id of era0 is derived when the appchain goes live
+    //
+    assert_eq!(
+        anchor_viewer::get_era_number_of_grandpa_set_id(&anchor, 0)
+            .await?
+            .unwrap()
+            .0,
+        0
+    );
+    //
+    // Bind the GRANDPA key with the signature of the key
+    //
+    let signature_for_user0 = hex::encode(
+        bound_keypair
+            .sign(format!("{}:{}", anchor.id(), users[0].id()).as_bytes())
+            .to_bytes(),
+    );
+    let signature_for_user1 = hex::encode(
+        bound_keypair
+            .sign(format!("{}:{}", anchor.id(), users[1].id()).as_bytes())
+            .to_bytes(),
+    );
+    assert!(validator_actions::set_grandpa_key_in_appchain(
+        &users[1],
+        &anchor,
+        &bound_key,
+        &signature_for_user0
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(validator_actions::set_grandpa_key_in_appchain(
+        &users[0],
+        &anchor,
+        &bound_key,
+        &signature_for_user0
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(validator_actions::set_grandpa_key_in_appchain(
+        &users[1],
+        &anchor,
+        &bound_key,
+        &signature_for_user1
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    //
+    // Commit the equivocation challenges of the bound key and the unbound key
+    //
+    let challenge_of_bound_key = equivocation_challenge_of(&users[1], &bound_keypair, 0);
+    assert!(permissionless_actions::commit_appchain_challenge(
+        &users[1],
+        &anchor,
+        &challenge_of_bound_key
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(permissionless_actions::commit_appchain_challenge(
+        &users[1],
+        &anchor,
+        &challenge_of_bound_key
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(permissionless_actions::commit_appchain_challenge(
+        &users[1],
+        &anchor,
+        &equivocation_challenge_of(&users[1], &unbound_keypair, 0)
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(matches!(
+        anchor_viewer::get_appchain_challenge_status(&anchor, 0).await?,
+        Some(AppchainChallengeStatus::Pending)
+    ));
+    //
+    // The offender of the bound key is penalized and the bounty is minted
+    //
+    let validator_deposit =
+        anchor_viewer::get_validator_deposit_of(&anchor, &users[0], None).await?;
+    let user2_deposit =
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[2], &users[0], None).await?;
+    let user3_deposit =
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[3], &users[0], None).await?;
+    let treasury_balance = common::get_ft_balance_of(&users[5], &oct_token).await?;
+    let submitter_balance = common::get_ft_balance_of(&users[1], &wrapped_appchain_token).await?;
+    process_appchain_challenge_till_done(&users[5], &anchor, 0).await;
+    let slashed_amount = validator_deposit.0 / 10 + user2_deposit.0 / 10 + user3_deposit.0 / 10;
+    match anchor_viewer::get_appchain_challenge_status(&anchor, 0).await? {
+        Some(AppchainChallengeStatus::Penalized {
+            offender_id,
+            slashed_amount: amount,
+        }) => {
+            assert_eq!(offender_id.to_string(), users[0].id().to_string());
+            assert_eq!(amount.0, slashed_amount);
+        }
+        _ => panic!("Appchain challenge 0 should be penalized."),
+    }
+    assert_eq!(
+        anchor_viewer::get_validator_deposit_of(&anchor, &users[0], None)
+            .await?
+            .0,
+        validator_deposit.0 - validator_deposit.0 / 10
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[5], &oct_token).await?.0,
+        treasury_balance.0 + slashed_amount
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        submitter_balance.0 + to_actual_amount(1, 18)
+    );
+    //
+    // The challenge of the unbound key is rejected
+    //
+    process_appchain_challenge_till_done(&users[5], &anchor, 1).await;
+    assert!(matches!(
+        anchor_viewer::get_appchain_challenge_status(&anchor, 1).await?,
+        Some(AppchainChallengeStatus::Rejected { .. })
+    ));
+    //
+    Ok(())
+}
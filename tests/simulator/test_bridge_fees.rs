@@ -0,0 +1,237 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        anchor_viewer, bridge_fee_manager, permissionless_actions, wrapped_appchain_token_manager,
+    },
+};
+use appchain_anchor::{
+    appchain_messages::{LockPayload, PayloadType, RawMessage},
+    types::{AppchainNotification, BridgeFee, BridgingAsset},
+};
+use near_sdk::{borsh::BorshSerialize, json_types::U128, serde_json::json};
+use parity_scale_codec::Encode;
+use workspaces::Contract;
+
+#[tokio::test]
+async fn test_bridge_fees() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let receiver_id_in_appchain =
+        "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d".to_string();
+    let user4_id_in_appchain =
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da273".to_string();
+    //
+    // Set bridge fees
+    //
+    assert!(bridge_fee_manager::set_bridge_fee(
+        &users[1],
+        &anchor,
+        BridgingAsset::WrappedAppchainToken,
+        Some(BridgeFee::BasisPoints(100)),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(bridge_fee_manager::set_bridge_fee(
+        &root,
+        &anchor,
+        BridgingAsset::WrappedAppchainToken,
+        Some(BridgeFee::BasisPoints(10_000)),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(bridge_fee_manager::set_bridge_fee(
+        &root,
+        &anchor,
+        BridgingAsset::WrappedAppchainNFT {
+            class_id: "1".to_string()
+        },
+        Some(BridgeFee::BasisPoints(100)),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(bridge_fee_manager::set_bridge_fee(
+        &root,
+        &anchor,
+        BridgingAsset::WrappedAppchainToken,
+        Some(BridgeFee::BasisPoints(100)),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        anchor_viewer::get_bridge_fee_of(&anchor, BridgingAsset::WrappedAppchainToken).await?,
+        Some(BridgeFee::BasisPoints(100))
+    );
+    //
+    // Mint wrapped appchain token for user1
+    //
+    appchain_message_nonce += 1;
+    let payload = LockPayload {
+        sender: user4_id_in_appchain.clone(),
+        receiver_id: users[1].id().to_string().parse().unwrap(),
+        amount: common::to_actual_amount(60, 18),
+        fee: common::to_actual_amount(1, 18),
+    };
+    let raw_message = RawMessage {
+        nonce: appchain_message_nonce as u64,
+        payload_type: PayloadType::Lock,
+        payload: payload.try_to_vec().unwrap(),
+    };
+    let mut raw_messages = Vec::new();
+    raw_messages.push(raw_message);
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        common::to_actual_amount(60, 18)
+    );
+    //
+    // The fee is charged on the burning by user1
+    //
+    assert!(wrapped_appchain_token_manager::burn_wrapped_appchain_token(
+        &users[1],
+        &anchor,
+        receiver_id_in_appchain.clone(),
+        common::to_actual_amount(50, 18),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    let fee = common::to_actual_amount(50, 18) / 100;
+    assert_latest_burnt_notification(&anchor, common::to_actual_amount(50, 18) - fee, fee).await;
+    let accrued_bridge_fees = anchor_viewer::get_accrued_bridge_fees(&anchor).await?;
+    assert_eq!(accrued_bridge_fees.len(), 1);
+    assert_eq!(
+        accrued_bridge_fees[0].asset,
+        BridgingAsset::WrappedAppchainToken
+    );
+    assert_eq!(
+        accrued_bridge_fees[0].fee,
+        Some(BridgeFee::BasisPoints(100))
+    );
+    assert_eq!(accrued_bridge_fees[0].accrued_amount.0, fee);
+    //
+    // The burning through wrapped appchain token faucet is free
+    //
+    assert!(wat_faucet
+        .as_account()
+        .call(anchor.id(), "burn_wrapped_appchain_token")
+        .args_json(json!({
+            "receiver_id": receiver_id_in_appchain,
+            "amount": U128::from(common::to_actual_amount(1, 18))
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+        .unwrap()
+        .is_success());
+    assert_latest_burnt_notification(&anchor, common::to_actual_amount(1, 18), 0).await;
+    assert_eq!(
+        anchor_viewer::get_accrued_bridge_fees(&anchor).await?[0]
+            .accrued_amount
+            .0,
+        fee
+    );
+    //
+    // Only the relayer can claim the accrued fees
+    //
+    assert!(bridge_fee_manager::claim_bridge_fees(
+        &users[1],
+        &anchor,
+        BridgingAsset::WrappedAppchainToken
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    let relayer_wat_balance = common::get_ft_balance_of(&users[5], &wrapped_appchain_token).await?;
+    assert!(bridge_fee_manager::claim_bridge_fees(
+        &users[5],
+        &anchor,
+        BridgingAsset::WrappedAppchainToken
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        common::get_ft_balance_of(&users[5], &wrapped_appchain_token)
+            .await?
+            .0,
+        relayer_wat_balance.0 + fee
+    );
+    assert!(anchor_viewer::get_accrued_bridge_fees(&anchor)
+        .await?
+        .is_empty());
+    assert!(bridge_fee_manager::claim_bridge_fees(
+        &users[5],
+        &anchor,
+        BridgingAsset::WrappedAppchainToken
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    //
+    // Remove the bridge fee
+    //
+    assert!(bridge_fee_manager::set_bridge_fee(
+        &root,
+        &anchor,
+        BridgingAsset::WrappedAppchainToken,
+        None,
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        anchor_viewer::get_bridge_fee_of(&anchor, BridgingAsset::WrappedAppchainToken)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
+
+async fn assert_latest_burnt_notification(
+    anchor: &Contract,
+    expected_amount: u128,
+    expected_fee: u128,
+) {
+    let index_range = anchor_viewer::get_index_range_of_appchain_notification_history(anchor)
+        .await
+        .unwrap();
+    let appchain_notification_history =
+        anchor_viewer::get_appchain_notification_history(anchor, index_range.end_index.0)
+            .await
+            .unwrap()
+            .unwrap();
+    match appchain_notification_history.appchain_notification {
+        AppchainNotification::WrappedAppchainTokenBurnt { amount, fee, .. } => {
+            assert_eq!(amount.0, expected_amount);
+            assert_eq!(fee.0, expected_fee);
+        }
+        _ => panic!("The latest appchain notification should be 'WrappedAppchainTokenBurnt'."),
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    common,
+    contract_interfaces::{anchor_viewer, lifecycle_actions},
+};
+use appchain_anchor::types::{AppchainState, MultiTxsOperationProcessingResult};
+
+#[tokio::test]
+async fn test_closing() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        _wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        _,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // Start closing the appchain
+    //
+    assert!(lifecycle_actions::start_closing(&users[1], &anchor)
+        .await
+        .unwrap()
+        .is_failure());
+    assert!(lifecycle_actions::start_closing(&root, &anchor)
+        .await
+        .unwrap()
+        .is_success());
+    assert_eq!(
+        anchor_viewer::get_appchain_state(&anchor).await?,
+        AppchainState::Closing
+    );
+    //
+    // The reward of era0 will never be concluded as the appchain is halted
+    //
+    assert!(lifecycle_actions::complete_closing(&root, &anchor, None)
+        .await
+        .unwrap()
+        .is_failure());
+    assert!(
+        lifecycle_actions::complete_closing(&users[1], &anchor, Some(true))
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    loop {
+        let result = lifecycle_actions::complete_closing(&root, &anchor, Some(true))
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        match result.json::<MultiTxsOperationProcessingResult>()? {
+            MultiTxsOperationProcessingResult::Ok => break,
+            MultiTxsOperationProcessingResult::NeedMoreGas => (),
+            MultiTxsOperationProcessingResult::Error(message) => {
+                panic!("Failed to complete closing: {}", &message);
+            }
+        }
+    }
+    assert_eq!(
+        anchor_viewer::get_appchain_state(&anchor).await?,
+        AppchainState::Closed
+    );
+    //
+    // All validators and delegators are unbonded
+    //
+    for user in [&users[0], &users[1], &users[2], &users[3]] {
+        assert!(!anchor_viewer::get_unbonded_stakes_of(&anchor, user)
+            .await?
+            .is_empty());
+    }
+    //
+    Ok(())
+}
@@ -0,0 +1,299 @@
+use crate::{
+    common::{self, to_actual_amount},
+    contract_interfaces::{
+        anchor_viewer, permissionless_actions, settings_manager, wrapped_appchain_nft_manager,
+    },
+};
+use appchain_anchor::{
+    appchain_messages::{LockNftPayload, LockPayload, PayloadType, RawMessage},
+    types::NFTTransferMessage,
+};
+use near_contract_standards::non_fungible_token::metadata::{
+    NFTContractMetadata, TokenMetadata, NFT_METADATA_SPEC,
+};
+use near_sdk::{
+    borsh::BorshSerialize,
+    json_types::U128,
+    serde_json::{self, json},
+};
+use near_units::parse_near;
+use parity_scale_codec::Encode;
+use std::str::FromStr;
+use workspaces::{Account, AccountId, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+#[tokio::test]
+async fn test_collateralization_of_wrapped_appchain_token() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // The premined balance of wrapped appchain token is not counted as bridged asset
+    //
+    let status = anchor_viewer::get_collateralization_status(&anchor).await?;
+    assert!(status.market_value_of_staked_oct_token.0 > 0);
+    assert_eq!(status.market_value_of_wrapped_appchain_token.0, 0);
+    assert!(status.collateralization_ratio_percent.is_none());
+    //
+    // Lower the limit of wrapped appchain token
+    //
+    assert!(
+        settings_manager::change_maximum_market_value_percent_of_wrapped_appchain_token(
+            &users[1], &anchor, 1
+        )
+        .await
+        .unwrap()
+        .is_failure()
+    );
+    assert!(
+        settings_manager::change_maximum_market_value_percent_of_wrapped_appchain_token(
+            &root, &anchor, 1
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    let status = anchor_viewer::get_collateralization_status(&anchor).await?;
+    assert_eq!(
+        status.maximum_market_value_of_wrapped_appchain_token.0,
+        status.market_value_of_staked_oct_token.0 / 100
+    );
+    //
+    // Minting more wrapped appchain token than the limit is rejected
+    //
+    let wrapped_appchain_token_info = anchor_viewer::get_wrapped_appchain_token(&anchor).await?;
+    let excessive_amount = to_actual_amount(
+        status.maximum_market_value_of_wrapped_appchain_token.0
+            / wrapped_appchain_token_info.price_in_usd.0
+            + 1,
+        18,
+    );
+    assert!(excessive_amount < to_actual_amount(TOTAL_SUPPLY / 2 - 10, 18));
+    let user1_wat_balance = common::get_ft_balance_of(&users[1], &wrapped_appchain_token).await?;
+    appchain_message_nonce += 1;
+    stage_and_process_lock_message(&users, &anchor, appchain_message_nonce, excessive_amount).await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0
+    );
+    //
+    // Minting within the limit is accepted and counted as bridged asset
+    //
+    appchain_message_nonce += 1;
+    stage_and_process_lock_message(
+        &users,
+        &anchor,
+        appchain_message_nonce,
+        to_actual_amount(60, 18),
+    )
+    .await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0 + to_actual_amount(60, 18)
+    );
+    let status = anchor_viewer::get_collateralization_status(&anchor).await?;
+    assert!(
+        status.market_value_of_wrapped_appchain_token.0
+            >= 60 * wrapped_appchain_token_info.price_in_usd.0
+    );
+    assert!(status.collateralization_ratio_percent.is_some());
+    Ok(())
+}
+
+async fn stage_and_process_lock_message(
+    users: &Vec<Account>,
+    anchor: &Contract,
+    appchain_message_nonce: u32,
+    amount: u128,
+) {
+    let user4_id_in_appchain =
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da273".to_string();
+    let payload = LockPayload {
+        sender: user4_id_in_appchain,
+        receiver_id: users[1].id().to_string().parse().unwrap(),
+        amount,
+        fee: to_actual_amount(1, 18),
+    };
+    let raw_message = RawMessage {
+        nonce: appchain_message_nonce as u64,
+        payload_type: PayloadType::Lock,
+        payload: payload.try_to_vec().unwrap(),
+    };
+    let mut raw_messages = Vec::new();
+    raw_messages.push(raw_message);
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[4], anchor).await;
+}
+
+#[tokio::test]
+async fn test_collateralization_of_wrapped_appchain_nft() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        _wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let user0_id_in_appchain =
+        "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d".to_string();
+    //
+    // Register and open bridging of a wrapped appchain NFT class
+    //
+    assert!(root
+        .call(anchor.id(), "store_wasm_of_wrapped_appchain_nft_contract")
+        .args(std::fs::read(format!("res/wrapped_appchain_nft.wasm"))?)
+        .gas(300_000_000_000_000)
+        .deposit(parse_near!("30 N"))
+        .transact()
+        .await
+        .unwrap()
+        .is_success());
+    let class_id = "1".to_string();
+    assert!(wrapped_appchain_nft_manager::register_wrapped_appchain_nft(
+        &root,
+        &anchor,
+        class_id.clone(),
+        NFTContractMetadata {
+            spec: NFT_METADATA_SPEC.to_string(),
+            name: "nft class type 1".to_string(),
+            symbol: "nft_class1".to_string(),
+            icon: None,
+            base_uri: None,
+            reference: None,
+            reference_hash: None,
+        },
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        wrapped_appchain_nft_manager::open_bridging_of_wrapped_appchain_nft(
+            &root,
+            &anchor,
+            class_id.clone(),
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    //
+    // Mint a wrapped appchain NFT for user0
+    //
+    appchain_message_nonce += 1;
+    let payload = LockNftPayload {
+        sender: user0_id_in_appchain.clone(),
+        receiver_id: users[0].id().to_string().parse().unwrap(),
+        class: 1 as u128,
+        instance: 1 as u128,
+        metadata: TokenMetadata {
+            title: Some("token_id1 title".to_string()),
+            description: Some("token_id1 description".to_string()),
+            media: None,
+            media_hash: None,
+            copies: Some(1),
+            issued_at: None,
+            expires_at: None,
+            starts_at: None,
+            updated_at: None,
+            extra: None,
+            reference: None,
+            reference_hash: None,
+        },
+        fee: to_actual_amount(1, 18),
+    };
+    let raw_message = RawMessage {
+        nonce: appchain_message_nonce as u64,
+        payload_type: PayloadType::LockNft,
+        payload: payload.try_to_vec().unwrap(),
+    };
+    let mut raw_messages = Vec::new();
+    raw_messages.push(raw_message);
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+    //
+    // The NFT without floor price is locked but not valued
+    //
+    let nft_contract_id = AccountId::from_str(format!("{}.{}", class_id, anchor.id()).as_str())?;
+    let nft_transfer_message = serde_json::ser::to_string(&NFTTransferMessage::BridgeToAppchain {
+        receiver_id_in_appchain: user0_id_in_appchain.clone(),
+    })?;
+    assert!(users[0]
+        .call(&nft_contract_id, "nft_transfer_call")
+        .args_json(json!({
+            "receiver_id": anchor.id(),
+            "token_id": "1".to_string(),
+            "approval_Id": Option::<String>::None,
+            "memo": Option::<String>::None,
+            "msg": nft_transfer_message,
+        }))
+        .gas(300_000_000_000_000)
+        .deposit(1)
+        .transact()
+        .await
+        .unwrap()
+        .is_success());
+    let wrapped_appchain_nfts = anchor_viewer::get_wrapped_appchain_nfts(&anchor).await?;
+    assert_eq!(wrapped_appchain_nfts[0].count_of_locked_tokens.0, 1);
+    let status = anchor_viewer::get_collateralization_status(&anchor).await?;
+    assert_eq!(status.market_value_of_locked_wrapped_appchain_nfts.0, 0);
+    //
+    // The locked NFT is valued by its floor price after the floor price is set
+    //
+    assert!(
+        wrapped_appchain_nft_manager::set_floor_price_of_wrapped_appchain_nft(
+            &root,
+            &anchor,
+            class_id.clone(),
+            U128::from(1_000_000),
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    let status = anchor_viewer::get_collateralization_status(&anchor).await?;
+    assert_eq!(
+        status.market_value_of_locked_wrapped_appchain_nfts.0,
+        1_000_000
+    );
+    assert!(status.collateralization_ratio_percent.is_some());
+    Ok(())
+}
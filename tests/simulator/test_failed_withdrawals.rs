@@ -0,0 +1,135 @@
+use crate::{
+    common,
+    contract_interfaces::{anchor_viewer, staking_actions},
+};
+use appchain_anchor::types::WithdrawalKind;
+use near_units::parse_near;
+
+#[tokio::test]
+async fn test_failed_withdrawals() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // Switch to era1 and distribute reward of era0
+    //
+    appchain_message_nonce += 1;
+    common::complex_actions::switch_era(&users[5], &anchor, 1, appchain_message_nonce, false).await;
+    appchain_message_nonce += 1;
+    common::complex_actions::distribute_reward_of(
+        &users[5],
+        &anchor,
+        &wrapped_appchain_token,
+        appchain_message_nonce,
+        0,
+        Vec::new(),
+        false,
+    )
+    .await;
+    //
+    // Set a beneficiary which is not registered in wrapped appchain token contract
+    //
+    let beneficiary = root
+        .create_subaccount("beneficiary")
+        .initial_balance(parse_near!("10 N"))
+        .transact()
+        .await?
+        .unwrap();
+    let beneficiary_id = beneficiary.id().to_string().parse().unwrap();
+    assert!(
+        staking_actions::set_reward_beneficiary(&users[5], &anchor, Some(&beneficiary_id))
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        staking_actions::set_reward_beneficiary(&users[3], &anchor, Some(&beneficiary_id))
+            .await
+            .unwrap()
+            .is_success()
+    );
+    //
+    // The transfer of rewards fails and is recorded as a failed withdrawal
+    //
+    let rewards =
+        anchor_viewer::get_delegator_rewards_of(&anchor, 0, 0, &users[3], &users[0]).await?;
+    let reward_amount = rewards[0].unwithdrawn_reward.0;
+    assert!(reward_amount > 0);
+    assert!(staking_actions::withdraw_delegator_rewards(
+        &users[3],
+        &anchor,
+        &users[3].id().to_string().parse().unwrap(),
+        &users[0].id().to_string().parse().unwrap(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    let failed_withdrawals = anchor_viewer::get_failed_withdrawals_of(&anchor, &users[3]).await?;
+    assert_eq!(failed_withdrawals.len(), 1);
+    assert!(matches!(
+        failed_withdrawals[0].kind,
+        WithdrawalKind::Rewards
+    ));
+    assert_eq!(failed_withdrawals[0].receiver_id, beneficiary_id);
+    assert_eq!(failed_withdrawals[0].amount.0, reward_amount);
+    assert_eq!(
+        common::get_ft_balance_of(&beneficiary, &wrapped_appchain_token)
+            .await?
+            .0,
+        0
+    );
+    //
+    // The claim fails again while the beneficiary is still not registered
+    //
+    assert!(staking_actions::claim_failed_withdrawals(
+        &users[1],
+        &anchor,
+        &users[3].id().to_string().parse().unwrap()
+    )
+    .await
+    .unwrap()
+    .is_success());
+    let failed_withdrawals = anchor_viewer::get_failed_withdrawals_of(&anchor, &users[3]).await?;
+    assert_eq!(failed_withdrawals.len(), 1);
+    assert_eq!(failed_withdrawals[0].amount.0, reward_amount);
+    //
+    // The claim succeeds after the beneficiary is registered
+    //
+    common::basic_actions::register_user_to_ft_contract(&beneficiary, &wrapped_appchain_token)
+        .await;
+    assert!(staking_actions::claim_failed_withdrawals(
+        &users[1],
+        &anchor,
+        &users[3].id().to_string().parse().unwrap()
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(anchor_viewer::get_failed_withdrawals_of(&anchor, &users[3])
+        .await?
+        .is_empty());
+    assert_eq!(
+        common::get_ft_balance_of(&beneficiary, &wrapped_appchain_token)
+            .await?
+            .0,
+        reward_amount
+    );
+    assert!(staking_actions::claim_failed_withdrawals(
+        &users[1],
+        &anchor,
+        &users[3].id().to_string().parse().unwrap()
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    Ok(())
+}
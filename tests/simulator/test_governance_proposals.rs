@@ -0,0 +1,176 @@
+use crate::{
+    common::{self, to_actual_amount},
+    contract_interfaces::{anchor_viewer, governance_proposal_manager, settings_manager},
+};
+use appchain_anchor::types::GovernanceProposalStatus;
+use near_sdk::{
+    json_types::{U128, U64},
+    serde_json::json,
+};
+
+#[tokio::test]
+async fn test_governance_proposals() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (root, _, _, _registry, _council, anchor, _wat_faucet, users, _) =
+        common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // Enable the governance timelock
+    //
+    assert!(
+        settings_manager::change_governance_timelock_delay(&users[1], &anchor, 30)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        settings_manager::change_governance_timelock_delay(&root, &anchor, 30)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    //
+    // Timelocked functions can not be called directly
+    //
+    assert!(
+        settings_manager::set_era_reward(&root, &anchor, to_actual_amount(20, 18))
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        settings_manager::change_governance_timelock_delay(&root, &anchor, 0)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    // Propose governance actions
+    //
+    let args = json!({ "era_reward": U128::from(to_actual_amount(20, 18)) }).to_string();
+    assert!(governance_proposal_manager::propose_governance_action(
+        &users[1],
+        &anchor,
+        "set_era_reward",
+        args.clone(),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(governance_proposal_manager::propose_governance_action(
+        &root,
+        &anchor,
+        "set_rpc_endpoint",
+        json!({ "rpc_endpoint": "wss://rpc.testnet.oct.network:9944" }).to_string(),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(governance_proposal_manager::propose_governance_action(
+        &root,
+        &anchor,
+        "set_era_reward",
+        "invalid json".to_string(),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    let result = governance_proposal_manager::propose_governance_action(
+        &root,
+        &anchor,
+        "set_era_reward",
+        args.clone(),
+    )
+    .await?;
+    assert!(result.is_success());
+    let index = result.json::<U64>()?.0;
+    let proposal = anchor_viewer::get_governance_proposal(&anchor, index)
+        .await?
+        .expect("The governance proposal should exist.");
+    assert_eq!(proposal.method_name, "set_era_reward");
+    assert_eq!(proposal.args, args);
+    assert_eq!(proposal.proposer.to_string(), root.id().to_string());
+    assert_eq!(proposal.status, GovernanceProposalStatus::Pending);
+    //
+    // The proposal can not be executed before the timelock delay has passed
+    //
+    assert!(
+        governance_proposal_manager::execute_governance_proposal(&users[1], &anchor, index)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    // Cancel a proposal
+    //
+    let result = governance_proposal_manager::propose_governance_action(
+        &root,
+        &anchor,
+        "change_governance_timelock_delay",
+        json!({ "value": U64::from(0) }).to_string(),
+    )
+    .await?;
+    assert!(result.is_success());
+    let cancelled_index = result.json::<U64>()?.0;
+    assert!(governance_proposal_manager::cancel_governance_proposal(
+        &users[1],
+        &anchor,
+        cancelled_index
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(governance_proposal_manager::cancel_governance_proposal(
+        &root,
+        &anchor,
+        cancelled_index
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        anchor_viewer::get_governance_proposal(&anchor, cancelled_index)
+            .await?
+            .unwrap()
+            .status,
+        GovernanceProposalStatus::Cancelled
+    );
+    //
+    // Anyone can execute the proposal after the timelock delay has passed
+    //
+    worker.fast_forward(500).await?;
+    assert!(governance_proposal_manager::execute_governance_proposal(
+        &users[1],
+        &anchor,
+        cancelled_index
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(
+        governance_proposal_manager::execute_governance_proposal(&users[1], &anchor, index)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert_eq!(
+        anchor_viewer::get_governance_proposal(&anchor, index)
+            .await?
+            .unwrap()
+            .status,
+        GovernanceProposalStatus::Executed
+    );
+    assert_eq!(
+        anchor_viewer::get_appchain_settings(&anchor)
+            .await?
+            .era_reward
+            .0,
+        to_actual_amount(20, 18)
+    );
+    assert!(
+        governance_proposal_manager::execute_governance_proposal(&users[1], &anchor, index)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    Ok(())
+}
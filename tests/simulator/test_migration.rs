@@ -1,5 +1,10 @@
 use crate::common::{self, complex_actions};
-use near_sdk::{json_types::U64, serde_json::json};
+use crate::contract_interfaces::anchor_viewer;
+use appchain_anchor::types::PricedAsset;
+use near_sdk::{
+    json_types::{U128, U64},
+    serde_json::json,
+};
 use near_units::parse_near;
 
 #[tokio::test]
@@ -53,6 +58,9 @@ async fn test_migration() -> anyhow::Result<()> {
     //
     let result = root
         .call(anchor.id(), "update_self")
+        .args_json(json!({
+            "onboarding_grant_budget": U128::from(parse_near!("1000 N"))
+        }))
         .gas(300_000_000_000_000)
         .transact()
         .await?;
@@ -60,6 +68,15 @@ async fn test_migration() -> anyhow::Result<()> {
     println!();
     assert!(result.is_success());
     //
+    let result = anchor
+        .as_account()
+        .call(anchor.id(), "change_governance_timelock_delay")
+        .args_json(json!({ "value": U64::from(0) }))
+        .gas(300_000_000_000_000)
+        .transact()
+        .await?;
+    assert!(result.is_success());
+    //
     let result = anchor
         .call("migrate_appchain_messages")
         .args_json(json!({
@@ -75,6 +92,18 @@ async fn test_migration() -> anyhow::Result<()> {
     println!();
     assert!(result.is_success());
     //
+    // The prices stored before upgrade are not stale right after upgrade
+    //
+    for asset in [
+        PricedAsset::OctToken,
+        PricedAsset::WrappedAppchainToken,
+        PricedAsset::NativeNearToken,
+    ] {
+        let price_status = anchor_viewer::get_price_status_of(&anchor, asset).await?;
+        assert!(price_status.updated_at.is_some());
+        assert!(!price_status.is_stale);
+    }
+    //
     //
     //
     common::complex_viewer::print_anchor_status(&anchor).await;
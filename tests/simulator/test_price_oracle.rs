@@ -0,0 +1,120 @@
+use crate::{
+    common,
+    contract_interfaces::{anchor_viewer, price_oracle_manager, settings_manager},
+};
+use appchain_anchor::types::PricedAsset;
+
+#[tokio::test]
+async fn test_price_oracle() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (root, _, _, _registry, _council, anchor, _wat_faucet, users, _) =
+        common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // Add price reporters
+    //
+    assert!(
+        price_oracle_manager::add_price_reporter(&users[1], &anchor, &users[2])
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        price_oracle_manager::add_price_reporter(&root, &anchor, &users[2])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert!(
+        price_oracle_manager::add_price_reporter(&root, &anchor, &users[3])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_reporters = anchor_viewer::get_price_reporters(&anchor).await?;
+    assert!(price_reporters.contains(&users[2].id().to_string().parse().unwrap()));
+    assert!(price_reporters.contains(&users[3].id().to_string().parse().unwrap()));
+    //
+    // Require 2 submissions to update the price
+    //
+    assert!(
+        settings_manager::change_minimum_price_submission_count(&root, &anchor, 2)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_status = anchor_viewer::get_price_status_of(&anchor, PricedAsset::OctToken).await?;
+    let current_price = price_status.price.0;
+    //
+    // The price is not changed by a single submission
+    //
+    assert!(
+        settings_manager::set_price_of_oct_token(&users[4], &anchor, current_price + 20_000)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_status = anchor_viewer::get_price_status_of(&anchor, PricedAsset::OctToken).await?;
+    assert_eq!(price_status.price.0, current_price);
+    assert_eq!(price_status.submissions.len(), 1);
+    //
+    // The price is updated to the median of submissions once the quorum is reached
+    //
+    assert!(
+        settings_manager::set_price_of_oct_token(&users[2], &anchor, current_price + 40_000)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_status = anchor_viewer::get_price_status_of(&anchor, PricedAsset::OctToken).await?;
+    assert_eq!(price_status.price.0, current_price + 30_000);
+    assert_eq!(price_status.submissions.len(), 2);
+    assert!(!price_status.is_stale);
+    //
+    // Submissions deviating too much from the current price are rejected
+    //
+    assert!(
+        settings_manager::set_price_of_oct_token(&users[3], &anchor, current_price * 2)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    // Accounts which are not price reporters can not submit price
+    //
+    assert!(
+        settings_manager::set_price_of_oct_token(&users[1], &anchor, current_price)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    // The price set by owner overrides the current price directly
+    //
+    assert!(
+        settings_manager::set_price_of_oct_token(&root, &anchor, current_price * 2)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_status = anchor_viewer::get_price_status_of(&anchor, PricedAsset::OctToken).await?;
+    assert_eq!(price_status.price.0, current_price * 2);
+    assert_eq!(price_status.submissions.len(), 0);
+    //
+    // Remove a price reporter
+    //
+    assert!(
+        price_oracle_manager::remove_price_reporter(&root, &anchor, &users[3])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let price_reporters = anchor_viewer::get_price_reporters(&anchor).await?;
+    assert!(!price_reporters.contains(&users[3].id().to_string().parse().unwrap()));
+    assert!(
+        settings_manager::set_price_of_oct_token(&users[3], &anchor, current_price * 2)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    Ok(())
+}
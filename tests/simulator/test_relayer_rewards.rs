@@ -0,0 +1,136 @@
+use crate::{
+    common::{self, to_actual_amount},
+    contract_interfaces::{
+        anchor_viewer, permissionless_actions, settings_manager, staking_actions,
+    },
+};
+use appchain_anchor::appchain_messages::{EraPayoutPayload, PayloadType, RawMessage};
+use near_sdk::borsh::BorshSerialize;
+use parity_scale_codec::Encode;
+
+#[tokio::test]
+async fn test_relayer_rewards() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    //
+    // Set the relayer reward per era
+    //
+    assert!(settings_manager::set_relayer_reward_per_era(
+        &users[1],
+        &anchor,
+        to_actual_amount(1, 18)
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(
+        settings_manager::set_relayer_reward_per_era(&root, &anchor, to_actual_amount(1, 18))
+            .await
+            .unwrap()
+            .is_success()
+    );
+    //
+    // Switch to era1
+    //
+    appchain_message_nonce += 1;
+    common::complex_actions::switch_era(&users[5], &anchor, 1, appchain_message_nonce, false).await;
+    let relayer_stats = anchor_viewer::get_relayer_stats_of(&anchor, &users[5])
+        .await?
+        .unwrap();
+    assert!(relayer_stats.staged_message_count.0 > 0);
+    assert!(relayer_stats.applied_message_count.0 > 0);
+    assert!(relayer_stats.workload_of_current_era.0 > 0);
+    assert_eq!(relayer_stats.total_rewards.0, 0);
+    //
+    // Distribute reward of era0, the relayer rewards are minted along with the era reward
+    //
+    let anchor_balance_of_wat =
+        common::get_ft_balance_of(&anchor.as_account(), &wrapped_appchain_token).await?;
+    appchain_message_nonce += 1;
+    let payload = EraPayoutPayload {
+        end_era: 0,
+        excluded_validators: Vec::new(),
+        offenders: Vec::new(),
+    };
+    let raw_messages = vec![RawMessage {
+        nonce: appchain_message_nonce as u64,
+        payload_type: PayloadType::EraPayout,
+        payload: payload.try_to_vec().unwrap(),
+    }];
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[5], &anchor).await;
+    let relayer_stats = anchor_viewer::get_relayer_stats_of(&anchor, &users[5])
+        .await?
+        .unwrap();
+    assert!(relayer_stats.total_rewards.0 > 0);
+    assert!(relayer_stats.total_rewards.0 <= to_actual_amount(1, 18));
+    assert_eq!(
+        relayer_stats.unwithdrawn_rewards.0,
+        relayer_stats.total_rewards.0
+    );
+    let minted_amount = common::get_ft_balance_of(&anchor.as_account(), &wrapped_appchain_token)
+        .await?
+        .0
+        - anchor_balance_of_wat.0;
+    assert!(minted_amount >= to_actual_amount(10, 18) + relayer_stats.total_rewards.0);
+    assert!(minted_amount <= to_actual_amount(11, 18));
+    //
+    // Withdraw relayer rewards
+    //
+    assert!(
+        staking_actions::withdraw_relayer_rewards(&users[1], &anchor)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    let relayer_balance_of_wat =
+        common::get_ft_balance_of(&users[5], &wrapped_appchain_token).await?;
+    assert!(
+        staking_actions::withdraw_relayer_rewards(&users[5], &anchor)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[5], &wrapped_appchain_token)
+            .await?
+            .0,
+        relayer_balance_of_wat.0 + relayer_stats.unwithdrawn_rewards.0
+    );
+    let new_relayer_stats = anchor_viewer::get_relayer_stats_of(&anchor, &users[5])
+        .await?
+        .unwrap();
+    assert_eq!(new_relayer_stats.unwithdrawn_rewards.0, 0);
+    assert_eq!(
+        new_relayer_stats.total_rewards.0,
+        relayer_stats.total_rewards.0
+    );
+    assert!(
+        staking_actions::withdraw_relayer_rewards(&users[5], &anchor)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use crate::{
+    common,
+    contract_interfaces::{anchor_viewer, permissionless_actions, sudo_actions},
+};
+use appchain_anchor::{
+    appchain_messages::{LockPayload, PayloadType, RawMessage},
+    types::{AppchainMessageProcessingResult, MultiTxsOperationProcessingResult},
+};
+use near_sdk::borsh::BorshSerialize;
+use parity_scale_codec::Encode;
+
+#[tokio::test]
+async fn test_retrying_appchain_messages() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let user4_id_in_appchain =
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da273".to_string();
+    //
+    // Mint wrapped appchain token for user1 while asset transfer is paused
+    //
+    assert!(sudo_actions::pause_asset_transfer(&root, &anchor)
+        .await
+        .unwrap()
+        .is_success());
+    let user1_wat_balance = common::get_ft_balance_of(&users[1], &wrapped_appchain_token).await?;
+    appchain_message_nonce += 1;
+    let nonce = appchain_message_nonce;
+    let payload = LockPayload {
+        sender: user4_id_in_appchain.clone(),
+        receiver_id: users[1].id().to_string().parse().unwrap(),
+        amount: common::to_actual_amount(60, 18),
+        fee: common::to_actual_amount(1, 18),
+    };
+    let raw_message = RawMessage {
+        nonce: nonce as u64,
+        payload_type: PayloadType::Lock,
+        payload: payload.try_to_vec().unwrap(),
+    };
+    let mut raw_messages = Vec::new();
+    raw_messages.push(raw_message);
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0
+    );
+    //
+    // The failed message is added to retry queue
+    //
+    let retryable_appchain_messages =
+        anchor_viewer::get_retryable_appchain_messages(&anchor).await?;
+    assert_eq!(retryable_appchain_messages.len(), 1);
+    assert_eq!(retryable_appchain_messages[0].nonce, nonce);
+    assert_eq!(retryable_appchain_messages[0].retry_count, 0);
+    let failed_at = retryable_appchain_messages[0].failed_at;
+    //
+    // Retrying fails while asset transfer is still paused
+    //
+    let result = permissionless_actions::retry_appchain_message(&users[1], &anchor, nonce).await?;
+    assert!(result.is_success());
+    assert!(result
+        .json::<MultiTxsOperationProcessingResult>()?
+        .is_error());
+    let retryable_appchain_messages =
+        anchor_viewer::get_retryable_appchain_messages(&anchor).await?;
+    assert_eq!(retryable_appchain_messages.len(), 1);
+    assert_eq!(retryable_appchain_messages[0].retry_count, 1);
+    assert_eq!(retryable_appchain_messages[0].failed_at, failed_at);
+    //
+    // Retrying succeeds after asset transfer is resumed
+    //
+    assert!(sudo_actions::resume_asset_transfer(&root, &anchor)
+        .await
+        .unwrap()
+        .is_success());
+    let result = permissionless_actions::retry_appchain_message(&users[1], &anchor, nonce).await?;
+    assert!(result.is_success());
+    assert!(result.json::<MultiTxsOperationProcessingResult>()?.is_ok());
+    assert!(anchor_viewer::get_retryable_appchain_messages(&anchor)
+        .await?
+        .is_empty());
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0 + common::to_actual_amount(60, 18)
+    );
+    //
+    // All attempts are recorded in processing result history
+    //
+    let processing_results =
+        anchor_viewer::get_processing_result_history_of_appchain_message(&anchor, nonce).await?;
+    assert!(processing_results.len() >= 3);
+    assert!(matches!(
+        processing_results[0],
+        AppchainMessageProcessingResult::Error { .. }
+    ));
+    assert!(matches!(
+        processing_results[1],
+        AppchainMessageProcessingResult::Error { .. }
+    ));
+    assert!(matches!(
+        processing_results.last().unwrap(),
+        AppchainMessageProcessingResult::Ok { .. }
+    ));
+    //
+    // The message can not be applied twice
+    //
+    assert!(
+        permissionless_actions::retry_appchain_message(&users[1], &anchor, nonce)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0 + common::to_actual_amount(60, 18)
+    );
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use crate::{
+    common::{self, to_actual_amount},
+    contract_interfaces::{anchor_viewer, permissionless_actions, settings_manager},
+};
+use appchain_anchor::appchain_messages::{EraPayoutPayload, Offender, PayloadType, RawMessage};
+use near_sdk::borsh::BorshSerialize;
+use parity_scale_codec::Encode;
+
+#[tokio::test]
+async fn test_slashing() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let user0_id_in_appchain =
+        "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d".to_string();
+    let offence_kind = "im-online:offlin";
+    //
+    // Set the slash percent of the offence kind and the treasury account
+    //
+    assert!(settings_manager::change_slash_percent_per_offence_of_kind(
+        &users[1],
+        &anchor,
+        offence_kind,
+        10
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(settings_manager::change_slash_percent_per_offence_of_kind(
+        &root,
+        &anchor,
+        offence_kind,
+        10
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        settings_manager::set_treasury_account(&root, &anchor, &users[5])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    //
+    // Switch to era1
+    //
+    appchain_message_nonce += 1;
+    common::complex_actions::switch_era(&users[5], &anchor, 1, appchain_message_nonce, false).await;
+    //
+    // Distribute reward of era0 with user0 reported as offender
+    //
+    let validator_deposit =
+        anchor_viewer::get_validator_deposit_of(&anchor, &users[0], None).await?;
+    let user2_deposit =
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[2], &users[0], None).await?;
+    let user3_deposit =
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[3], &users[0], None).await?;
+    let user1_deposit = anchor_viewer::get_validator_deposit_of(&anchor, &users[1], None).await?;
+    let treasury_balance = common::get_ft_balance_of(&users[5], &oct_token).await?;
+    let anchor_balance_of_wat =
+        common::get_ft_balance_of(&anchor.as_account(), &wrapped_appchain_token).await?;
+    appchain_message_nonce += 1;
+    let payload = EraPayoutPayload {
+        end_era: 0,
+        excluded_validators: Vec::new(),
+        offenders: vec![
+            Offender {
+                kind: offence_kind.to_string(),
+                who: user0_id_in_appchain.clone(),
+                offences: 1,
+            },
+            Offender {
+                kind: "babe:equivocatio".to_string(),
+                who: user0_id_in_appchain.clone(),
+                offences: 1,
+            },
+        ],
+    };
+    let raw_messages = vec![RawMessage {
+        nonce: appchain_message_nonce as u64,
+        payload_type: PayloadType::EraPayout,
+        payload: payload.try_to_vec().unwrap(),
+    }];
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        raw_messages.encode(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::complex_actions::process_appchain_messages(&users[5], &anchor).await;
+    //
+    // The offender and its delegators are slashed by 10 percent in next era,
+    // the offence kind without slash percent is ignored
+    //
+    assert_eq!(
+        anchor_viewer::get_validator_deposit_of(&anchor, &users[0], None)
+            .await?
+            .0,
+        validator_deposit.0 - validator_deposit.0 / 10
+    );
+    assert_eq!(
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[2], &users[0], None)
+            .await?
+            .0,
+        user2_deposit.0 - user2_deposit.0 / 10
+    );
+    assert_eq!(
+        anchor_viewer::get_delegator_deposit_of(&anchor, &users[3], &users[0], None)
+            .await?
+            .0,
+        user3_deposit.0 - user3_deposit.0 / 10
+    );
+    assert_eq!(
+        anchor_viewer::get_validator_deposit_of(&anchor, &users[1], None)
+            .await?
+            .0,
+        user1_deposit.0
+    );
+    //
+    // The slashed OCT token is transferred to treasury account
+    //
+    let slashed_amount = validator_deposit.0 / 10 + user2_deposit.0 / 10 + user3_deposit.0 / 10;
+    assert!(slashed_amount > 0);
+    assert_eq!(
+        common::get_ft_balance_of(&users[5], &oct_token).await?.0,
+        treasury_balance.0 + slashed_amount
+    );
+    assert_eq!(
+        anchor_viewer::get_pending_slashed_oct_token(&anchor)
+            .await?
+            .0,
+        0
+    );
+    //
+    // The reward of era0 is still distributed
+    //
+    assert_eq!(
+        common::get_ft_balance_of(&anchor.as_account(), &wrapped_appchain_token)
+            .await?
+            .0,
+        anchor_balance_of_wat.0 + to_actual_amount(10, 18)
+    );
+    //
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        anchor_viewer, near_fungible_token_manager, permissionless_actions, storage_fund_manager,
+    },
+};
+use appchain_anchor::{
+    appchain_messages::{BurnAssetPayload, PayloadType, RawMessage},
+    types::BridgingAsset,
+};
+use near_sdk::{borsh::BorshSerialize, json_types::U128, serde_json::json};
+use near_units::parse_near;
+use parity_scale_codec::Encode;
+
+#[tokio::test]
+async fn test_storage_fund() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        oct_token,
+        _wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let user0_id_in_appchain =
+        "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d".to_string();
+    let oct_asset = BridgingAsset::NearFungibleToken {
+        contract_account: oct_token.id().to_string().parse().unwrap(),
+    };
+    //
+    // Register OCT token as a NEAR fungible token and lock some of it
+    //
+    assert!(near_fungible_token_manager::register_near_fungible_token(
+        &root,
+        &anchor,
+        "OCT".to_string(),
+        "Oct token".to_string(),
+        18,
+        oct_token.id().to_string().parse().unwrap(),
+        U128::from(1000000),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        near_fungible_token_manager::set_price_of_near_fungible_token(
+            &root,
+            &anchor,
+            "OCT".to_string(),
+            U128::from(1000000),
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    assert!(
+        near_fungible_token_manager::open_bridging_of_near_fungible_token(
+            &root,
+            &anchor,
+            "OCT".to_string(),
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    let anchor_oct_balance = common::get_ft_balance_of(&anchor.as_account(), &oct_token).await?;
+    assert!(common::call_ft_transfer_call(
+        &users[0],
+        &anchor.as_account(),
+        common::to_actual_amount(10, 18),
+        json!({
+            "BridgeToAppchain": {
+                "receiver_id_in_appchain": user0_id_in_appchain,
+            }
+        })
+        .to_string(),
+        &oct_token,
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        common::get_ft_balance_of(&anchor.as_account(), &oct_token)
+            .await?
+            .0,
+        anchor_oct_balance.0 + common::to_actual_amount(10, 18)
+    );
+    //
+    // Set storage deposit budget
+    //
+    let budget = parse_near!("0.0125 N");
+    assert!(storage_fund_manager::set_storage_deposit_budget(
+        &users[1],
+        &anchor,
+        oct_asset.clone(),
+        Some(U128::from(budget)),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(storage_fund_manager::set_storage_deposit_budget(
+        &root,
+        &anchor,
+        BridgingAsset::WrappedAppchainToken,
+        Some(U128::from(budget)),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    assert!(storage_fund_manager::set_storage_deposit_budget(
+        &root,
+        &anchor,
+        oct_asset.clone(),
+        Some(U128::from(budget)),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        anchor_viewer::get_storage_deposit_budget_of(&anchor, oct_asset.clone()).await?,
+        Some(U128::from(budget))
+    );
+    //
+    // Top up storage fund
+    //
+    assert!(
+        storage_fund_manager::deposit_to_storage_fund(&users[1], &anchor, parse_near!("1 N"))
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        storage_fund_manager::deposit_to_storage_fund(&root, &anchor, parse_near!("1 N"))
+            .await
+            .unwrap()
+            .is_success()
+    );
+    let storage_fund = anchor_viewer::get_storage_fund(&anchor).await?;
+    assert_eq!(storage_fund.balance.0, parse_near!("1 N"));
+    assert_eq!(storage_fund.spent_amount.0, 0);
+    //
+    // Unlock OCT token to a receiver which is not registered in token contract
+    //
+    let receiver = root
+        .create_subaccount("receiver")
+        .initial_balance(parse_near!("10 N"))
+        .transact()
+        .await?
+        .unwrap();
+    for _ in 0..2 {
+        appchain_message_nonce += 1;
+        let payload = BurnAssetPayload {
+            token_id: oct_token.id().to_string(),
+            sender: user0_id_in_appchain.clone(),
+            receiver_id: receiver.id().to_string().parse().unwrap(),
+            amount: common::to_actual_amount(5, 18),
+            fee: 0,
+        };
+        let raw_message = RawMessage {
+            nonce: appchain_message_nonce as u64,
+            payload_type: PayloadType::BurnAsset,
+            payload: payload.try_to_vec().unwrap(),
+        };
+        let mut raw_messages = Vec::new();
+        raw_messages.push(raw_message);
+        assert!(permissionless_actions::verify_and_stage_appchain_messages(
+            &users[5],
+            &anchor,
+            raw_messages.encode(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .await
+        .unwrap()
+        .is_success());
+        common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+        //
+        // The storage deposit is only paid for the first unlocking
+        //
+        let storage_fund = anchor_viewer::get_storage_fund(&anchor).await?;
+        assert_eq!(storage_fund.balance.0, parse_near!("1 N") - budget);
+        assert_eq!(storage_fund.spent_amount.0, budget);
+    }
+    assert_eq!(
+        common::get_ft_balance_of(&receiver, &oct_token).await?.0,
+        common::to_actual_amount(10, 18)
+    );
+    //
+    // Remove storage deposit budget
+    //
+    assert!(storage_fund_manager::set_storage_deposit_budget(
+        &root,
+        &anchor,
+        oct_asset.clone(),
+        None,
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        anchor_viewer::get_storage_deposit_budget_of(&anchor, oct_asset)
+            .await?
+            .is_none()
+    );
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::{
+    common,
+    contract_interfaces::{anchor_viewer, permissionless_actions, settings_manager},
+};
+use appchain_anchor::appchain_messages::{LockPayload, PayloadType, RawMessage};
+use near_sdk::borsh::BorshSerialize;
+use parity_scale_codec::Encode;
+
+#[tokio::test]
+async fn test_witness_attestations() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let (
+        root,
+        _oct_token,
+        wrapped_appchain_token,
+        _registry,
+        _council,
+        anchor,
+        _wat_faucet,
+        users,
+        mut appchain_message_nonce,
+    ) = common::test_normal_actions(&worker, false, false, vec!["0x00".to_string()]).await?;
+    let user4_id_in_appchain =
+        "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da273".to_string();
+    //
+    // The threshold can not be greater than the count of witnesses
+    //
+    assert!(
+        settings_manager::change_witness_attestation_threshold(&root, &anchor, 2)
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    assert!(
+        settings_manager::grant_role(&root, &anchor, "Witness", &users[3])
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert!(
+        settings_manager::change_witness_attestation_threshold(&root, &anchor, 2)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert!(
+        settings_manager::revoke_role(&root, &anchor, "Witness", &users[3])
+            .await
+            .unwrap()
+            .is_failure()
+    );
+    //
+    // Prepare appchain messages
+    //
+    appchain_message_nonce += 1;
+    let start_nonce = appchain_message_nonce;
+    let payload = LockPayload {
+        sender: user4_id_in_appchain.clone(),
+        receiver_id: users[1].id().to_string().parse().unwrap(),
+        amount: common::to_actual_amount(60, 18),
+        fee: common::to_actual_amount(1, 18),
+    };
+    let raw_message = RawMessage {
+        nonce: start_nonce as u64,
+        payload_type: PayloadType::Lock,
+        payload: payload.try_to_vec().unwrap(),
+    };
+    let mut raw_messages = Vec::new();
+    raw_messages.push(raw_message);
+    let encoded_messages = raw_messages.encode();
+    let user1_wat_balance = common::get_ft_balance_of(&users[1], &wrapped_appchain_token).await?;
+    //
+    // The messages are not staged with the attestation of relayer only
+    //
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[5],
+        &anchor,
+        encoded_messages.clone(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    let attestations = anchor_viewer::get_witness_attestations_of(&anchor, start_nonce).await?;
+    assert_eq!(attestations.len(), 1);
+    assert_eq!(
+        attestations[0].witness.to_string(),
+        users[5].id().to_string()
+    );
+    common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0
+    );
+    //
+    // Accounts which are not witnesses can not attest the messages
+    //
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[1],
+        &anchor,
+        encoded_messages.clone(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert_eq!(
+        anchor_viewer::get_witness_attestations_of(&anchor, start_nonce)
+            .await?
+            .len(),
+        1
+    );
+    //
+    // The messages are staged once the threshold is reached
+    //
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[3],
+        &anchor,
+        encoded_messages.clone(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        anchor_viewer::get_witness_attestations_of(&anchor, start_nonce)
+            .await?
+            .is_empty()
+    );
+    common::complex_actions::process_appchain_messages(&users[4], &anchor).await;
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &wrapped_appchain_token)
+            .await?
+            .0,
+        user1_wat_balance.0 + common::to_actual_amount(60, 18)
+    );
+    let witness_submission_stats = anchor_viewer::get_witness_submission_stats(&anchor).await?;
+    let stats_of_user3 = witness_submission_stats
+        .iter()
+        .find(|stats| stats.witness.to_string().eq(&users[3].id().to_string()))
+        .expect("The submission stats of user3 should exist.");
+    assert_eq!(stats_of_user3.submission_count.0, 1);
+    assert_eq!(stats_of_user3.agreed_count.0, 1);
+    assert_eq!(stats_of_user3.disagreed_count.0, 0);
+    //
+    // The staged messages can not be attested again
+    //
+    assert!(permissionless_actions::verify_and_stage_appchain_messages(
+        &users[3],
+        &anchor,
+        encoded_messages,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .await
+    .unwrap()
+    .is_failure());
+    Ok(())
+}
@@ -37,7 +37,7 @@ impl WrappedAppchainTokenFaucet {
         );
     }
     ///
-    pub fn burn_wrapped_appchain_token(&mut self, receiver_id: String, amount: U128) {
+    pub fn burn_wrapped_appchain_token(&mut self, receiver_id: String, amount: U128) -> Promise {
         self.assert_anchor();
         //
         #[derive(near_sdk::serde::Serialize)]
@@ -57,6 +57,6 @@ impl WrappedAppchainTokenFaucet {
             args,
             0,
             Gas::ONE_TERA.mul(T_GAS_FOR_BURN_WRAPPED_APPCHAIN_TOKEN),
-        );
+        )
     }
 }